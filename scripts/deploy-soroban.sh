@@ -8,7 +8,7 @@ echo "🚀 Starting Soroban Contract Deployment..."
 # Configuration
 NETWORK="testnet"
 CONTRACT_DIR="./soroban-contracts/staff-registry"
-WASM_FILE="target/wasm32-unknown-unknown/release/staff_registry.wasm"
+WASM_FILE="../target/wasm32-unknown-unknown/release/staff_registry.wasm"
 
 # Check if Soroban CLI is installed
 if ! command -v soroban &> /dev/null; then
@@ -70,6 +70,19 @@ soroban contract invoke \
 
 echo "✅ Contract initialized with owner: $OWNER_PUBLIC_KEY"
 echo ""
+# The backend registers the staff hashes it derives itself (register_staff), which the
+# contract rejects by default in favour of on-chain domain-separated hashes; opt in explicitly
+echo "🔓 Allowing backend-supplied staff hashes..."
+soroban contract invoke \
+    --id $CONTRACT_ID \
+    --network $NETWORK \
+    --source-account default \
+    -- \
+    set_legacy_hashes_allowed \
+    --allowed true
+
+echo "✅ Backend-supplied staff hashes allowed"
+echo ""
 echo "✅ Deployment complete!"
 echo ""
 echo "Next steps:"
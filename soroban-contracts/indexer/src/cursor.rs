@@ -0,0 +1,45 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Last committed RPC cursor, kept in a local file next to the database
+pub struct CursorFile {
+    path: PathBuf,
+}
+
+impl CursorFile {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        CursorFile { path: path.into() }
+    }
+
+    pub fn load(&self) -> io::Result<Option<String>> {
+        match fs::read_to_string(&self.path) {
+            Ok(cursor) => Ok(Some(cursor.trim().to_string()).filter(|cursor| !cursor.is_empty())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    // Write-then-rename so a crash never leaves a truncated cursor behind
+    pub fn save(&self, cursor: &str) -> io::Result<()> {
+        let tmp = self.path.with_extension("tmp");
+        fs::write(&tmp, cursor)?;
+        fs::rename(&tmp, &self.path)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let file = CursorFile::new(std::env::temp_dir().join("indexer-test.cursor"));
+        let _ = fs::remove_file(&file.path);
+        assert_eq!(file.load().unwrap(), None);
+
+        file.save("0000000042-0000000001").unwrap();
+        assert_eq!(file.load().unwrap().as_deref(), Some("0000000042-0000000001"));
+        fs::remove_file(&file.path).unwrap();
+    }
+}
@@ -0,0 +1,226 @@
+use std::fmt;
+
+use stellar_xdr::curr::{Limits, ReadXdr, ScMap, ScVal};
+
+// Topic layout from EVENT_SCHEMA_VERSION 2: (version, event, hash), data (actor, struct)
+const SCHEMA_TOPIC: &str = "v2";
+
+/// A contract event as Soroban RPC returns it, topics and value still XDR
+#[derive(Clone, Debug, PartialEq)]
+pub struct RawEvent {
+    pub id: String,
+    pub ledger: u32,
+    pub ledger_closed_at: String,
+    pub topic: Vec<String>,  // base64 ScVal per topic
+    pub value: String,       // base64 ScVal
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StaffEventKind {
+    Registered,
+    Revoked,
+}
+
+impl StaffEventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StaffEventKind::Registered => "staff_reg",
+            StaffEventKind::Revoked => "staff_rev",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StaffEvent {
+    pub event_id: String,
+    pub ledger: u32,
+    pub kind: StaffEventKind,
+    pub staff_hash: [u8; 32],
+    pub actor: String,
+    pub registered_by: String,
+    pub registered_at: u64,
+    pub status: String,
+    pub metadata_uri: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchEvent {
+    pub event_id: String,
+    pub ledger: u32,
+    pub batch_hash: [u8; 32],
+    pub actor: String,
+    pub uploaded_by: String,
+    pub timestamp: u64,
+    pub staff_count: u32,
+    pub merkle_root: [u8; 32],
+    pub prev_batch_hash: [u8; 32],
+    pub is_valid: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum RegistryEvent {
+    Staff(StaffEvent),
+    Batch(BatchEvent),
+}
+
+impl RegistryEvent {
+    pub fn event_id(&self) -> &str {
+        match self {
+            RegistryEvent::Staff(event) => &event.event_id,
+            RegistryEvent::Batch(event) => &event.event_id,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DecodeError(pub String);
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+fn err<T>(message: impl Into<String>) -> Result<T, DecodeError> {
+    Err(DecodeError(message.into()))
+}
+
+fn scval(b64: &str) -> Result<ScVal, DecodeError> {
+    ScVal::from_xdr_base64(b64, Limits::none()).map_err(|e| DecodeError(format!("bad XDR: {}", e)))
+}
+
+fn symbol(val: &ScVal) -> Option<String> {
+    match val {
+        ScVal::Symbol(symbol) => Some(symbol.0.to_utf8_string_lossy()),
+        _ => None,
+    }
+}
+
+fn hash(val: &ScVal) -> Result<[u8; 32], DecodeError> {
+    match val {
+        ScVal::Bytes(bytes) => bytes.0.as_slice().try_into().or_else(|_| err("hash is not 32 bytes")),
+        _ => err("expected a hash"),
+    }
+}
+
+fn address(val: &ScVal) -> Result<String, DecodeError> {
+    match val {
+        ScVal::Address(address) => Ok(address.to_string()),
+        _ => err("expected an address"),
+    }
+}
+
+fn field<'a>(map: &'a ScMap, name: &str) -> Result<&'a ScVal, DecodeError> {
+    map.0
+        .iter()
+        .find(|entry| symbol(&entry.key).as_deref() == Some(name))
+        .map(|entry| &entry.val)
+        .ok_or_else(|| DecodeError(format!("missing field {}", name)))
+}
+
+fn u64_field(map: &ScMap, name: &str) -> Result<u64, DecodeError> {
+    match field(map, name)? {
+        ScVal::U64(value) => Ok(*value),
+        _ => err(format!("{} is not a u64", name)),
+    }
+}
+
+// Unit enum variants such as StaffStatus encode as a one-symbol vector
+fn variant(val: &ScVal) -> Result<String, DecodeError> {
+    match val {
+        ScVal::Vec(Some(items)) => items.0.first().and_then(symbol).ok_or_else(|| DecodeError("bad enum".into())),
+        _ => err("expected an enum"),
+    }
+}
+
+/// Decode a staff-registry event. Events other than v2 staff_reg / staff_rev /
+/// batch_rec are skipped (`Ok(None)`); a matching event that doesn't parse is an error.
+pub fn decode(raw: &RawEvent) -> Result<Option<RegistryEvent>, DecodeError> {
+    let [version, name, _hash] = raw.topic.as_slice() else {
+        return Ok(None);
+    };
+    if symbol(&scval(version)?).as_deref() != Some(SCHEMA_TOPIC) {
+        return Ok(None);
+    }
+    let name = symbol(&scval(name)?);
+    let kind = match name.as_deref() {
+        Some("staff_reg") => Some(StaffEventKind::Registered),
+        Some("staff_rev") => Some(StaffEventKind::Revoked),
+        Some("batch_rec") => None,
+        _ => return Ok(None),
+    };
+
+    let ScVal::Vec(Some(data)) = scval(&raw.value)? else {
+        return err("event data is not a tuple");
+    };
+    let (Some(actor), Some(ScVal::Map(Some(record)))) = (data.0.first(), data.0.get(1)) else {
+        return err("event data is not (actor, record)");
+    };
+    let actor = address(actor)?;
+
+    Ok(Some(match kind {
+        Some(kind) => RegistryEvent::Staff(StaffEvent {
+            event_id: raw.id.clone(),
+            ledger: raw.ledger,
+            kind,
+            staff_hash: hash(field(record, "staff_hash")?)?,
+            actor,
+            registered_by: address(field(record, "registered_by")?)?,
+            registered_at: u64_field(record, "registered_at")?,
+            status: variant(field(record, "status")?)?,
+            metadata_uri: match field(record, "metadata_uri")? {
+                ScVal::String(uri) => uri.0.to_utf8_string_lossy(),
+                _ => return err("metadata_uri is not a string"),
+            },
+        }),
+        None => RegistryEvent::Batch(BatchEvent {
+            event_id: raw.id.clone(),
+            ledger: raw.ledger,
+            batch_hash: hash(field(record, "batch_hash")?)?,
+            actor,
+            uploaded_by: address(field(record, "uploaded_by")?)?,
+            timestamp: u64_field(record, "timestamp")?,
+            staff_count: match field(record, "staff_count")? {
+                ScVal::U32(count) => *count,
+                _ => return err("staff_count is not a u32"),
+            },
+            merkle_root: hash(field(record, "merkle_root")?)?,
+            prev_batch_hash: hash(field(record, "prev_batch_hash")?)?,
+            is_valid: matches!(field(record, "is_valid")?, ScVal::Bool(true)),
+        }),
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use stellar_xdr::curr::{ScSymbol, WriteXdr};
+
+    fn b64(val: ScVal) -> String {
+        val.to_xdr_base64(Limits::none()).unwrap()
+    }
+
+    fn sym(name: &str) -> ScVal {
+        ScVal::Symbol(ScSymbol(name.try_into().unwrap()))
+    }
+
+    #[test]
+    fn test_skips_other_events() {
+        let raw = RawEvent {
+            id: "1".into(),
+            ledger: 1,
+            ledger_closed_at: String::new(),
+            topic: vec![b64(sym("v2")), b64(sym("staff_sus")), b64(ScVal::Void)],
+            value: b64(ScVal::Void),
+        };
+        assert_eq!(decode(&raw), Ok(None));
+
+        let legacy = RawEvent { topic: vec![b64(sym("staff_reg")), b64(ScVal::Void)], ..raw.clone() };
+        assert_eq!(decode(&legacy), Ok(None));
+
+        let broken = RawEvent { topic: vec![b64(sym("v2")), b64(sym("batch_rec")), b64(ScVal::Void)], ..raw };
+        assert!(decode(&broken).is_err());
+    }
+}
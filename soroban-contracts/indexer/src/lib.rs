@@ -0,0 +1,16 @@
+//! Event indexer for the staff-registry contract.
+//!
+//! Pages through `getEvents` on Soroban RPC, decodes the v2 `staff_reg`,
+//! `staff_rev` and `batch_rec` events into typed records, and emits them as SQL
+//! that runs unchanged on SQLite and Postgres. Every page is one transaction that
+//! also stores the RPC cursor, so a restarted indexer resumes where it stopped
+//! and replayed pages are ignored.
+
+pub mod cursor;
+pub mod decode;
+pub mod rpc;
+pub mod sink;
+
+pub use decode::{decode, BatchEvent, RawEvent, RegistryEvent, StaffEvent, StaffEventKind};
+pub use rpc::{Page, Position, RpcClient};
+pub use sink::SqlSink;
@@ -0,0 +1,95 @@
+//! `indexer`: stream staff-registry events into a database.
+//!
+//!     indexer --rpc-url https://soroban-testnet.stellar.org --contract-id C... \
+//!         --start-ledger 123456 --follow true | sqlite3 payroll.db
+//!
+//! SQL goes to stdout (pipe it into `sqlite3` or `psql`); the resume cursor is
+//! saved to `--cursor-file` after each page has been written. To resume from what
+//! the database actually committed instead, pass `--cursor` with the value of
+//! `indexer_cursor.cursor`.
+
+use std::collections::HashMap;
+use std::env;
+use std::io;
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
+
+use indexer::cursor::CursorFile;
+use indexer::{decode, Position, RpcClient, SqlSink};
+
+const PAGE_SIZE: u32 = 100;
+
+fn options() -> Result<HashMap<String, String>, String> {
+    let mut options = HashMap::new();
+    let mut argv = env::args().skip(1);
+    while let Some(flag) = argv.next() {
+        let name = flag.strip_prefix("--").ok_or_else(|| format!("unexpected argument: {}", flag))?;
+        let value = argv.next().ok_or_else(|| format!("missing value for --{}", name))?;
+        options.insert(name.to_string(), value);
+    }
+    Ok(options)
+}
+
+fn run() -> Result<(), String> {
+    let options = options()?;
+    let get = |name: &str| options.get(name).cloned();
+    let require = |name: &str| get(name).ok_or_else(|| format!("missing --{}", name));
+
+    let rpc = RpcClient {
+        url: require("rpc-url")?,
+        contract_id: require("contract-id")?,
+        curl: get("curl").unwrap_or_else(|| "curl".to_string()),
+    };
+    let cursor_file = CursorFile::new(get("cursor-file").unwrap_or_else(|| "indexer.cursor".to_string()));
+    let follow = get("follow").as_deref() == Some("true");
+    let poll = Duration::from_secs(get("poll-seconds").map_or(Ok(5), |s| s.parse()).map_err(|_| "bad --poll-seconds")?);
+
+    let mut position = match get("cursor").or(cursor_file.load().map_err(|e| e.to_string())?) {
+        Some(cursor) => Position::Cursor(cursor),
+        None => Position::Ledger(require("start-ledger")?.parse().map_err(|_| "bad --start-ledger")?),
+    };
+
+    let mut sink = SqlSink::new(io::stdout().lock());
+    sink.write_schema().map_err(|e| e.to_string())?;
+
+    loop {
+        let page = rpc.fetch(&position, PAGE_SIZE)?;
+        let Some(cursor) = page.cursor.clone() else {
+            if !follow {
+                return Ok(());
+            }
+            thread::sleep(poll);
+            continue;
+        };
+
+        let mut events = Vec::new();
+        for raw in &page.events {
+            if let Some(event) = decode(raw).map_err(|e| format!("event {}: {}", raw.id, e))? {
+                events.push(event);
+            }
+        }
+        let ledger = page.events.last().map_or(page.latest_ledger, |event| event.ledger);
+        sink.write_page(&events, &cursor, ledger).map_err(|e| e.to_string())?;
+        cursor_file.save(&cursor).map_err(|e| e.to_string())?;
+        eprintln!("indexed {} of {} events up to ledger {}", events.len(), page.events.len(), ledger);
+
+        position = Position::Cursor(cursor);
+        if page.events.len() < PAGE_SIZE as usize {
+            if !follow {
+                return Ok(());
+            }
+            thread::sleep(poll);
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use std::process::Command;
+
+use serde_json::{json, Value};
+
+use crate::decode::RawEvent;
+
+/// Where the next `getEvents` page starts
+#[derive(Clone, Debug, PartialEq)]
+pub enum Position {
+    Ledger(u32),
+    Cursor(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Page {
+    pub events: Vec<RawEvent>,
+    pub cursor: Option<String>,  // resume point after this page; none when it was empty
+    pub latest_ledger: u32,
+}
+
+// JSON-RPC over HTTP via curl, so the indexer needs no TLS or HTTP stack of its own
+pub struct RpcClient {
+    pub url: String,
+    pub contract_id: String,
+    pub curl: String,
+}
+
+impl RpcClient {
+    pub fn request_body(&self, from: &Position, limit: u32) -> String {
+        let filters = json!([{ "type": "contract", "contractIds": [self.contract_id] }]);
+        let params = match from {
+            Position::Ledger(ledger) => json!({
+                "startLedger": ledger,
+                "filters": filters,
+                "pagination": { "limit": limit },
+            }),
+            Position::Cursor(cursor) => json!({
+                "filters": filters,
+                "pagination": { "cursor": cursor, "limit": limit },
+            }),
+        };
+        json!({ "jsonrpc": "2.0", "id": 1, "method": "getEvents", "params": params }).to_string()
+    }
+
+    pub fn fetch(&self, from: &Position, limit: u32) -> Result<Page, String> {
+        let output = Command::new(&self.curl)
+            .args(["-sS", "--fail", "-X", "POST", "-H", "Content-Type: application/json"])
+            .args(["--data", &self.request_body(from, limit), &self.url])
+            .output()
+            .map_err(|e| format!("failed to run {}: {}", self.curl, e))?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+        parse_response(&String::from_utf8_lossy(&output.stdout))
+    }
+}
+
+/// Parse a `getEvents` response. Older RPC versions wrap values as `{"xdr": ..}`
+/// and have no top-level cursor; the last event's paging token is used instead.
+pub fn parse_response(body: &str) -> Result<Page, String> {
+    let response: Value = serde_json::from_str(body).map_err(|e| format!("bad response: {}", e))?;
+    if let Some(error) = response.get("error") {
+        return Err(format!("rpc error: {}", error));
+    }
+    let result = response.get("result").ok_or("response has no result")?;
+
+    let text = |event: &Value, name: &str| event.get(name).and_then(Value::as_str).map(str::to_string);
+    let mut events = Vec::new();
+    for event in result.get("events").and_then(Value::as_array).into_iter().flatten() {
+        let value = event.get("value").ok_or("event has no value")?;
+        events.push(RawEvent {
+            id: text(event, "id").ok_or("event has no id")?,
+            ledger: event.get("ledger").and_then(Value::as_u64).ok_or("event has no ledger")? as u32,
+            ledger_closed_at: text(event, "ledgerClosedAt").unwrap_or_default(),
+            topic: event
+                .get("topic")
+                .and_then(Value::as_array)
+                .ok_or("event has no topic")?
+                .iter()
+                .filter_map(|topic| topic.as_str().map(str::to_string))
+                .collect(),
+            value: value
+                .as_str()
+                .or_else(|| value.get("xdr").and_then(Value::as_str))
+                .ok_or("event value is not XDR")?
+                .to_string(),
+        });
+    }
+
+    let last_token = result
+        .get("events")
+        .and_then(Value::as_array)
+        .and_then(|events| events.last())
+        .and_then(|event| text(event, "pagingToken").or_else(|| text(event, "id")));
+    Ok(Page {
+        events,
+        cursor: text(result, "cursor").filter(|cursor| !cursor.is_empty()).or(last_token),
+        latest_ledger: result.get("latestLedger").and_then(Value::as_u64).unwrap_or(0) as u32,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_request_body() {
+        let rpc = RpcClient { url: String::new(), contract_id: "CABC".into(), curl: "curl".into() };
+
+        let first: Value = serde_json::from_str(&rpc.request_body(&Position::Ledger(100), 50)).unwrap();
+        assert_eq!(first["params"]["startLedger"], 100);
+        assert_eq!(first["params"]["filters"][0]["contractIds"][0], "CABC");
+
+        let next: Value = serde_json::from_str(&rpc.request_body(&Position::Cursor("42-1".into()), 50)).unwrap();
+        assert_eq!(next["params"]["pagination"]["cursor"], "42-1");
+        assert!(next["params"].get("startLedger").is_none());
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let body = r#"{"jsonrpc":"2.0","id":1,"result":{"latestLedger":130,"events":[
+            {"id":"0000000042-0000000001","pagingToken":"0000000042-0000000001","ledger":42,
+             "ledgerClosedAt":"2026-08-01T00:00:00Z","topic":["AAAADw=="],"value":{"xdr":"AAAAAQ=="}}]}}"#;
+        let page = parse_response(body).unwrap();
+        assert_eq!(page.latest_ledger, 130);
+        assert_eq!(page.events[0].ledger, 42);
+        assert_eq!(page.events[0].value, "AAAAAQ==");
+        assert_eq!(page.cursor.as_deref(), Some("0000000042-0000000001"));
+
+        let empty = parse_response(r#"{"result":{"latestLedger":131,"events":[],"cursor":""}}"#).unwrap();
+        assert_eq!(empty.cursor, None);
+        assert!(parse_response(r#"{"error":{"code":-32600}}"#).is_err());
+    }
+}
@@ -0,0 +1,132 @@
+use std::io::{self, Write};
+
+use crate::decode::RegistryEvent;
+
+// Plain SQL both SQLite and Postgres accept: TEXT/BIGINT/BOOLEAN columns, and
+// ON CONFLICT clauses so replaying a page after a restart is a no-op
+pub const SCHEMA: &str = "\
+CREATE TABLE IF NOT EXISTS staff_events (
+    event_id TEXT PRIMARY KEY,
+    ledger BIGINT NOT NULL,
+    kind TEXT NOT NULL,
+    staff_hash TEXT NOT NULL,
+    actor TEXT NOT NULL,
+    registered_by TEXT NOT NULL,
+    registered_at BIGINT NOT NULL,
+    status TEXT NOT NULL,
+    metadata_uri TEXT NOT NULL
+);
+CREATE INDEX IF NOT EXISTS staff_events_hash ON staff_events (staff_hash);
+CREATE TABLE IF NOT EXISTS batch_events (
+    event_id TEXT PRIMARY KEY,
+    ledger BIGINT NOT NULL,
+    batch_hash TEXT NOT NULL,
+    actor TEXT NOT NULL,
+    uploaded_by TEXT NOT NULL,
+    timestamp BIGINT NOT NULL,
+    staff_count BIGINT NOT NULL,
+    merkle_root TEXT NOT NULL,
+    prev_batch_hash TEXT NOT NULL,
+    is_valid BOOLEAN NOT NULL
+);
+CREATE INDEX IF NOT EXISTS batch_events_hash ON batch_events (batch_hash);
+CREATE TABLE IF NOT EXISTS indexer_cursor (
+    id INTEGER PRIMARY KEY,
+    cursor TEXT NOT NULL,
+    ledger BIGINT NOT NULL
+);
+";
+
+fn hex(bytes: &[u8; 32]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn quote(text: &str) -> String {
+    format!("'{}'", text.replace('\'', "''"))
+}
+
+/// Writes schema, events and cursor updates as SQL to a stream, e.g. the stdin
+/// of `sqlite3 payroll.db` or `psql`
+pub struct SqlSink<W: Write> {
+    out: W,
+}
+
+impl<W: Write> SqlSink<W> {
+    pub fn new(out: W) -> Self {
+        SqlSink { out }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+
+    pub fn write_schema(&mut self) -> io::Result<()> {
+        self.out.write_all(SCHEMA.as_bytes())?;
+        self.out.flush()
+    }
+
+    /// One page as one transaction: its events, then the cursor to resume from
+    pub fn write_page(&mut self, events: &[RegistryEvent], cursor: &str, ledger: u32) -> io::Result<()> {
+        writeln!(self.out, "BEGIN;")?;
+        for event in events {
+            writeln!(self.out, "{}", insert(event))?;
+        }
+        writeln!(
+            self.out,
+            "INSERT INTO indexer_cursor (id, cursor, ledger) VALUES (1, {}, {}) \
+             ON CONFLICT (id) DO UPDATE SET cursor = excluded.cursor, ledger = excluded.ledger;",
+            quote(cursor),
+            ledger
+        )?;
+        writeln!(self.out, "COMMIT;")?;
+        self.out.flush()
+    }
+}
+
+fn insert(event: &RegistryEvent) -> String {
+    match event {
+        RegistryEvent::Staff(e) => format!(
+            "INSERT INTO staff_events (event_id, ledger, kind, staff_hash, actor, registered_by, registered_at, status, metadata_uri) \
+             VALUES ({}, {}, {}, {}, {}, {}, {}, {}, {}) ON CONFLICT (event_id) DO NOTHING;",
+            quote(&e.event_id), e.ledger, quote(e.kind.as_str()), quote(&hex(&e.staff_hash)), quote(&e.actor),
+            quote(&e.registered_by), e.registered_at, quote(&e.status), quote(&e.metadata_uri)
+        ),
+        RegistryEvent::Batch(e) => format!(
+            "INSERT INTO batch_events (event_id, ledger, batch_hash, actor, uploaded_by, timestamp, staff_count, merkle_root, prev_batch_hash, is_valid) \
+             VALUES ({}, {}, {}, {}, {}, {}, {}, {}, {}, {}) ON CONFLICT (event_id) DO NOTHING;",
+            quote(&e.event_id), e.ledger, quote(&hex(&e.batch_hash)), quote(&e.actor), quote(&e.uploaded_by),
+            e.timestamp, e.staff_count, quote(&hex(&e.merkle_root)), quote(&hex(&e.prev_batch_hash)),
+            if e.is_valid { "TRUE" } else { "FALSE" }
+        ),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::decode::{StaffEvent, StaffEventKind};
+
+    #[test]
+    fn test_page_is_one_transaction() {
+        let event = RegistryEvent::Staff(StaffEvent {
+            event_id: "42-1".into(),
+            ledger: 42,
+            kind: StaffEventKind::Registered,
+            staff_hash: [1u8; 32],
+            actor: "GABC".into(),
+            registered_by: "GABC".into(),
+            registered_at: 1_000,
+            status: "Active".into(),
+            metadata_uri: "ipfs://o'brien".into(),
+        });
+
+        let mut sink = SqlSink::new(Vec::new());
+        sink.write_page(&[event], "42-1", 42).unwrap();
+        let sql = String::from_utf8(sink.into_inner()).unwrap();
+
+        let lines: Vec<&str> = sql.lines().collect();
+        assert_eq!((lines[0], lines[lines.len() - 1]), ("BEGIN;", "COMMIT;"));
+        assert!(lines[1].contains("'staff_reg'") && lines[1].contains("'ipfs://o''brien'"));
+        assert!(lines[2].starts_with("INSERT INTO indexer_cursor") && lines[2].contains("'42-1', 42"));
+    }
+}
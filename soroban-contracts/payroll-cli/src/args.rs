@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+use std::env;
+
+use payroll_verify::HashMode;
+
+// `--name value` options after the subcommand, with environment fallbacks for
+// the connection settings so they don't need repeating on every call
+pub struct Args {
+    pub command: String,
+    options: HashMap<String, String>,
+}
+
+impl Args {
+    pub fn parse(mut argv: impl Iterator<Item = String>) -> Result<Args, String> {
+        let command = argv.next().ok_or("missing subcommand")?;
+
+        let mut options = HashMap::new();
+        while let Some(flag) = argv.next() {
+            let name = flag.strip_prefix("--").ok_or_else(|| format!("unexpected argument: {}", flag))?;
+            let value = argv.next().ok_or_else(|| format!("missing value for --{}", name))?;
+            if options.insert(name.to_string(), value).is_some() {
+                return Err(format!("--{} given twice", name));
+            }
+        }
+        Ok(Args { command, options })
+    }
+
+    pub fn get(&self, name: &str) -> Option<String> {
+        self.options.get(name).cloned().or_else(|| {
+            let var = format!("PAYROLL_{}", name.to_uppercase().replace('-', "_"));
+            env::var(var).ok()
+        })
+    }
+
+    pub fn require(&self, name: &str) -> Result<String, String> {
+        self.get(name).ok_or_else(|| format!("missing --{}", name))
+    }
+
+    // Passphrase of the target network, which v2 staff hashes take their chain domain from
+    pub fn network_passphrase(&self) -> Result<String, String> {
+        if let Some(passphrase) = self.get("network-passphrase") {
+            return Ok(passphrase);
+        }
+        match self.get("network").as_deref() {
+            None | Some("testnet") => Ok("Test SDF Network ; September 2015".to_string()),
+            Some("futurenet") => Ok("Test SDF Future Network ; October 2022".to_string()),
+            Some("mainnet") | Some("pubnet") => Ok("Public Global Stellar Network ; September 2015".to_string()),
+            Some(other) => Err(format!("unknown network {}; give --network-passphrase", other)),
+        }
+    }
+
+    pub fn hash_mode(&self) -> Result<HashMode, String> {
+        match self.get("hash-mode").as_deref() {
+            None | Some("sha256") => Ok(HashMode::Sha256),
+            Some("keccak256") => Ok(HashMode::Keccak256),
+            Some(other) => Err(format!("unknown hash mode: {}", other)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, String> {
+        Args::parse(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let args = parse(&["register-staff", "--caller", "GABC", "--hash-mode", "keccak256"]).unwrap();
+        assert_eq!(args.command, "register-staff");
+        assert_eq!(args.get("caller").as_deref(), Some("GABC"));
+        assert_eq!(args.hash_mode(), Ok(HashMode::Keccak256));
+        assert_eq!(args.require("staff-hash"), Err("missing --staff-hash".to_string()));
+
+        assert!(parse(&["record-batch", "--caller"]).is_err());
+        assert!(parse(&["record-batch", "caller"]).is_err());
+        assert!(parse(&["record-batch", "--caller", "A", "--caller", "B"]).is_err());
+    }
+}
@@ -0,0 +1,171 @@
+use std::fs;
+
+use payroll_verify::batch::to_hex;
+use payroll_verify::hashing::{network_id, parse_hash, staff_hash_v2};
+use payroll_verify::{parse_line_items, parse_rows, Batch, MerkleTree, PayrollRow};
+
+use crate::args::Args;
+use crate::rpc::Rpc;
+
+// Staff hash from --staff-hash, or the domain-separated hash the deployment derives
+// from --preimage for the main registry
+fn staff_hash_arg(args: &Args) -> Result<[u8; 32], String> {
+    match (args.get("staff-hash"), args.get("preimage")) {
+        (Some(hash), None) => parse_hash(&hash).map_err(|err| err.to_string()),
+        (None, Some(preimage)) => {
+            let contract_id = args.require("contract-id")?;
+            let contract = stellar_strkey::Contract::from_string(&contract_id)
+                .map_err(|_| format!("invalid contract id: {}", contract_id))?;
+            let chain_domain = network_id(&args.network_passphrase()?);
+            staff_hash_v2(args.hash_mode()?, preimage.as_bytes(), "", &chain_domain, &contract.0).map_err(|err| err.to_string())
+        }
+        _ => Err("give exactly one of --staff-hash or --preimage".to_string()),
+    }
+}
+
+fn read_rows(args: &Args) -> Result<Vec<PayrollRow>, String> {
+    let path = args.require("csv")?;
+    let input = fs::read_to_string(&path).map_err(|err| format!("{}: {}", path, err))?;
+    parse_rows(&input).map_err(|err| format!("{}: {}", path, err))
+}
+
+fn tree(args: &Args, rows: &[PayrollRow]) -> Result<MerkleTree, String> {
+    let mode = args.hash_mode()?;
+    MerkleTree::new(mode, rows.iter().map(|row| row.leaf(mode)).collect()).map_err(|err| err.to_string())
+}
+
+// A preimage goes through register_staff_v2 so the contract derives the hash itself;
+// a raw --staff-hash is only accepted by deployments that allow legacy hashes
+pub fn register_staff(args: &Args) -> Result<String, String> {
+    let caller = args.require("caller")?;
+    if let (Some(preimage), None) = (args.get("preimage"), args.get("staff-hash")) {
+        let preimage_hex = preimage.bytes().map(|byte| format!("{:02x}", byte)).collect();
+        return Rpc::from_args(args)?.invoke("register_staff_v2", &[("caller", caller), ("preimage", preimage_hex)]);
+    }
+    let hash = staff_hash_arg(args)?;
+    Rpc::from_args(args)?.invoke("register_staff", &[("caller", caller), ("staff_hash", to_hex(&hash))])
+}
+
+pub fn revoke_staff(args: &Args) -> Result<String, String> {
+    let hash = staff_hash_arg(args)?;
+    Rpc::from_args(args)?.invoke("revoke_staff", &[("caller", args.require("caller")?), ("staff_hash", to_hex(&hash))])
+}
+
+// With --line-items every argument, batch hash included, is derived from the canonical
+// batch; with --csv the staff count and merkle root come from the export; otherwise
+// --staff-count is recorded alone
+pub fn record_batch(args: &Args) -> Result<String, String> {
+    let rpc = Rpc::from_args(args)?;
+    let caller = args.require("caller")?;
+
+    if let Some(path) = args.get("line-items") {
+        let input = fs::read_to_string(&path).map_err(|err| format!("{}: {}", path, err))?;
+        let items = parse_line_items(&input).map_err(|err| format!("{}: {}", path, err))?;
+        let batch = Batch::build(args.hash_mode()?, items).map_err(|err| format!("{}: {}", path, err))?.args();
+        return rpc.invoke(
+            "record_payroll_batch_with_root",
+            &[
+                ("caller", caller),
+                ("batch_hash", to_hex(&batch.batch_hash)),
+                ("staff_count", batch.staff_count.to_string()),
+                ("merkle_root", to_hex(&batch.merkle_root)),
+            ],
+        );
+    }
+
+    let batch_hash = to_hex(&parse_hash(&args.require("batch-hash")?).map_err(|err| err.to_string())?);
+
+    if args.get("csv").is_some() {
+        let rows = read_rows(args)?;
+        let root = tree(args, &rows)?.root();
+        rpc.invoke(
+            "record_payroll_batch_with_root",
+            &[("caller", caller), ("batch_hash", batch_hash), ("staff_count", rows.len().to_string()), ("merkle_root", to_hex(&root))],
+        )
+    } else {
+        rpc.invoke("record_payroll_batch", &[("caller", caller), ("batch_hash", batch_hash), ("staff_count", args.require("staff-count")?)])
+    }
+}
+
+// Build a staff member's proof from the payroll export; with --batch-hash it is also checked on-chain
+pub fn verify_inclusion(args: &Args) -> Result<String, String> {
+    let rows = read_rows(args)?;
+    let hash = staff_hash_arg(args)?;
+    let index = rows
+        .iter()
+        .position(|row| row.staff_hash == hash)
+        .ok_or("staff hash not in the payroll export")?;
+
+    let tree = tree(args, &rows)?;
+    let leaf = tree.leaves()[index];
+    let proof: Vec<String> = tree.proof(index).map_err(|err| err.to_string())?.iter().map(to_hex).collect();
+    let proof_json = format!("[{}]", proof.iter().map(|node| format!("\"{}\"", node)).collect::<Vec<_>>().join(","));
+
+    let on_chain = match args.get("batch-hash") {
+        Some(batch_hash) => Rpc::from_args(args)?.invoke(
+            "verify_inclusion",
+            &[("batch_hash", batch_hash), ("leaf", to_hex(&leaf)), ("proof", proof_json.clone())],
+        )?,
+        None => "null".to_string(),
+    };
+
+    Ok(format!(
+        "{{\"leaf\":\"{}\",\"root\":\"{}\",\"proof\":{},\"verified\":{}}}",
+        to_hex(&leaf),
+        to_hex(&tree.root()),
+        proof_json,
+        on_chain
+    ))
+}
+
+// Snapshot of the registry for auditors; written to --out or printed
+pub fn export_report(args: &Args) -> Result<String, String> {
+    let rpc = Rpc::from_args(args)?;
+    let mut fields = Vec::new();
+    for function in ["contract_info", "get_total_staff", "get_total_batches", "get_latest_batch"] {
+        fields.push(format!("\"{}\":{}", function, rpc.invoke(function, &[])?));
+    }
+    let report = format!("{{{}}}", fields.join(","));
+
+    match args.get("out") {
+        Some(path) => {
+            fs::write(&path, &report).map_err(|err| format!("{}: {}", path, err))?;
+            Ok(format!("report written to {}", path))
+        }
+        None => Ok(report),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn args(list: &[&str]) -> Args {
+        Args::parse(list.iter().map(|arg| arg.to_string())).unwrap()
+    }
+
+    #[test]
+    fn test_verify_inclusion_offline() {
+        let path = std::env::temp_dir().join("payroll-cli-test.csv");
+        let first = "01".repeat(32);
+        let second = "02".repeat(32);
+        fs::write(&path, format!("staff_hash,amount\n{},100\n{},200\n", first, second)).unwrap();
+
+        let report = verify_inclusion(&args(&["verify-inclusion", "--csv", path.to_str().unwrap(), "--staff-hash", &second])).unwrap();
+        assert!(report.contains("\"verified\":null"));
+        assert!(report.contains(&to_hex(&payroll_verify::payslip_leaf(payroll_verify::HashMode::Sha256, &[2u8; 32], 200))));
+
+        let missing = verify_inclusion(&args(&["verify-inclusion", "--csv", path.to_str().unwrap(), "--staff-hash", &"03".repeat(32)]));
+        assert_eq!(missing, Err("staff hash not in the payroll export".to_string()));
+    }
+
+    #[test]
+    fn test_staff_hash_from_preimage() {
+        let contract_id = stellar_strkey::Contract([7u8; 32]).to_string();
+        let derived = staff_hash_arg(&args(&["register-staff", "--preimage", "EMP-0001", "--contract-id", &contract_id])).unwrap();
+        let chain_domain = network_id("Test SDF Network ; September 2015");
+        assert_eq!(derived, staff_hash_v2(payroll_verify::HashMode::Sha256, b"EMP-0001", "", &chain_domain, &[7u8; 32]).unwrap());
+        assert!(staff_hash_arg(&args(&["register-staff", "--preimage", "EMP-0001"])).is_err());
+        assert!(staff_hash_arg(&args(&["register-staff"])).is_err());
+    }
+}
@@ -0,0 +1,60 @@
+//! `payroll-cli`: operate a staff-registry deployment without hand-crafting XDR.
+//!
+//! Contract calls are submitted through the Stellar CLI (`soroban`, or whatever
+//! `--soroban-bin` names), so signing keys stay in its key store. Staff hashes,
+//! merkle roots and proofs are computed locally with `payroll-verify`.
+
+use std::env;
+use std::process::ExitCode;
+
+mod args;
+mod commands;
+mod rpc;
+
+use args::Args;
+
+const USAGE: &str = "usage: payroll-cli <command> [--option value ...]
+
+commands:
+  register-staff    --caller ADDR (--staff-hash HEX | --preimage TEXT)
+  revoke-staff      --caller ADDR (--staff-hash HEX | --preimage TEXT)
+  record-batch      --caller ADDR (--line-items FILE | --batch-hash HEX (--csv FILE | --staff-count N))
+  verify-inclusion  --csv FILE (--staff-hash HEX | --preimage TEXT) [--batch-hash HEX]
+  export-report     [--out FILE]
+
+connection (or PAYROLL_CONTRACT_ID, PAYROLL_SOURCE, ... in the environment):
+  --contract-id ID  --source KEY  --network NAME (testnet)
+  --soroban-bin PATH (soroban)  --hash-mode sha256|keccak256  --dry-run true
+  --network-passphrase TEXT (derived from --network for testnet, futurenet, mainnet)
+
+--preimage staff hashes are the domain-separated v2 hash for this deployment and network;
+register-staff sends the preimage to register_staff_v2 so the contract derives it
+
+payroll CSV rows are `staff_hash,amount` with amounts in minor units;
+line-item rows are `staff_hash,period_id,currency,gross,net`";
+
+fn run() -> Result<String, String> {
+    let args = Args::parse(env::args().skip(1))?;
+    match args.command.as_str() {
+        "register-staff" => commands::register_staff(&args),
+        "revoke-staff" => commands::revoke_staff(&args),
+        "record-batch" => commands::record_batch(&args),
+        "verify-inclusion" => commands::verify_inclusion(&args),
+        "export-report" => commands::export_report(&args),
+        "help" => Ok(USAGE.to_string()),
+        other => Err(format!("unknown command: {}", other)),
+    }
+}
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(output) => {
+            println!("{}", output);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
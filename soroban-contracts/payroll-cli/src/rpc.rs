@@ -0,0 +1,85 @@
+use std::process::Command;
+
+use crate::args::Args;
+
+// Contract calls go through the Stellar CLI, which already handles key storage,
+// simulation, fees, signing and submission against Soroban RPC
+pub struct Rpc {
+    binary: String,
+    contract_id: String,
+    source: String,
+    network: String,
+    dry_run: bool,
+}
+
+impl Rpc {
+    pub fn from_args(args: &Args) -> Result<Rpc, String> {
+        Ok(Rpc {
+            binary: args.get("soroban-bin").unwrap_or_else(|| "soroban".to_string()),
+            contract_id: args.require("contract-id")?,
+            source: args.require("source")?,
+            network: args.get("network").unwrap_or_else(|| "testnet".to_string()),
+            dry_run: args.get("dry-run").as_deref() == Some("true"),
+        })
+    }
+
+    // Full argument list for `<binary> contract invoke`
+    pub fn invocation(&self, function: &str, params: &[(&str, String)]) -> Vec<String> {
+        let mut argv: Vec<String> = [
+            "contract", "invoke",
+            "--id", &self.contract_id,
+            "--source", &self.source,
+            "--network", &self.network,
+            "--", function,
+        ]
+        .iter()
+        .map(|arg| arg.to_string())
+        .collect();
+        for (name, value) in params {
+            argv.push(format!("--{}", name));
+            argv.push(value.clone());
+        }
+        argv
+    }
+
+    // Run a contract function and return its JSON-encoded result
+    pub fn invoke(&self, function: &str, params: &[(&str, String)]) -> Result<String, String> {
+        let argv = self.invocation(function, params);
+        if self.dry_run {
+            return Ok(format!("{} {}", self.binary, argv.join(" ")));
+        }
+
+        let output = Command::new(&self.binary)
+            .args(&argv)
+            .output()
+            .map_err(|err| format!("failed to run {}: {}", self.binary, err))?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_invocation() {
+        let args = Args::parse(
+            ["record-batch", "--contract-id", "CABC", "--source", "hr", "--network", "futurenet"]
+                .iter()
+                .map(|arg| arg.to_string()),
+        )
+        .unwrap();
+        let rpc = Rpc::from_args(&args).unwrap();
+
+        assert_eq!(
+            rpc.invocation("record_payroll_batch", &[("caller", "GXYZ".to_string()), ("staff_count", "3".to_string())]),
+            [
+                "contract", "invoke", "--id", "CABC", "--source", "hr", "--network", "futurenet",
+                "--", "record_payroll_batch", "--caller", "GXYZ", "--staff_count", "3",
+            ]
+        );
+    }
+}
@@ -0,0 +1,208 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::hashing::{digest, parse_hash, HashMode};
+use crate::merkle::MerkleTree;
+use crate::Error;
+
+// Domain tags so a line encoding can never be mistaken for a batch header or a
+// v1 payslip leaf (staff hash || amount), which has no tag
+const LINE_TAG: &[u8] = b"payroll-line-v1";
+const BATCH_TAG: &[u8] = b"payroll-batch-v1";
+
+/// One payroll line item. Amounts are integers in minor units of `currency`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LineItem {
+    pub staff_hash: [u8; 32],
+    pub period_id: u32,      // YYYYMM, as in record_batch_for_period
+    pub currency: [u8; 3],   // ISO 4217 code, upper case
+    pub gross: i128,
+    pub net: i128,
+}
+
+impl LineItem {
+    pub fn new(staff_hash: [u8; 32], period_id: u32, currency: &str, gross: i128, net: i128) -> Result<Self, Error> {
+        let year = period_id / 100;
+        let month = period_id % 100;
+        if !(1970..=9999).contains(&year) || !(1..=12).contains(&month) {
+            return Err(Error::InvalidPeriod);
+        }
+        let code = currency.trim().as_bytes();
+        if code.len() != 3 || !code.iter().all(u8::is_ascii_alphabetic) {
+            return Err(Error::InvalidCurrency);
+        }
+        if gross < 0 || net < 0 || net > gross {
+            return Err(Error::InvalidAmount);
+        }
+
+        Ok(LineItem {
+            staff_hash,
+            period_id,
+            currency: [code[0].to_ascii_uppercase(), code[1].to_ascii_uppercase(), code[2].to_ascii_uppercase()],
+            gross,
+            net,
+        })
+    }
+
+    /// Canonical encoding: tag, staff hash, period (u32), currency (3 ASCII bytes),
+    /// gross and net (i128), all fixed width and big-endian
+    pub fn canonical_bytes(&self) -> [u8; 86] {
+        let mut out = [0u8; 86];
+        out[..15].copy_from_slice(LINE_TAG);
+        out[15..47].copy_from_slice(&self.staff_hash);
+        out[47..51].copy_from_slice(&self.period_id.to_be_bytes());
+        out[51..54].copy_from_slice(&self.currency);
+        out[54..70].copy_from_slice(&self.gross.to_be_bytes());
+        out[70..86].copy_from_slice(&self.net.to_be_bytes());
+        out
+    }
+
+    pub fn leaf(&self, mode: HashMode) -> [u8; 32] {
+        digest(mode, &self.canonical_bytes())
+    }
+}
+
+/// Exact arguments for `record_payroll_batch_with_root` (or, without the root,
+/// `record_payroll_batch` / `record_batch_for_period`)
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchArgs {
+    pub batch_hash: [u8; 32],
+    pub staff_count: u32,
+    pub merkle_root: [u8; 32],
+    pub period_id: u32,
+}
+
+/// A payroll batch in canonical form: one line per staff member, sorted by staff
+/// hash, so any two parties holding the same payroll derive the same root and
+/// batch hash whatever order their export lists it in
+pub struct Batch {
+    items: Vec<LineItem>,
+    tree: MerkleTree,
+}
+
+impl Batch {
+    pub fn build(mode: HashMode, mut items: Vec<LineItem>) -> Result<Self, Error> {
+        items.sort_by_key(|item| item.staff_hash);
+        if items.windows(2).any(|pair| pair[0].staff_hash == pair[1].staff_hash) {
+            return Err(Error::DuplicateStaff);
+        }
+        if items.first().is_some_and(|first| items.iter().any(|item| item.period_id != first.period_id)) {
+            return Err(Error::MixedPeriods);
+        }
+
+        let tree = MerkleTree::new(mode, items.iter().map(|item| item.leaf(mode)).collect())?;
+        Ok(Batch { items, tree })
+    }
+
+    pub fn items(&self) -> &[LineItem] {
+        &self.items
+    }
+
+    /// Batch hash commits to the hash mode's root, the headcount and the period:
+    /// digest(tag || root || staff_count u32 || period_id u32)
+    pub fn args(&self) -> BatchArgs {
+        let root = self.tree.root();
+        let staff_count = self.items.len() as u32;
+        let period_id = self.items[0].period_id;
+
+        let mut header = Vec::with_capacity(BATCH_TAG.len() + 40);
+        header.extend_from_slice(BATCH_TAG);
+        header.extend_from_slice(&root);
+        header.extend_from_slice(&staff_count.to_be_bytes());
+        header.extend_from_slice(&period_id.to_be_bytes());
+
+        BatchArgs {
+            batch_hash: digest(self.tree.mode(), &header),
+            staff_count,
+            merkle_root: root,
+            period_id,
+        }
+    }
+
+    /// A staff member's leaf and inclusion proof, for `verify_inclusion`
+    pub fn proof(&self, staff_hash: &[u8; 32]) -> Option<([u8; 32], Vec<[u8; 32]>)> {
+        let index = self.items.binary_search_by(|item| item.staff_hash.cmp(staff_hash)).ok()?;
+        Some((self.tree.leaves()[index], self.tree.proof(index).ok()?))
+    }
+}
+
+/// Parse `staff_hash,period_id,currency,gross,net` rows; blank lines and a
+/// `staff_hash` header are skipped, as in `parse_rows`
+pub fn parse_line_items(input: &str) -> Result<Vec<LineItem>, Error> {
+    let mut items = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || (index == 0 && line.starts_with("staff_hash")) {
+            continue;
+        }
+
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        let [hash, period, currency, gross, net] = fields.as_slice() else {
+            return Err(Error::InvalidRow { line: index + 1 });
+        };
+        let amount = |text: &str| text.parse::<i128>().map_err(|_| Error::InvalidAmount);
+        items.push(LineItem::new(
+            parse_hash(hash)?,
+            period.parse().map_err(|_| Error::InvalidPeriod)?,
+            currency,
+            amount(gross)?,
+            amount(net)?,
+        )?);
+    }
+    Ok(items)
+}
+
+/// Lower-case hex, as the Stellar CLI takes BytesN<32> arguments
+pub fn to_hex(bytes: &[u8; 32]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    bytes
+        .iter()
+        .flat_map(|byte| [DIGITS[(byte >> 4) as usize] as char, DIGITS[(byte & 15) as usize] as char])
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::merkle::verify_proof;
+
+    fn item(seed: u8, net: i128) -> LineItem {
+        LineItem::new([seed; 32], 202608, "ngn", 500_000, net).unwrap()
+    }
+
+    #[test]
+    fn test_order_independent() {
+        let a = Batch::build(HashMode::Sha256, alloc::vec![item(1, 400_000), item(2, 450_000), item(3, 300_000)]).unwrap();
+        let b = Batch::build(HashMode::Sha256, alloc::vec![item(3, 300_000), item(1, 400_000), item(2, 450_000)]).unwrap();
+        assert_eq!(a.args(), b.args());
+        assert_eq!(a.args().staff_count, 3);
+        assert_eq!(a.items()[0].currency, *b"NGN");
+
+        // Any changed amount changes the root and the batch hash
+        let c = Batch::build(HashMode::Sha256, alloc::vec![item(1, 400_001), item(2, 450_000), item(3, 300_000)]).unwrap();
+        assert_ne!(a.args().merkle_root, c.args().merkle_root);
+        assert_ne!(a.args().batch_hash, c.args().batch_hash);
+
+        let (leaf, proof) = a.proof(&[2u8; 32]).unwrap();
+        assert!(verify_proof(HashMode::Sha256, &a.args().merkle_root, &leaf, &proof));
+        assert!(a.proof(&[9u8; 32]).is_none());
+    }
+
+    #[test]
+    fn test_rejects_ambiguous_batches() {
+        assert!(matches!(Batch::build(HashMode::Sha256, alloc::vec![item(1, 1), item(1, 2)]), Err(Error::DuplicateStaff)));
+        let other_period = LineItem::new([2u8; 32], 202609, "NGN", 1, 1).unwrap();
+        assert!(matches!(Batch::build(HashMode::Sha256, alloc::vec![item(1, 1), other_period]), Err(Error::MixedPeriods)));
+        assert_eq!(LineItem::new([1u8; 32], 202613, "NGN", 1, 1), Err(Error::InvalidPeriod));
+        assert_eq!(LineItem::new([1u8; 32], 202608, "NG", 1, 1), Err(Error::InvalidCurrency));
+        assert_eq!(LineItem::new([1u8; 32], 202608, "NGN", 1, 2), Err(Error::InvalidAmount));
+    }
+
+    #[test]
+    fn test_parse_line_items() {
+        let hash = to_hex(&[1u8; 32]);
+        let items = parse_line_items(&alloc::format!("staff_hash,period_id,currency,gross,net\n{hash}, 202608, usd, 100, 90\n")).unwrap();
+        assert_eq!(items, alloc::vec![LineItem::new([1u8; 32], 202608, "USD", 100, 90).unwrap()]);
+        assert_eq!(parse_line_items(&alloc::format!("{hash},202608,USD,100\n")), Err(Error::InvalidRow { line: 1 }));
+    }
+}
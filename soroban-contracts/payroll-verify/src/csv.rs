@@ -0,0 +1,69 @@
+use alloc::vec::Vec;
+
+use crate::hashing::{parse_hash, HashMode};
+use crate::merkle::payslip_leaf;
+use crate::Error;
+
+/// One payslip from a payroll export: `staff_hash,amount` with the staff hash in
+/// hex and the net amount as an integer in minor units (e.g. kobo, cents)
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayrollRow {
+    pub staff_hash: [u8; 32],
+    pub amount: i128,
+}
+
+impl PayrollRow {
+    pub fn leaf(&self, mode: HashMode) -> [u8; 32] {
+        payslip_leaf(mode, &self.staff_hash, self.amount)
+    }
+}
+
+/// Parse one `staff_hash,amount` row; surrounding whitespace is ignored
+pub fn parse_row(line: &str) -> Result<PayrollRow, Error> {
+    let mut fields = line.split(',').map(str::trim);
+    let (Some(hash), Some(amount), None) = (fields.next(), fields.next(), fields.next()) else {
+        return Err(Error::InvalidRow { line: 1 });
+    };
+
+    Ok(PayrollRow {
+        staff_hash: parse_hash(hash)?,
+        amount: amount.parse().map_err(|_| Error::InvalidAmount)?,
+    })
+}
+
+/// Parse a whole export. Blank lines are skipped, as is a first line starting
+/// with `staff_hash` (a header). Row errors report their 1-based line number.
+pub fn parse_rows(input: &str) -> Result<Vec<PayrollRow>, Error> {
+    let mut rows = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || (index == 0 && line.starts_with("staff_hash")) {
+            continue;
+        }
+        rows.push(parse_row(line).map_err(|err| match err {
+            Error::InvalidRow { .. } => Error::InvalidRow { line: index + 1 },
+            other => other,
+        })?);
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_rows() {
+        let hash = "0101010101010101010101010101010101010101010101010101010101010101";
+        let input = alloc::format!("staff_hash,amount\n{hash}, 250000\n\n0x{hash},-15\n");
+
+        let rows = parse_rows(&input).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0], PayrollRow { staff_hash: [1u8; 32], amount: 250_000 });
+        assert_eq!(rows[1].amount, -15);
+
+        assert_eq!(parse_rows(&alloc::format!("{hash}\n")), Err(Error::InvalidRow { line: 1 }));
+        assert_eq!(parse_rows(&alloc::format!("{hash},1\n{hash},1,2\n")), Err(Error::InvalidRow { line: 2 }));
+        assert_eq!(parse_rows(&alloc::format!("{hash},1.5\n")), Err(Error::InvalidAmount));
+    }
+}
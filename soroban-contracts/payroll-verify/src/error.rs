@@ -0,0 +1,46 @@
+use core::fmt;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// A hex field was not 64 hex digits
+    InvalidHash,
+    /// An amount was not a base-10 integer in i128 range (or, for line items,
+    /// was negative or had net above gross)
+    InvalidAmount,
+    /// A CSV row did not have exactly the expected columns
+    InvalidRow { line: usize },
+    /// An org id was longer than 32 bytes or used characters outside `[A-Za-z0-9_]`
+    InvalidOrgId,
+    /// A period id was not a YYYYMM month
+    InvalidPeriod,
+    /// A currency was not a three-letter code
+    InvalidCurrency,
+    /// A batch listed the same staff hash twice
+    DuplicateStaff,
+    /// A batch mixed line items from different pay periods
+    MixedPeriods,
+    /// A merkle tree needs at least one leaf
+    EmptyTree,
+    /// A proof was requested for a leaf index past the end of the tree
+    LeafOutOfRange,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidHash => write!(f, "invalid hash"),
+            Error::InvalidAmount => write!(f, "invalid amount"),
+            Error::InvalidRow { line } => write!(f, "invalid row on line {}", line),
+            Error::InvalidOrgId => write!(f, "invalid org id"),
+            Error::InvalidPeriod => write!(f, "invalid period id"),
+            Error::InvalidCurrency => write!(f, "invalid currency"),
+            Error::DuplicateStaff => write!(f, "duplicate staff hash in batch"),
+            Error::MixedPeriods => write!(f, "batch mixes pay periods"),
+            Error::EmptyTree => write!(f, "empty merkle tree"),
+            Error::LeafOutOfRange => write!(f, "leaf index out of range"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
@@ -0,0 +1,118 @@
+use alloc::vec::Vec;
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
+
+use crate::Error;
+
+// Domain tag prefixed to every domain-separated staff hash preimage; must match the contract
+const STAFF_HASH_V2_TAG: &[u8] = b"payroll-staff-v2";
+
+// XDR discriminants of the values the contract serializes into v2 preimages
+const SCV_SYMBOL: u32 = 15;
+const SCV_ADDRESS: u32 = 18;
+const SC_ADDRESS_TYPE_CONTRACT: u32 = 1;
+
+/// Digest a deployment uses for staff hashes and merkle nodes (see `get_hash_mode`)
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HashMode {
+    Sha256,
+    Keccak256,
+}
+
+pub fn digest(mode: HashMode, data: &[u8]) -> [u8; 32] {
+    match mode {
+        HashMode::Sha256 => Sha256::digest(data).into(),
+        HashMode::Keccak256 => Keccak256::digest(data).into(),
+    }
+}
+
+/// Raw staff hash, as `compute_staff_hash` derives it
+pub fn staff_hash(mode: HashMode, preimage: &[u8]) -> [u8; 32] {
+    digest(mode, preimage)
+}
+
+/// Network id used as the chain domain: SHA-256 of the network passphrase
+pub fn network_id(passphrase: &str) -> [u8; 32] {
+    Sha256::digest(passphrase.as_bytes()).into()
+}
+
+/// Domain-separated staff hash, as `compute_staff_hash_v2` derives it on the
+/// deployment `contract_id` (the 32-byte contract hash behind its `C...` address).
+/// `org_id` is empty for the main registry.
+pub fn staff_hash_v2(
+    mode: HashMode,
+    preimage: &[u8],
+    org_id: &str,
+    chain_domain: &[u8; 32],
+    contract_id: &[u8; 32],
+) -> Result<[u8; 32], Error> {
+    let mut data = Vec::with_capacity(STAFF_HASH_V2_TAG.len() + 80 + org_id.len() + preimage.len());
+    data.extend_from_slice(STAFF_HASH_V2_TAG);
+    data.extend_from_slice(chain_domain);
+    data.extend_from_slice(&SCV_ADDRESS.to_be_bytes());
+    data.extend_from_slice(&SC_ADDRESS_TYPE_CONTRACT.to_be_bytes());
+    data.extend_from_slice(contract_id);
+    symbol_xdr(&mut data, org_id)?;
+    data.extend_from_slice(preimage);
+    Ok(digest(mode, &data))
+}
+
+// ScVal::Symbol: discriminant, length, bytes padded to a 4-byte boundary
+fn symbol_xdr(out: &mut Vec<u8>, symbol: &str) -> Result<(), Error> {
+    if symbol.len() > 32 || !symbol.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_') {
+        return Err(Error::InvalidOrgId);
+    }
+    out.extend_from_slice(&SCV_SYMBOL.to_be_bytes());
+    out.extend_from_slice(&(symbol.len() as u32).to_be_bytes());
+    out.extend_from_slice(symbol.as_bytes());
+    out.resize(out.len() + (4 - symbol.len() % 4) % 4, 0);
+    Ok(())
+}
+
+/// Parse a 32-byte hash from 64 hex digits, with or without a `0x` prefix
+pub fn parse_hash(hex: &str) -> Result<[u8; 32], Error> {
+    let hex = hex.strip_prefix("0x").unwrap_or(hex).as_bytes();
+    if hex.len() != 64 {
+        return Err(Error::InvalidHash);
+    }
+
+    let mut hash = [0u8; 32];
+    for (byte, pair) in hash.iter_mut().zip(hex.chunks(2)) {
+        *byte = (nibble(pair[0])? << 4) | nibble(pair[1])?;
+    }
+    Ok(hash)
+}
+
+fn nibble(c: u8) -> Result<u8, Error> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(Error::InvalidHash),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_known_digests() {
+        assert_eq!(
+            staff_hash(HashMode::Sha256, b"abc"),
+            parse_hash("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad").unwrap()
+        );
+        assert_eq!(
+            staff_hash(HashMode::Keccak256, b""),
+            parse_hash("0xc5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_org_id_validation() {
+        let domain = [0u8; 32];
+        assert!(staff_hash_v2(HashMode::Sha256, b"x", "lagos_hq", &domain, &domain).is_ok());
+        assert_eq!(staff_hash_v2(HashMode::Sha256, b"x", "lagos-hq", &domain, &domain), Err(Error::InvalidOrgId));
+        assert_eq!(parse_hash("zz"), Err(Error::InvalidHash));
+    }
+}
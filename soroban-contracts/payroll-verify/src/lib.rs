@@ -0,0 +1,24 @@
+//! Off-chain counterpart of the staff-registry contract's hashing.
+//!
+//! Reproduces the contract's staff hashes (raw and domain-separated v2), its
+//! sorted-pair merkle proofs and the payslip leaf format, and parses payroll CSV
+//! exports into leaves. The `batch` module defines the canonical line-item format
+//! and derives the exact `record_payroll_batch_with_root` arguments. Builds under
+//! `no_std` (with `alloc`) when the default `std` feature is off, so the same code
+//! can run in wallets and other contracts.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod batch;
+pub mod csv;
+pub mod error;
+pub mod hashing;
+pub mod merkle;
+
+pub use batch::{parse_line_items, Batch, BatchArgs, LineItem};
+pub use csv::{parse_rows, PayrollRow};
+pub use error::Error;
+pub use hashing::{staff_hash, staff_hash_v2, HashMode};
+pub use merkle::{payslip_leaf, verify_proof, MerkleTree};
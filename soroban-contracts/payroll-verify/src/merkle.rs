@@ -0,0 +1,113 @@
+use alloc::vec::Vec;
+
+use crate::hashing::{digest, HashMode};
+use crate::Error;
+
+/// Hash two sibling nodes the way the contract does: sorted, then concatenated,
+/// so proofs carry no left/right position bits
+pub fn hash_pair(mode: HashMode, a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+
+    let mut data = [0u8; 64];
+    data[..32].copy_from_slice(first);
+    data[32..].copy_from_slice(second);
+    digest(mode, &data)
+}
+
+/// Fold a proof from the leaf up and compare against the root (`verify_inclusion`)
+pub fn verify_proof(mode: HashMode, root: &[u8; 32], leaf: &[u8; 32], proof: &[[u8; 32]]) -> bool {
+    proof.iter().fold(*leaf, |node, sibling| hash_pair(mode, &node, sibling)) == *root
+}
+
+/// Payslip leaf: digest of the staff hash followed by the net amount in minor
+/// units as a 16-byte big-endian two's complement integer
+pub fn payslip_leaf(mode: HashMode, staff_hash: &[u8; 32], amount: i128) -> [u8; 32] {
+    let mut data = [0u8; 48];
+    data[..32].copy_from_slice(staff_hash);
+    data[32..].copy_from_slice(&amount.to_be_bytes());
+    digest(mode, &data)
+}
+
+/// Merkle tree over payslip leaves, in the order given. Each level pairs
+/// neighbours left to right; an odd node at the end moves up unchanged.
+pub struct MerkleTree {
+    mode: HashMode,
+    levels: Vec<Vec<[u8; 32]>>,
+}
+
+impl MerkleTree {
+    pub fn new(mode: HashMode, leaves: Vec<[u8; 32]>) -> Result<Self, Error> {
+        if leaves.is_empty() {
+            return Err(Error::EmptyTree);
+        }
+
+        let mut levels = Vec::new();
+        levels.push(leaves);
+        while levels[levels.len() - 1].len() > 1 {
+            let next = levels[levels.len() - 1]
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => hash_pair(mode, a, b),
+                    [a] => *a,
+                    _ => unreachable!(),
+                })
+                .collect();
+            levels.push(next);
+        }
+        Ok(MerkleTree { mode, levels })
+    }
+
+    pub fn mode(&self) -> HashMode {
+        self.mode
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.levels[self.levels.len() - 1][0]
+    }
+
+    pub fn leaves(&self) -> &[[u8; 32]] {
+        &self.levels[0]
+    }
+
+    /// Sibling path for the leaf at `index`, leaf level first
+    pub fn proof(&self, index: usize) -> Result<Vec<[u8; 32]>, Error> {
+        if index >= self.levels[0].len() {
+            return Err(Error::LeafOutOfRange);
+        }
+
+        let mut proof = Vec::new();
+        let mut position = index;
+        for level in &self.levels[..self.levels.len() - 1] {
+            if let Some(sibling) = level.get(position ^ 1) {
+                proof.push(*sibling);
+            }
+            position /= 2;
+        }
+        Ok(proof)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_every_leaf_proves_against_root() {
+        for count in 1..=9u8 {
+            let leaves: Vec<[u8; 32]> = (0..count).map(|i| payslip_leaf(HashMode::Sha256, &[i; 32], 1_000)).collect();
+            let tree = MerkleTree::new(HashMode::Sha256, leaves.clone()).unwrap();
+
+            for (index, leaf) in leaves.iter().enumerate() {
+                let proof = tree.proof(index).unwrap();
+                assert!(verify_proof(HashMode::Sha256, &tree.root(), leaf, &proof));
+            }
+        }
+    }
+
+    #[test]
+    fn test_empty_tree_and_bad_index() {
+        assert!(matches!(MerkleTree::new(HashMode::Sha256, Vec::new()), Err(Error::EmptyTree)));
+        let tree = MerkleTree::new(HashMode::Sha256, alloc::vec![[1u8; 32]]).unwrap();
+        assert_eq!(tree.proof(1), Err(Error::LeafOutOfRange));
+    }
+}
@@ -0,0 +1,194 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env};
+
+use super::*;
+
+#[contracttype]
+enum AddressKey {
+    StaffByAddress(Address),  // bound address -> staff hash, for self-service lookups
+}
+
+// Wallet an employee controls, if one has been bound to their staff hash
+pub(crate) fn bound_address(env: &Env, staff_hash: &BytesN<32>) -> Option<Address> {
+    storage::get(env, DataClass::Records, &DataKey::StaffAddress(staff_hash.clone()))
+}
+
+// Staff hash an address is bound to, if any
+pub(crate) fn bound_staff(env: &Env, address: &Address) -> Option<BytesN<32>> {
+    storage::get(env, DataClass::Indexes, &AddressKey::StaffByAddress(address.clone()))
+}
+
+// Bind an address to a staff hash in both directions; an address belongs to one staff member
+pub(crate) fn bind(env: &Env, staff_hash: &BytesN<32>, address: &Address) {
+    if bound_staff(env, address).is_some_and(|bound| bound != *staff_hash) {
+        panic!("Address already bound");
+    }
+    unbind(env, staff_hash);
+
+    let key = DataKey::StaffAddress(staff_hash.clone());
+    storage::set(env, DataClass::Records, &key, address);
+    let reverse_key = AddressKey::StaffByAddress(address.clone());
+    storage::set(env, DataClass::Indexes, &reverse_key, staff_hash);
+}
+
+pub(crate) fn unbind(env: &Env, staff_hash: &BytesN<32>) {
+    if let Some(address) = bound_address(env, staff_hash) {
+        storage::remove(env, DataClass::Indexes, &AddressKey::StaffByAddress(address));
+        storage::remove(env, DataClass::Records, &DataKey::StaffAddress(staff_hash.clone()));
+    }
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Bind a staff hash to the employee's own Stellar address (Registrar, with the employee's consent)
+    pub fn bind_staff_address(env: Env, caller: Address, staff_hash: BytesN<32>, address: Address) {
+        // onlyOwner or Registrar
+        auth::require_role(&env, &caller, Role::Registrar);
+        address.require_auth();
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        rotation::require_current(&env, &staff_hash);
+        erasure::require_not_erased(&env, &staff_hash);
+
+        if !Self::is_staff_registered(env.clone(), staff_hash.clone()) {
+            panic!("Staff not found");
+        }
+
+        bind(&env, &staff_hash, &address);
+
+        audit::log(&env, symbol_short!("staff_adr"), &caller, &staff_hash);
+
+        env.events().publish(
+            (symbol_short!("staff_adr"), staff_hash),
+            (caller, address, env.ledger().timestamp())
+        );
+    }
+
+    /// Get the address bound to a staff hash
+    pub fn get_staff_address(env: Env, staff_hash: BytesN<32>) -> Option<Address> {
+        bound_address(&env, &staff_hash)
+    }
+
+    /// Confirm receipt of pay for a batch (bound staff address only)
+    pub fn acknowledge_payment(env: Env, staff_hash: BytesN<32>, batch_hash: BytesN<32>) {
+        let address = bound_address(&env, &staff_hash).unwrap_or_else(|| panic!("Staff address not bound"));
+        address.require_auth();
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        if !Self::is_batch_recorded(env.clone(), batch_hash.clone()) {
+            panic!("Batch not found");
+        }
+        if !Self::is_staff_registered(env.clone(), staff_hash.clone()) {
+            panic!("Staff not found");
+        }
+        // Batches recorded with their members can only be acknowledged by those members
+        if has_member_list(&env, &batch_hash) && !Self::is_staff_in_batch(env.clone(), batch_hash.clone(), staff_hash.clone()) {
+            panic!("Staff not in batch");
+        }
+
+        let key = DataKey::PaymentAck(batch_hash.clone(), staff_hash.clone());
+        if storage::has(&env, DataClass::Records, &key) {
+            panic!("Payment already acknowledged");
+        }
+        storage::set(&env, DataClass::Records, &key, &env.ledger().timestamp());
+
+        audit::log(&env, symbol_short!("pay_ack"), &address, &batch_hash);
+
+        env.events().publish(
+            (symbol_short!("pay_ack"), batch_hash, staff_hash),
+            (address, env.ledger().timestamp())
+        );
+    }
+
+    /// Get when a staff member acknowledged pay for a batch
+    pub fn get_payment_acknowledgement(env: Env, batch_hash: BytesN<32>, staff_hash: BytesN<32>) -> Option<u64> {
+        storage::get(&env, DataClass::Records, &DataKey::PaymentAck(batch_hash, staff_hash))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Ledger}, Env, Vec};
+
+    #[test]
+    fn test_acknowledge_payment() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.timestamp = 5_000);
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let employee = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        client.record_payroll_batch(&owner, &batch_hash, &1);
+
+        client.bind_staff_address(&owner, &staff_hash, &employee);
+        assert_eq!(client.get_staff_address(&staff_hash), Some(employee));
+
+        assert_eq!(client.get_payment_acknowledgement(&batch_hash, &staff_hash), None);
+        client.acknowledge_payment(&staff_hash, &batch_hash);
+        assert_eq!(client.get_payment_acknowledgement(&batch_hash, &staff_hash), Some(5_000));
+    }
+
+    #[test]
+    #[should_panic(expected = "Staff not in batch")]
+    fn test_acknowledge_requires_batch_membership() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let member = BytesN::from_array(&env, &[1u8; 32]);
+        let outsider = BytesN::from_array(&env, &[3u8; 32]);
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.register_staff(&owner, &member);
+        client.register_staff(&owner, &outsider);
+        client.record_batch_with_members(&owner, &batch_hash, &Vec::from_array(&env, [member.clone()]));
+        client.bind_staff_address(&owner, &member, &Address::generate(&env));
+        client.bind_staff_address(&owner, &outsider, &Address::generate(&env));
+
+        client.acknowledge_payment(&member, &batch_hash);
+        env.as_contract(&contract_id, || StaffRegistry::acknowledge_payment(env.clone(), outsider, batch_hash));
+    }
+
+    #[test]
+    #[should_panic(expected = "Staff address not bound")]
+    fn test_acknowledge_without_binding() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        client.record_payroll_batch(&owner, &batch_hash, &1);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::acknowledge_payment(env.clone(), staff_hash, batch_hash)
+        });
+    }
+}
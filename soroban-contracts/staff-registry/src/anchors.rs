@@ -0,0 +1,131 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env, Vec};
+
+use super::*;
+
+// Upper bound on external anchors attached to one batch
+pub const MAX_EXTERNAL_ANCHORS: u32 = 10;
+
+// Transaction on another chain that mirrors a batch recorded here
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExternalAnchor {
+    pub chain_id: u32,         // EIP-155 chain id of the counterpart deployment
+    pub tx_hash: BytesN<32>,
+    pub anchored_by: Address,
+    pub anchored_at: u64,
+}
+
+#[contracttype]
+enum AnchorKey {
+    BatchAnchors(BytesN<32>),        // external anchors per batch
+    AnchoredBatch(u32, BytesN<32>),  // (chain id, tx hash) -> batch hash
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Reference the external-chain transaction that mirrors a recorded batch
+    pub fn anchor_external_record(env: Env, caller: Address, chain_id: u32, tx_hash: BytesN<32>, batch_hash: BytesN<32>) {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        if !Self::is_batch_recorded(env.clone(), batch_hash.clone()) {
+            panic!("Batch not found");
+        }
+
+        let reverse_key = AnchorKey::AnchoredBatch(chain_id, tx_hash.clone());
+        if storage::has(&env, DataClass::Records, &reverse_key) {
+            panic!("Transaction already anchored");
+        }
+
+        let key = AnchorKey::BatchAnchors(batch_hash.clone());
+        let mut anchors = Self::get_external_anchors(env.clone(), batch_hash.clone());
+        if anchors.len() >= MAX_EXTERNAL_ANCHORS {
+            panic!("Too many anchors");
+        }
+
+        anchors.push_back(ExternalAnchor {
+            chain_id,
+            tx_hash: tx_hash.clone(),
+            anchored_by: caller.clone(),
+            anchored_at: env.ledger().timestamp(),
+        });
+        storage::set(&env, DataClass::Records, &key, &anchors);
+
+        storage::set(&env, DataClass::Records, &reverse_key, &batch_hash);
+
+        audit::log(&env, symbol_short!("anchor"), &caller, &batch_hash);
+
+        env.events().publish(
+            (symbol_short!("anchor"), batch_hash),
+            (chain_id, tx_hash, caller, env.ledger().timestamp())
+        );
+    }
+
+    /// Get the external transactions anchored to a batch
+    pub fn get_external_anchors(env: Env, batch_hash: BytesN<32>) -> Vec<ExternalAnchor> {
+        storage::get(&env, DataClass::Records, &AnchorKey::BatchAnchors(batch_hash))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Find the batch an external transaction was anchored to
+    pub fn get_anchored_batch(env: Env, chain_id: u32, tx_hash: BytesN<32>) -> Option<BytesN<32>> {
+        storage::get(&env, DataClass::Records, &AnchorKey::AnchoredBatch(chain_id, tx_hash))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_anchor_external_record() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        let tx_hash = BytesN::from_array(&env, &[9u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &3);
+        client.anchor_external_record(&owner, &1, &tx_hash, &batch_hash);
+
+        let anchors = client.get_external_anchors(&batch_hash);
+        assert_eq!(anchors.len(), 1);
+        assert_eq!(anchors.get(0).unwrap().chain_id, 1);
+        assert_eq!(client.get_anchored_batch(&1, &tx_hash), Some(batch_hash));
+        assert_eq!(client.get_anchored_batch(&5, &tx_hash), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Transaction already anchored")]
+    fn test_anchor_same_transaction_twice() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let first = BytesN::from_array(&env, &[2u8; 32]);
+        let second = BytesN::from_array(&env, &[3u8; 32]);
+        let tx_hash = BytesN::from_array(&env, &[9u8; 32]);
+        client.record_payroll_batch(&owner, &first, &3);
+        client.record_payroll_batch(&owner, &second, &3);
+        client.anchor_external_record(&owner, &1, &tx_hash, &first);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::anchor_external_record(env.clone(), owner, 1, tx_hash, second)
+        });
+    }
+}
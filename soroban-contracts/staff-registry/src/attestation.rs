@@ -0,0 +1,219 @@
+use soroban_sdk::{contractimpl, symbol_short, Address, BytesN, Env, Vec};
+
+use super::*;
+
+// Number of auditor attestations needed to finalize a batch when not configured
+pub const DEFAULT_ATTESTATION_THRESHOLD: u32 = 1;
+
+// Capped at the current auditor count so revoking auditors cannot leave batches unfinalizable
+pub(crate) fn attestation_threshold(env: &Env) -> u32 {
+    let threshold = storage::get(env, DataClass::Config, &DataKey::AttestationThreshold)
+        .unwrap_or(DEFAULT_ATTESTATION_THRESHOLD);
+    let auditors = roles::role_members(env, Role::Auditor).len();
+    threshold.min(auditors).max(1)
+}
+
+// Attestations only count while the auditor still holds the role
+pub(crate) fn current_attestations(env: &Env, attestations: &Vec<Address>) -> u32 {
+    attestations
+        .iter()
+        .filter(|auditor| roles::roles_of(env, auditor).contains(Role::Auditor))
+        .count() as u32
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Set how many distinct auditors must attest a batch before it is final (owner only)
+    pub fn set_attestation_threshold(env: Env, threshold: u32) {
+        let owner = auth::require_owner(&env);
+
+        let auditors = roles::role_members(&env, Role::Auditor).len();
+        if threshold == 0 || threshold > auditors {
+            panic!("Invalid threshold");
+        }
+
+        storage::set(&env, DataClass::Config, &DataKey::AttestationThreshold, &threshold);
+
+        audit::log_action(&env, symbol_short!("att_thr"), &owner);
+
+        env.events().publish(
+            (symbol_short!("att_thr"),),
+            (threshold, auditors, env.ledger().timestamp())
+        );
+    }
+
+    /// Get the effective attestation threshold (never above the number of auditors)
+    pub fn get_attestation_threshold(env: Env) -> u32 {
+        attestation_threshold(&env)
+    }
+
+    /// Attest a batch as an auditor; the batch is finalized once the threshold is met
+    pub fn attest_batch(env: Env, auditor: Address, batch_hash: BytesN<32>) {
+        auditor.require_auth();
+
+        // Owner deliberately does not count: uploads must be confirmed independently
+        if !roles::roles_of(&env, &auditor).contains(Role::Auditor) {
+            panic!("Missing role");
+        }
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        let batch = Self::get_payroll_batch(env.clone(), batch_hash.clone());
+        if !batch.is_valid {
+            panic!("Batch is invalidated");
+        }
+
+        let key = DataKey::BatchAttestations(batch_hash.clone());
+        let mut attestations = Self::get_batch_attestations(env.clone(), batch_hash.clone());
+        if attestations.contains(&auditor) {
+            panic!("Already attested");
+        }
+        attestations.push_back(auditor.clone());
+        storage::set(&env, DataClass::Records, &key, &attestations);
+
+        audit::log(&env, symbol_short!("batch_att"), &auditor, &batch_hash);
+
+        env.events().publish(
+            (symbol_short!("batch_att"), batch_hash.clone()),
+            (auditor, attestations.len(), env.ledger().timestamp())
+        );
+
+        let finalized_key = DataKey::BatchFinalizedAt(batch_hash.clone());
+        if current_attestations(&env, &attestations) >= attestation_threshold(&env)
+            && !storage::has(&env, DataClass::Records, &finalized_key)
+        {
+            storage::set(&env, DataClass::Records, &finalized_key, &env.ledger().timestamp());
+
+            env.events().publish(
+                (symbol_short!("batch_fin"), batch_hash),
+                (attestations.len(), env.ledger().timestamp())
+            );
+        }
+    }
+
+    /// Get the auditors that have attested a batch
+    pub fn get_batch_attestations(env: Env, batch_hash: BytesN<32>) -> Vec<Address> {
+        storage::get(&env, DataClass::Records, &DataKey::BatchAttestations(batch_hash))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Check whether a batch has reached the attestation threshold
+    pub fn is_batch_finalized(env: Env, batch_hash: BytesN<32>) -> bool {
+        storage::has(&env, DataClass::Records, &DataKey::BatchFinalizedAt(batch_hash))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_threshold_finalization() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let auditors = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+        for auditor in auditors.iter() {
+            client.grant_role(auditor, &Role::Auditor);
+        }
+        assert_eq!(client.get_auditors().len(), 3);
+        client.set_attestation_threshold(&2);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &10);
+
+        client.attest_batch(&auditors[0], &batch_hash);
+        assert!(!client.is_batch_finalized(&batch_hash));
+
+        client.attest_batch(&auditors[2], &batch_hash);
+        assert!(client.is_batch_finalized(&batch_hash));
+        assert_eq!(client.get_batch_attestations(&batch_hash).len(), 2);
+    }
+
+    #[test]
+    fn test_threshold_capped_after_auditor_revoked() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let auditors = [Address::generate(&env), Address::generate(&env)];
+        for auditor in auditors.iter() {
+            client.grant_role(auditor, &Role::Auditor);
+        }
+        client.set_attestation_threshold(&2);
+
+        // One auditor left: the configured threshold of 2 could never be met
+        client.revoke_auditor(&auditors[1]);
+        assert_eq!(client.get_attestation_threshold(), 1);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &10);
+        client.attest_batch(&auditors[0], &batch_hash);
+        assert!(client.is_batch_finalized(&batch_hash));
+
+        // The configured threshold applies again once enough auditors exist
+        client.grant_role(&auditors[1], &Role::Auditor);
+        assert_eq!(client.get_attestation_threshold(), 2);
+    }
+
+    #[test]
+    fn test_revoked_auditor_attestation_stops_counting() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let auditors = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+        for auditor in auditors.iter() {
+            client.grant_role(auditor, &Role::Auditor);
+        }
+        client.set_attestation_threshold(&2);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &10);
+        client.attest_batch(&auditors[0], &batch_hash);
+        client.revoke_role(&auditors[0], &Role::Auditor);
+
+        client.attest_batch(&auditors[1], &batch_hash);
+        assert!(!client.is_batch_finalized(&batch_hash));
+
+        client.attest_batch(&auditors[2], &batch_hash);
+        assert!(client.is_batch_finalized(&batch_hash));
+    }
+
+    #[test]
+    #[should_panic(expected = "Missing role")]
+    fn test_owner_cannot_attest() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &10);
+
+        env.as_contract(&contract_id, || StaffRegistry::attest_batch(env.clone(), owner, batch_hash));
+    }
+}
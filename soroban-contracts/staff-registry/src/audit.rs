@@ -0,0 +1,104 @@
+use soroban_sdk::{contractimpl, contracttype, Address, BytesN, Env, Symbol, Vec};
+
+use super::*;
+
+// One line of the permanent action trail; `action` matches the event topic
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AuditEntry {
+    pub seq: u64,
+    pub action: Symbol,
+    pub actor: Address,
+    pub subject: BytesN<32>,  // staff or batch hash acted on, zero for contract-wide actions
+    pub timestamp: u64,
+}
+
+#[contracttype]
+enum AuditKey {
+    AuditSeq,         // last written audit sequence number
+    AuditEntry(u64),  // audit entry by sequence number
+}
+
+// Append an entry about a staff or batch hash
+pub(crate) fn log(env: &Env, action: Symbol, actor: &Address, subject: &BytesN<32>) {
+    let seq: u64 = storage::get(env, DataClass::Config, &AuditKey::AuditSeq).unwrap_or(0) + 1;
+    storage::set(env, DataClass::Config, &AuditKey::AuditSeq, &seq);
+
+    let key = AuditKey::AuditEntry(seq);
+    storage::set(env, DataClass::Records, &key, &AuditEntry {
+        seq,
+        action,
+        actor: actor.clone(),
+        subject: subject.clone(),
+        timestamp: env.ledger().timestamp(),
+    });
+}
+
+// Append an entry for a contract-wide action (roles, ownership, configuration)
+pub(crate) fn log_action(env: &Env, action: Symbol, actor: &Address) {
+    log(env, action, actor, &BytesN::from_array(env, &[0u8; 32]));
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Get up to `limit` audit entries starting at `from_seq` (sequence numbers start at 1)
+    pub fn get_audit_entries(env: Env, from_seq: u64, limit: u32) -> Vec<AuditEntry> {
+        let last = Self::get_audit_length(env.clone());
+        let mut entries = Vec::new(&env);
+
+        let mut seq = from_seq.max(1);
+        while seq <= last && entries.len() < limit.min(MAX_PAGE_SIZE) {
+            entries.push_back(
+                storage::get(&env, DataClass::Records, &AuditKey::AuditEntry(seq))
+                    .unwrap_or_else(|| panic!("Audit entry archived")),
+            );
+            seq += 1;
+        }
+
+        entries
+    }
+
+    /// Get the sequence number of the latest audit entry
+    pub fn get_audit_length(env: Env) -> u64 {
+        storage::get(&env, DataClass::Config, &AuditKey::AuditSeq).unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{symbol_short, testutils::Address as _, Env};
+
+    #[test]
+    fn test_audit_log() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let registrar = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.grant_role(&registrar, &Role::Registrar);
+        client.register_staff(&registrar, &staff_hash);
+        client.revoke_staff(&owner, &staff_hash);
+        assert_eq!(client.get_audit_length(), 4);
+
+        let entries = client.get_audit_entries(&3, &10);
+        assert_eq!(entries.len(), 2);
+
+        let registered = entries.get(0).unwrap();
+        assert_eq!(registered.seq, 3);
+        assert_eq!(registered.action, symbol_short!("staff_reg"));
+        assert_eq!(registered.actor, registrar);
+        assert_eq!(registered.subject, staff_hash);
+
+        let revoked = entries.get(1).unwrap();
+        assert_eq!(revoked.action, symbol_short!("staff_rev"));
+        assert_eq!(revoked.actor, owner);
+    }
+}
@@ -0,0 +1,112 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env, Vec};
+
+use super::*;
+
+// Auditor role holder together with the document backing their mandate
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Auditor {
+    pub address: Address,
+    pub credential_hash: BytesN<32>,  // hash of the license/mandate document, zero if granted via grant_role
+    pub registered_at: u64,
+}
+
+#[contracttype]
+enum AuditorKey {
+    AuditorCredential(Address),  // (credential hash, registered at)
+}
+
+// Drop an auditor's anchored credential; called whenever the Auditor role is revoked
+pub(crate) fn clear_credential(env: &Env, account: &Address) {
+    storage::remove(env, DataClass::Records, &AuditorKey::AuditorCredential(account.clone()));
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Grant the Auditor role and anchor the auditor's license or mandate document (owner only)
+    pub fn register_auditor(env: Env, account: Address, credential_hash: BytesN<32>) {
+        let owner = auth::require_owner(&env);
+
+        if credential_hash == BytesN::from_array(&env, &[0u8; 32]) {
+            panic!("Invalid credential hash");
+        }
+        roles::grant(&env, &owner, account.clone(), Role::Auditor);
+
+        let key = AuditorKey::AuditorCredential(account.clone());
+        storage::set(&env, DataClass::Records, &key, &(credential_hash.clone(), env.ledger().timestamp()));
+
+        audit::log(&env, symbol_short!("aud_reg"), &owner, &credential_hash);
+
+        env.events().publish(
+            (symbol_short!("aud_reg"), account),
+            (credential_hash, env.ledger().timestamp())
+        );
+    }
+
+    /// Revoke the Auditor role and drop the anchored credential (owner only)
+    pub fn revoke_auditor(env: Env, account: Address) {
+        let owner = auth::require_owner(&env);
+
+        // Also drops the anchored credential
+        roles::revoke(&env, &owner, account.clone(), Role::Auditor);
+
+        env.events().publish(
+            (symbol_short!("aud_rev"), account),
+            env.ledger().timestamp()
+        );
+    }
+
+    /// Get all current auditors with their credentials
+    pub fn get_auditors(env: Env) -> Vec<Auditor> {
+        let mut auditors = Vec::new(&env);
+        for address in roles::role_members(&env, Role::Auditor).iter() {
+            let (credential_hash, registered_at) = storage::get(&env, DataClass::Records, &AuditorKey::AuditorCredential(address.clone()))
+                .unwrap_or((BytesN::from_array(&env, &[0u8; 32]), 0));
+            auditors.push_back(Auditor { address, credential_hash, registered_at });
+        }
+        auditors
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_auditor_registry() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let licensed = Address::generate(&env);
+        let legacy = Address::generate(&env);
+        client.initialize(&owner);
+
+        let credential = BytesN::from_array(&env, &[5u8; 32]);
+        client.register_auditor(&licensed, &credential);
+        client.grant_role(&legacy, &Role::Auditor);
+        assert!(client.has_role(&licensed, &Role::Auditor));
+
+        let auditors = client.get_auditors();
+        assert_eq!(auditors.len(), 2);
+        assert_eq!(auditors.get(0).unwrap().credential_hash, credential);
+        assert_eq!(auditors.get(1).unwrap().credential_hash, BytesN::from_array(&env, &[0u8; 32]));
+
+        client.revoke_auditor(&licensed);
+        assert!(!client.has_role(&licensed, &Role::Auditor));
+        assert_eq!(client.get_auditors().len(), 1);
+        assert_eq!(client.get_auditors().get(0).unwrap().address, legacy);
+
+        // Revoking through the generic role path drops the credential too
+        client.register_auditor(&licensed, &credential);
+        client.revoke_role(&licensed, &Role::Auditor);
+        client.grant_role(&licensed, &Role::Auditor);
+        let regranted = client.get_auditors().get(1).unwrap();
+        assert_eq!(regranted.address, licensed);
+        assert_eq!(regranted.credential_hash, BytesN::from_array(&env, &[0u8; 32]));
+    }
+}
@@ -0,0 +1,54 @@
+use soroban_sdk::{Address, Env};
+
+use super::*;
+
+// Access gates shared by every privileged entry point. Each gate reads the owner,
+// signer set and caller roles once and passes them along, instead of re-reading
+// instance storage (and cloning Env) in every helper it calls.
+
+pub(crate) fn owner(env: &Env) -> Address {
+    storage::get(env, DataClass::Config, &DataKey::Owner)
+        .unwrap_or_else(|| panic!("Not initialized"))
+}
+
+pub(crate) fn is_owner(env: &Env, account: &Address) -> bool {
+    *account == owner(env)
+}
+
+// Require auth from the active quorum, skipping an address that already authorized this call
+fn require_quorum(env: &Env, signers: &OwnerSigners, already_authorized: Option<&Address>) {
+    for signer in multisig::active_quorum(env, signers).iter() {
+        if Some(&signer) != already_authorized {
+            signer.require_auth();
+        }
+    }
+}
+
+// Owner-only gate: the owner key alone, or the signer quorum once one is configured.
+// Returns the owner address, which remains the actor recorded in the audit log.
+pub(crate) fn require_owner(env: &Env) -> Address {
+    renounce::require_not_renounced(env);
+
+    let owner = owner(env);
+    match multisig::owner_signers(env) {
+        Some(signers) => require_quorum(env, &signers, None),
+        None => owner.require_auth(),
+    }
+    recovery::touch_owner(env);
+    owner
+}
+
+// Owner or holder of `role`; the owner's bypass is subject to the signer quorum
+pub(crate) fn require_role(env: &Env, caller: &Address, role: Role) {
+    caller.require_auth();
+    renounce::require_not_renounced(env);
+
+    if *caller == owner(env) {
+        if let Some(signers) = multisig::owner_signers(env) {
+            require_quorum(env, &signers, Some(caller));
+        }
+        recovery::touch_owner(env);
+    } else if !roles::roles_of(env, caller).contains(role) {
+        panic!("Missing role");
+    }
+}
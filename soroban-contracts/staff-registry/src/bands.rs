@@ -0,0 +1,144 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env, Symbol};
+
+use super::*;
+
+// Published salary range; batches attest which band each staff member falls in
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SalaryBand {
+    pub id: Symbol,
+    pub min: i128,
+    pub max: i128,
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Define (or redefine) a salary band (owner only)
+    pub fn define_band(env: Env, band_id: Symbol, min: i128, max: i128) {
+        let owner = auth::require_owner(&env);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        if min < 0 || min > max {
+            panic!("Invalid band range");
+        }
+
+        let key = DataKey::SalaryBand(band_id.clone());
+        storage::set(&env, DataClass::Records, &key, &SalaryBand { id: band_id.clone(), min, max });
+
+        audit::log_action(&env, symbol_short!("band_def"), &owner);
+
+        env.events().publish(
+            (symbol_short!("band_def"), band_id),
+            (min, max, env.ledger().timestamp())
+        );
+    }
+
+    /// Get a salary band by id
+    pub fn get_band(env: Env, band_id: Symbol) -> SalaryBand {
+        storage::get(&env, DataClass::Records, &DataKey::SalaryBand(band_id))
+            .unwrap_or_else(|| panic!("Band not found"))
+    }
+
+    /// Assert that a staff member was paid within a band in a given batch
+    pub fn attest_band(env: Env, caller: Address, batch_hash: BytesN<32>, staff_hash: BytesN<32>, band_id: Symbol) {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        if !Self::is_batch_recorded(env.clone(), batch_hash.clone()) {
+            panic!("Batch not found");
+        }
+        if !Self::is_staff_registered(env.clone(), staff_hash.clone()) {
+            panic!("Staff not found");
+        }
+        if !storage::has(&env, DataClass::Records, &DataKey::SalaryBand(band_id.clone())) {
+            panic!("Band not found");
+        }
+
+        let key = DataKey::BandAttestation(batch_hash.clone(), staff_hash.clone());
+        if storage::has(&env, DataClass::Records, &key) {
+            panic!("Band already attested");
+        }
+        storage::set(&env, DataClass::Records, &key, &band_id);
+
+        // Per-band headcount for this batch, for publishing band-level figures
+        let count_key = DataKey::BandHeadcount(batch_hash.clone(), band_id.clone());
+        let count: u32 = storage::get(&env, DataClass::Records, &count_key).unwrap_or(0);
+        storage::set(&env, DataClass::Records, &count_key, &(count + 1));
+
+        audit::log(&env, symbol_short!("band_att"), &caller, &batch_hash);
+
+        env.events().publish(
+            (symbol_short!("band_att"), batch_hash, staff_hash),
+            (band_id, caller)
+        );
+    }
+
+    /// Get the band a staff member was attested in for a batch, if any
+    pub fn get_band_attestation(env: Env, batch_hash: BytesN<32>, staff_hash: BytesN<32>) -> Option<Symbol> {
+        storage::get(&env, DataClass::Records, &DataKey::BandAttestation(batch_hash, staff_hash))
+    }
+
+    /// Get how many staff were attested in a band for a batch
+    pub fn get_band_headcount(env: Env, batch_hash: BytesN<32>, band_id: Symbol) -> u32 {
+        storage::get(&env, DataClass::Records, &DataKey::BandHeadcount(batch_hash, band_id))
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_band_attestation() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let gl08 = symbol_short!("gl08");
+        client.define_band(&gl08, &1_200_000, &1_800_000);
+        assert_eq!(client.get_band(&gl08).max, 1_800_000);
+
+        let alice = BytesN::from_array(&env, &[1u8; 32]);
+        let bob = BytesN::from_array(&env, &[2u8; 32]);
+        client.register_staff(&owner, &alice);
+        client.register_staff(&owner, &bob);
+
+        let batch_hash = BytesN::from_array(&env, &[10u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &2);
+        client.attest_band(&owner, &batch_hash, &alice, &gl08);
+        client.attest_band(&owner, &batch_hash, &bob, &gl08);
+
+        assert_eq!(client.get_band_attestation(&batch_hash, &alice), Some(gl08.clone()));
+        assert_eq!(client.get_band_headcount(&batch_hash, &gl08), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid band range")]
+    fn test_invalid_band_range() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::define_band(env.clone(), symbol_short!("gl08"), 2_000, 1_000)
+        });
+    }
+}
@@ -0,0 +1,284 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, token, Address, BytesN, Env};
+
+use super::*;
+
+// Ledgers after an uploader's last batch before a bond can be withdrawn (~30 days)
+pub const BOND_UNLOCK_LEDGERS: u32 = 30 * ttl::DAY_IN_LEDGERS;
+
+// Bond terms for delegated uploaders
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BondConfig {
+    pub token: Address,
+    pub min_bond: i128,     // bond needed before an uploader may record batches
+    pub slash_bps: u32,     // share of the bond forfeited per invalidated batch, in basis points
+    pub fund: Address,      // transparency fund receiving slashed tokens
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct UploaderBond {
+    pub token: Address,
+    pub amount: i128,
+    pub posted_at: u64,
+}
+
+#[contracttype]
+enum BondKey {
+    BondConfig,
+    UploaderBond(Address),
+    SlashedBatch(BytesN<32>),
+}
+
+fn bond_config(env: &Env) -> Option<BondConfig> {
+    storage::get(env, DataClass::Config, &BondKey::BondConfig)
+}
+
+fn set_bond(env: &Env, uploader: &Address, bond: &UploaderBond) {
+    let key = BondKey::UploaderBond(uploader.clone());
+    storage::set(env, DataClass::Records, &key, bond);
+}
+
+// Delegated uploaders must hold the minimum bond; the owner and the contract itself are exempt
+pub(crate) fn require_bonded(env: &Env, uploader: &Address) {
+    let config = match bond_config(env) {
+        Some(config) => config,
+        None => return,
+    };
+    if auth::is_owner(env, uploader) || *uploader == env.current_contract_address() {
+        return;
+    }
+
+    let bonded = StaffRegistry::get_uploader_bond(env.clone(), uploader.clone()).map_or(0, |bond| bond.amount);
+    if bonded < config.min_bond {
+        panic!("Insufficient bond");
+    }
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Set the bond terms for delegated uploaders (owner only)
+    pub fn set_bond_config(env: Env, config: BondConfig) {
+        let owner = auth::require_owner(&env);
+        ttl::extend_instance(&env);
+
+        if config.min_bond < 0 || config.slash_bps > 10_000 {
+            panic!("Invalid bond config");
+        }
+        if let Some(current) = bond_config(&env) {
+            if current.token != config.token {
+                panic!("Token mismatch");
+            }
+        }
+        storage::set(&env, DataClass::Config, &BondKey::BondConfig, &config);
+
+        audit::log_action(&env, symbol_short!("bond_cfg"), &owner);
+
+        env.events().publish(
+            (symbol_short!("bond_cfg"),),
+            (config.token, config.min_bond, config.slash_bps, config.fund, env.ledger().timestamp())
+        );
+    }
+
+    /// Get the bond terms, if bonding is enabled
+    pub fn get_bond_config(env: Env) -> Option<BondConfig> {
+        bond_config(&env)
+    }
+
+    /// Post (or top up) an uploader's bond
+    pub fn post_bond(env: Env, uploader: Address, token: Address, amount: i128) {
+        uploader.require_auth();
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        let config = bond_config(&env).unwrap_or_else(|| panic!("Bonding not configured"));
+        if token != config.token {
+            panic!("Token mismatch");
+        }
+        if amount <= 0 {
+            panic!("Invalid amount");
+        }
+
+        token::Client::new(&env, &token).transfer(&uploader, &env.current_contract_address(), &amount);
+
+        let mut bond = Self::get_uploader_bond(env.clone(), uploader.clone()).unwrap_or(UploaderBond {
+            token: token.clone(),
+            amount: 0,
+            posted_at: env.ledger().timestamp(),
+        });
+        bond.amount += amount;
+        set_bond(&env, &uploader, &bond);
+
+        audit::log_action(&env, symbol_short!("bond_post"), &uploader);
+
+        env.events().publish(
+            (symbol_short!("bond_post"), uploader),
+            (token, amount, bond.amount, env.ledger().timestamp())
+        );
+    }
+
+    /// Withdraw a bond once the uploader role is gone and the last upload is past review
+    pub fn withdraw_bond(env: Env, uploader: Address, amount: i128) {
+        uploader.require_auth();
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        if roles::roles_of(&env, &uploader).contains(Role::PayrollUploader) {
+            panic!("Uploader still active");
+        }
+        if let Some(last_batch) = indexes::last_batch_by(&env, &uploader) {
+            let unlock_at = challenge::recorded_ledger(&env, &last_batch).saturating_add(BOND_UNLOCK_LEDGERS);
+            if env.ledger().sequence() < unlock_at {
+                panic!("Bond locked");
+            }
+        }
+
+        let mut bond = Self::get_uploader_bond(env.clone(), uploader.clone())
+            .unwrap_or_else(|| panic!("No bond posted"));
+        if amount <= 0 || amount > bond.amount {
+            panic!("Invalid amount");
+        }
+
+        token::Client::new(&env, &bond.token).transfer(&env.current_contract_address(), &uploader, &amount);
+        bond.amount -= amount;
+        set_bond(&env, &uploader, &bond);
+
+        audit::log_action(&env, symbol_short!("bond_wdr"), &uploader);
+
+        env.events().publish(
+            (symbol_short!("bond_wdr"), uploader),
+            (amount, bond.amount, env.ledger().timestamp())
+        );
+    }
+
+    /// Forfeit part of the uploader's bond to the transparency fund for an invalidated batch
+    /// (owner or Auditor); returns the amount slashed
+    pub fn slash_uploader(env: Env, caller: Address, batch_hash: BytesN<32>) -> i128 {
+        // onlyOwner or Auditor
+        auth::require_role(&env, &caller, Role::Auditor);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        let config = bond_config(&env).unwrap_or_else(|| panic!("Bonding not configured"));
+        let batch = Self::get_payroll_batch(env.clone(), batch_hash.clone());
+        if batch.is_valid || corrections::is_tombstoned(&batch) {
+            panic!("Batch not invalidated");
+        }
+
+        let slashed_key = BondKey::SlashedBatch(batch_hash.clone());
+        if storage::has(&env, DataClass::Records, &slashed_key) {
+            panic!("Batch already slashed");
+        }
+
+        let uploader = batch.uploaded_by;
+        let mut bond = Self::get_uploader_bond(env.clone(), uploader.clone())
+            .unwrap_or_else(|| panic!("No bond posted"));
+        let amount = bond.amount * config.slash_bps as i128 / 10_000;
+
+        if amount > 0 {
+            token::Client::new(&env, &bond.token).transfer(&env.current_contract_address(), &config.fund, &amount);
+        }
+        bond.amount -= amount;
+        set_bond(&env, &uploader, &bond);
+
+        storage::set(&env, DataClass::Records, &slashed_key, &amount);
+
+        audit::log(&env, symbol_short!("slashed"), &caller, &batch_hash);
+
+        env.events().publish(
+            (symbol_short!("slashed"), batch_hash),
+            (uploader, amount, config.fund, env.ledger().timestamp())
+        );
+
+        amount
+    }
+
+    /// Get an uploader's posted bond
+    pub fn get_uploader_bond(env: Env, uploader: Address) -> Option<UploaderBond> {
+        storage::get(&env, DataClass::Records, &BondKey::UploaderBond(uploader))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, token::StellarAssetClient, Env, Symbol};
+
+    #[test]
+    fn test_bond_and_slash() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let uploader = Address::generate(&env);
+        let fund = Address::generate(&env);
+        client.initialize(&owner);
+        client.add_uploader(&uploader);
+
+        let token = env.register_stellar_asset_contract(Address::generate(&env));
+        StellarAssetClient::new(&env, &token).mint(&uploader, &1_000);
+        client.set_bond_config(&BondConfig { token: token.clone(), min_bond: 1_000, slash_bps: 2_500, fund: fund.clone() });
+        client.post_bond(&uploader, &token, &1_000);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&uploader, &batch_hash, &3);
+        client.invalidate_batch(&owner, &batch_hash, &Symbol::new(&env, "bad_data"));
+
+        assert_eq!(client.slash_uploader(&owner, &batch_hash), 250);
+        assert_eq!(token::Client::new(&env, &token).balance(&fund), 250);
+        assert_eq!(client.get_uploader_bond(&uploader).unwrap().amount, 750);
+    }
+
+    #[test]
+    #[should_panic(expected = "Insufficient bond")]
+    fn test_unbonded_uploader() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let uploader = Address::generate(&env);
+        client.initialize(&owner);
+        client.add_uploader(&uploader);
+
+        let token = env.register_stellar_asset_contract(Address::generate(&env));
+        client.set_bond_config(&BondConfig { token, min_bond: 1_000, slash_bps: 2_500, fund: Address::generate(&env) });
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::record_payroll_batch(env.clone(), uploader, BytesN::from_array(&env, &[2u8; 32]), 3)
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Contract is paused")]
+    fn test_withdraw_bond_while_paused() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let uploader = Address::generate(&env);
+        client.initialize(&owner);
+
+        let token = env.register_stellar_asset_contract(Address::generate(&env));
+        StellarAssetClient::new(&env, &token).mint(&uploader, &1_000);
+        client.set_bond_config(&BondConfig { token: token.clone(), min_bond: 1_000, slash_bps: 2_500, fund: Address::generate(&env) });
+        client.post_bond(&uploader, &token, &1_000);
+        client.pause(&owner);
+
+        env.as_contract(&contract_id, || StaffRegistry::withdraw_bond(env.clone(), uploader, 1_000));
+    }
+}
@@ -0,0 +1,133 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, BytesN, Env};
+
+use super::*;
+
+// Published spending cap for a pay period (YYYYMM)
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PeriodBudget {
+    pub amount_commitment: BytesN<32>, // hash commitment to the published budget document
+    pub cap: i128,                     // maximum total disbursement for the period
+    pub disbursed: i128,               // paid out so far against this period
+}
+
+#[contracttype]
+enum BudgetKey {
+    PeriodBudget(u32),
+}
+
+pub(crate) fn period_budget(env: &Env, period_id: u32) -> Option<PeriodBudget> {
+    storage::get(env, DataClass::Records, &BudgetKey::PeriodBudget(period_id))
+}
+
+pub(crate) fn set_period_budget_state(env: &Env, period_id: u32, budget: &PeriodBudget) {
+    let key = BudgetKey::PeriodBudget(period_id);
+    storage::set(env, DataClass::Records, &key, budget);
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Publish or revise the disbursement cap for a pay period (YYYYMM, owner only)
+    pub fn set_period_budget(env: Env, period_id: u32, amount_commitment: BytesN<32>, cap: i128) {
+        let owner = auth::require_owner(&env);
+        ttl::extend_instance(&env);
+
+        periods::require_valid_period(period_id);
+
+        let disbursed = period_budget(&env, period_id).map_or(0, |budget| budget.disbursed);
+        if cap < disbursed {
+            panic!("Cap below disbursed amount");
+        }
+
+        set_period_budget_state(&env, period_id, &PeriodBudget { amount_commitment: amount_commitment.clone(), cap, disbursed });
+
+        audit::log(&env, symbol_short!("budget"), &owner, &amount_commitment);
+
+        env.events().publish(
+            (symbol_short!("budget"), period_id),
+            (amount_commitment, cap, env.ledger().timestamp())
+        );
+    }
+
+    /// Get the budget published for a pay period
+    pub fn get_period_budget(env: Env, period_id: u32) -> Option<PeriodBudget> {
+        period_budget(&env, period_id)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, token, token::StellarAssetClient, Address, Env, Vec};
+
+    #[test]
+    fn test_disbursement_capped_by_period_budget() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_challenge_period(&0);
+
+        let token = env.register_stellar_asset_contract(Address::generate(&env));
+        StellarAssetClient::new(&env, &token).mint(&owner, &2_000);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_batch_for_period(&owner, &batch_hash, &2, &202608);
+        let auditor = Address::generate(&env);
+        client.grant_role(&auditor, &Role::Auditor);
+        client.attest_batch(&auditor, &batch_hash);
+        client.fund_batch(&owner, &batch_hash, &token, &2_000);
+
+        client.set_period_budget(&202608, &BytesN::from_array(&env, &[9u8; 32]), &1_000);
+        // Budgets for other periods do not apply
+        client.set_period_budget(&202607, &BytesN::from_array(&env, &[8u8; 32]), &5_000);
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let mut payments = Vec::new(&env);
+        payments.push_back((alice.clone(), 600));
+        payments.push_back((bob.clone(), 600)); // funded, but over the period cap
+
+        let results = client.disburse(&owner, &batch_hash, &payments);
+        assert_eq!(results, Vec::from_array(&env, [true, false]));
+        assert_eq!(token::Client::new(&env, &token).balance(&bob), 0);
+        assert_eq!(client.get_period_budget(&202608).unwrap().disbursed, 600);
+        assert_eq!(client.get_period_budget(&202607).unwrap().disbursed, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cap below disbursed amount")]
+    fn test_cap_below_disbursed() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+
+        let commitment = BytesN::from_array(&env, &[9u8; 32]);
+        env.as_contract(&contract_id, || {
+            set_period_budget_state(&env, 202608, &PeriodBudget { amount_commitment: commitment.clone(), cap: 1_000, disbursed: 600 });
+            StaffRegistry::set_period_budget(env.clone(), 202608, commitment, 500)
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid period id")]
+    fn test_budget_requires_calendar_period() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::set_period_budget(env.clone(), 0, BytesN::from_array(&env, &[9u8; 32]), 1_000)
+        });
+    }
+}
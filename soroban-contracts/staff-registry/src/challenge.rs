@@ -0,0 +1,273 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, BytesN, Env, Symbol};
+
+use super::*;
+
+// Ledgers a new batch stays open to disputes unless the owner configures otherwise
+pub const DEFAULT_CHALLENGE_LEDGERS: u32 = ttl::DAY_IN_LEDGERS;
+
+// Optimistic finalization state of a batch
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BatchStatus {
+    Pending,      // inside the challenge window
+    Disputed,     // window over but a dispute is still open
+    Final,        // window over with no open dispute; canonical
+    Invalidated,  // flagged invalid by an auditor
+    Superseded,   // replaced by a correction
+}
+
+#[contracttype]
+enum ChallengeKey {
+    ChallengePeriod,              // challenge window length in ledgers
+    RecordedLedger(BytesN<32>),   // ledger sequence a batch was recorded at
+    WindowCloses(BytesN<32>),     // ledger sequence a batch's challenge window closes at
+    OpenDisputeCount(BytesN<32>), // disputes on a batch not yet resolved or rejected
+    SettledCursor,                // batches (in record order) whose window close has been announced
+    OrgRecordedLedger(Symbol, BytesN<32>), // the same, for a batch in an org namespace
+    OrgWindowCloses(Symbol, BytesN<32>),
+}
+
+fn recorded_key(scope: &Scope, batch_hash: &BytesN<32>) -> ChallengeKey {
+    match scope {
+        Scope::Main => ChallengeKey::RecordedLedger(batch_hash.clone()),
+        Scope::Org(org_id) => ChallengeKey::OrgRecordedLedger(org_id.clone(), batch_hash.clone()),
+    }
+}
+
+fn closes_key(scope: &Scope, batch_hash: &BytesN<32>) -> ChallengeKey {
+    match scope {
+        Scope::Main => ChallengeKey::WindowCloses(batch_hash.clone()),
+        Scope::Org(org_id) => ChallengeKey::OrgWindowCloses(org_id.clone(), batch_hash.clone()),
+    }
+}
+
+fn challenge_period(env: &Env) -> u32 {
+    storage::get(env, DataClass::Config, &ChallengeKey::ChallengePeriod)
+        .unwrap_or(DEFAULT_CHALLENGE_LEDGERS)
+}
+
+fn open_dispute_count(env: &Env, batch_hash: &BytesN<32>) -> u32 {
+    storage::get(env, DataClass::Records, &ChallengeKey::OpenDisputeCount(batch_hash.clone()))
+        .unwrap_or(0)
+}
+
+fn set_open_dispute_count(env: &Env, batch_hash: &BytesN<32>, count: u32) {
+    let key = ChallengeKey::OpenDisputeCount(batch_hash.clone());
+    storage::set(env, DataClass::Records, &key, &count);
+}
+
+// Start a batch's challenge window at the current ledger, fixing its length so later
+// changes to the challenge period only apply to batches recorded after them
+pub(crate) fn track_batch(env: &Env, scope: &Scope, batch_hash: &BytesN<32>) {
+    storage::set(env, DataClass::Records, &recorded_key(scope, batch_hash), &env.ledger().sequence());
+
+    let closes_at = env.ledger().sequence().saturating_add(challenge_period(env));
+    storage::set(env, DataClass::Records, &closes_key(scope, batch_hash), &closes_at);
+}
+
+// Ledger a batch's challenge window closes at; batches tracked before windows were
+// stored fall back to the current period
+fn window_closes(env: &Env, scope: &Scope, batch_hash: &BytesN<32>) -> u32 {
+    storage::get(env, DataClass::Records, &closes_key(scope, batch_hash))
+        .unwrap_or_else(|| scoped_recorded_ledger(env, scope, batch_hash).saturating_add(challenge_period(env)))
+}
+
+fn scoped_recorded_ledger(env: &Env, scope: &Scope, batch_hash: &BytesN<32>) -> u32 {
+    storage::get(env, DataClass::Records, &recorded_key(scope, batch_hash))
+        .unwrap_or(0)
+}
+
+// Ledger sequence a batch was recorded at (0 for batches that predate tracking)
+pub(crate) fn recorded_ledger(env: &Env, batch_hash: &BytesN<32>) -> u32 {
+    scoped_recorded_ledger(env, &Scope::Main, batch_hash)
+}
+
+// Announce up to `max` batches whose challenge window has closed, oldest first
+pub(crate) fn close_expired_windows(env: &Env, max: u32) -> u32 {
+    let total = lists::len(env, lists::List::Batch);
+    let mut cursor: u32 = storage::get(env, DataClass::Config, &ChallengeKey::SettledCursor).unwrap_or(0);
+
+    let mut closed = 0;
+    while closed < max && cursor < total {
+        let batch_hash = lists::get(env, lists::List::Batch, cursor).unwrap();
+        let status = StaffRegistry::get_batch_status(env.clone(), batch_hash.clone());
+        if status == BatchStatus::Pending {
+            break;
+        }
+
+        env.events().publish(
+            (symbol_short!("chal_end"), batch_hash),
+            (status, env.ledger().timestamp())
+        );
+        cursor += 1;
+        closed += 1;
+    }
+
+    if closed > 0 {
+        storage::set(env, DataClass::Config, &ChallengeKey::SettledCursor, &cursor);
+    }
+    closed
+}
+
+// Called when a dispute is filed; only pending batches can be challenged
+pub(crate) fn dispute_opened(env: &Env, batch_hash: &BytesN<32>) {
+    if StaffRegistry::get_batch_status(env.clone(), batch_hash.clone()) != BatchStatus::Pending {
+        panic!("Challenge period over");
+    }
+    set_open_dispute_count(env, batch_hash, open_dispute_count(env, batch_hash) + 1);
+}
+
+// Called when a dispute reaches a terminal state
+pub(crate) fn dispute_closed(env: &Env, batch_hash: &BytesN<32>) {
+    set_open_dispute_count(env, batch_hash, open_dispute_count(env, batch_hash).saturating_sub(1));
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Set how many ledgers new batches stay open to disputes (owner only); batches
+    /// already recorded keep the window they started with
+    pub fn set_challenge_period(env: Env, ledgers: u32) {
+        let owner = auth::require_owner(&env);
+
+        storage::set(&env, DataClass::Config, &ChallengeKey::ChallengePeriod, &ledgers);
+        ttl::extend_instance(&env);
+
+        audit::log_action(&env, symbol_short!("chal_per"), &owner);
+
+        env.events().publish(
+            (symbol_short!("chal_per"),),
+            (ledgers, env.ledger().timestamp())
+        );
+    }
+
+    /// Get the challenge window length in ledgers
+    pub fn get_challenge_period(env: Env) -> u32 {
+        challenge_period(&env)
+    }
+
+    /// Get where a batch is in the optimistic finalization flow
+    pub fn get_batch_status(env: Env, batch_hash: BytesN<32>) -> BatchStatus {
+        let batch = Self::get_payroll_batch(env.clone(), batch_hash.clone());
+        if !batch.is_valid {
+            return BatchStatus::Invalidated;
+        }
+        if batch.superseded_by != BytesN::from_array(&env, &[0u8; 32]) {
+            return BatchStatus::Superseded;
+        }
+
+        if env.ledger().sequence() < window_closes(&env, &Scope::Main, &batch_hash) {
+            return BatchStatus::Pending;
+        }
+
+        if open_dispute_count(&env, &batch_hash) > 0 {
+            BatchStatus::Disputed
+        } else {
+            BatchStatus::Final
+        }
+    }
+
+    /// Get where a batch in an organization's namespace is in its challenge window.
+    /// Org batches can't be disputed, invalidated or corrected, so they are either
+    /// Pending or Final.
+    pub fn org_get_batch_status(env: Env, org_id: Symbol, batch_hash: BytesN<32>) -> BatchStatus {
+        Self::org_get_payroll_batch(env.clone(), org_id.clone(), batch_hash.clone());
+
+        if env.ledger().sequence() < window_closes(&env, &Scope::Org(org_id), &batch_hash) {
+            BatchStatus::Pending
+        } else {
+            BatchStatus::Final
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env};
+
+    #[test]
+    fn test_batch_finalizes_after_challenge_period() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.sequence_number = 100);
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let employee = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+        client.set_challenge_period(&50);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        let zero = BytesN::from_array(&env, &[0u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        client.bind_staff_address(&owner, &staff_hash, &employee);
+        client.record_batch_with_members(&owner, &batch_hash, &Vec::from_array(&env, [staff_hash.clone()]));
+        assert_eq!(client.get_batch_status(&batch_hash), BatchStatus::Pending);
+
+        let id = client.open_dispute(&employee, &batch_hash, &staff_hash, &BytesN::from_array(&env, &[3u8; 32]));
+
+        env.ledger().with_mut(|li| li.sequence_number = 150);
+        assert_eq!(client.get_batch_status(&batch_hash), BatchStatus::Disputed);
+
+        client.set_dispute_status(&owner, &id, &DisputeStatus::Rejected, &zero);
+        assert_eq!(client.get_batch_status(&batch_hash), BatchStatus::Final);
+    }
+
+    #[test]
+    fn test_period_change_keeps_recorded_windows() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_challenge_period(&10);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &1);
+        env.ledger().with_mut(|li| li.sequence_number += 10);
+        assert_eq!(client.get_batch_status(&batch_hash), BatchStatus::Final);
+
+        // A longer period doesn't reopen a window that has already closed
+        client.set_challenge_period(&100);
+        assert_eq!(client.get_batch_status(&batch_hash), BatchStatus::Final);
+
+        let later = BytesN::from_array(&env, &[3u8; 32]);
+        client.record_payroll_batch(&owner, &later, &1);
+        env.ledger().with_mut(|li| li.sequence_number += 10);
+        assert_eq!(client.get_batch_status(&later), BatchStatus::Pending);
+    }
+
+    #[test]
+    #[should_panic(expected = "Challenge period over")]
+    fn test_dispute_after_challenge_period() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let employee = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+        client.set_challenge_period(&10);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        client.bind_staff_address(&owner, &staff_hash, &employee);
+        client.record_batch_with_members(&owner, &batch_hash, &Vec::from_array(&env, [staff_hash.clone()]));
+
+        env.ledger().with_mut(|li| li.sequence_number += 10);
+        env.as_contract(&contract_id, || {
+            StaffRegistry::open_dispute(env.clone(), employee, batch_hash, staff_hash, BytesN::from_array(&env, &[3u8; 32]))
+        });
+    }
+}
@@ -0,0 +1,126 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env};
+
+use super::*;
+
+// Registry state pinned at a point in time; `digest` commits to every other field
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Checkpoint {
+    pub epoch: u64,
+    pub digest: BytesN<32>,
+    pub staff_count: u32,
+    pub batch_count: u32,
+    pub latest_staff_hash: BytesN<32>,  // zero when no staff are registered
+    pub latest_batch_hash: BytesN<32>,  // zero when no batches are recorded
+    pub audit_seq: u64,                 // last audit log entry covered
+    pub ledger: u32,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+enum CheckpointKey {
+    CheckpointEpoch,    // last checkpoint epoch
+    Checkpoint(u64),    // checkpoint by epoch
+}
+
+fn last_hash(env: &Env, list: lists::List) -> BytesN<32> {
+    lists::last(env, list).unwrap_or(BytesN::from_array(env, &[0u8; 32]))
+}
+
+// sha256(epoch || staff_count || batch_count || latest_staff || latest_batch || audit_seq), big-endian
+fn checkpoint_digest(env: &Env, checkpoint: &Checkpoint) -> BytesN<32> {
+    let mut data = Bytes::from_array(env, &checkpoint.epoch.to_be_bytes());
+    data.extend_from_array(&checkpoint.staff_count.to_be_bytes());
+    data.extend_from_array(&checkpoint.batch_count.to_be_bytes());
+    data.extend_from_array(&checkpoint.latest_staff_hash.to_array());
+    data.extend_from_array(&checkpoint.latest_batch_hash.to_array());
+    data.extend_from_array(&checkpoint.audit_seq.to_be_bytes());
+    env.crypto().sha256(&data)
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Pin the current registry state under a new epoch and return its digest (owner or Auditor)
+    pub fn checkpoint(env: Env, caller: Address) -> BytesN<32> {
+        // onlyOwner or Auditor
+        auth::require_role(&env, &caller, Role::Auditor);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        let epoch: u64 = storage::get(&env, DataClass::Config, &CheckpointKey::CheckpointEpoch).unwrap_or(0) + 1;
+        storage::set(&env, DataClass::Config, &CheckpointKey::CheckpointEpoch, &epoch);
+
+        let mut checkpoint = Checkpoint {
+            epoch,
+            digest: BytesN::from_array(&env, &[0u8; 32]),
+            staff_count: Self::get_total_staff(env.clone()),
+            batch_count: Self::get_total_batches(env.clone()),
+            latest_staff_hash: last_hash(&env, lists::List::Staff),
+            latest_batch_hash: last_hash(&env, lists::List::Batch),
+            audit_seq: Self::get_audit_length(env.clone()),
+            ledger: env.ledger().sequence(),
+            timestamp: env.ledger().timestamp(),
+        };
+        checkpoint.digest = checkpoint_digest(&env, &checkpoint);
+
+        let key = CheckpointKey::Checkpoint(epoch);
+        storage::set(&env, DataClass::Records, &key, &checkpoint);
+
+        audit::log(&env, symbol_short!("chkpoint"), &caller, &checkpoint.digest);
+
+        env.events().publish(
+            (symbol_short!("chkpoint"), epoch),
+            (checkpoint.digest.clone(), caller, env.ledger().timestamp())
+        );
+
+        checkpoint.digest
+    }
+
+    /// Get a checkpoint by epoch
+    pub fn get_checkpoint(env: Env, epoch: u64) -> Checkpoint {
+        storage::get(&env, DataClass::Records, &CheckpointKey::Checkpoint(epoch))
+            .unwrap_or_else(|| panic!("Checkpoint not found"))
+    }
+
+    /// Get the latest checkpoint epoch (0 if none)
+    pub fn get_checkpoint_epoch(env: Env) -> u64 {
+        storage::get(&env, DataClass::Config, &CheckpointKey::CheckpointEpoch).unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_checkpoints() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        client.record_payroll_batch(&owner, &batch_hash, &1);
+
+        let first = client.checkpoint(&owner);
+        let pinned = client.get_checkpoint(&1);
+        assert_eq!(pinned.digest, first);
+        assert_eq!((pinned.staff_count, pinned.batch_count), (1, 1));
+        assert_eq!(pinned.latest_batch_hash, batch_hash);
+
+        // Same state, new epoch: the digest still changes
+        let second = client.checkpoint(&owner);
+        assert_ne!(first, second);
+        assert_eq!(client.get_checkpoint_epoch(), 2);
+    }
+}
@@ -0,0 +1,257 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, token, Address, BytesN, Env, Vec};
+
+use super::*;
+
+// How long staff have to pull their payment before it can be clawed back (~90 days)
+pub const CLAIM_WINDOW_SECONDS: u64 = 90 * 86_400;
+
+// Pull-based payout escrowed for a batch
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClaimSet {
+    pub token: Address,
+    pub funder: Address,    // receives unclaimed amounts on clawback
+    pub total: i128,
+    pub claimed: i128,
+    pub created_at: u64,
+    pub expires_at: u64,
+    pub clawed_back: bool,
+}
+
+// One staff address's share of a claim set
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Claim {
+    pub amount: i128,
+    pub claimed_at: Option<u64>,
+}
+
+#[contracttype]
+enum ClaimKey {
+    ClaimSet(BytesN<32>),
+    Claim(BytesN<32>, Address),  // (batch, claimant) share
+}
+
+fn load_claim_set(env: &Env, batch_hash: &BytesN<32>) -> ClaimSet {
+    storage::get(env, DataClass::Records, &ClaimKey::ClaimSet(batch_hash.clone()))
+        .unwrap_or_else(|| panic!("No claims for batch"))
+}
+
+fn save_claim_set(env: &Env, batch_hash: &BytesN<32>, claims: &ClaimSet) {
+    let key = ClaimKey::ClaimSet(batch_hash.clone());
+    storage::set(env, DataClass::Records, &key, claims);
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Escrow a batch's payments for staff to pull themselves, instead of pushing
+    /// transfers that can fail against frozen or unfunded accounts
+    pub fn create_claims(env: Env, caller: Address, batch_hash: BytesN<32>, claims: Vec<(Address, i128)>, token: Address) {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        let batch = Self::get_payroll_batch(env.clone(), batch_hash.clone());
+        if !batch.is_valid {
+            panic!("Batch is invalidated");
+        }
+        if storage::has(&env, DataClass::Records, &ClaimKey::ClaimSet(batch_hash.clone())) {
+            panic!("Claims already created");
+        }
+        if claims.is_empty() || claims.len() > MAX_BULK_SIZE {
+            panic!("Invalid claims");
+        }
+
+        let mut total: i128 = 0;
+        for (claimant, amount) in claims.iter() {
+            if amount <= 0 {
+                panic!("Invalid amount");
+            }
+            let key = ClaimKey::Claim(batch_hash.clone(), claimant);
+            if storage::has(&env, DataClass::Records, &key) {
+                panic!("Duplicate claimant");
+            }
+            storage::set(&env, DataClass::Records, &key, &Claim { amount, claimed_at: None });
+            total += amount;
+        }
+
+        token::Client::new(&env, &token).transfer(&caller, &env.current_contract_address(), &total);
+
+        let now = env.ledger().timestamp();
+        save_claim_set(&env, &batch_hash, &ClaimSet {
+            token: token.clone(),
+            funder: caller.clone(),
+            total,
+            claimed: 0,
+            created_at: now,
+            expires_at: now.saturating_add(CLAIM_WINDOW_SECONDS),
+            clawed_back: false,
+        });
+
+        audit::log(&env, symbol_short!("claim_new"), &caller, &batch_hash);
+
+        env.events().publish(
+            (symbol_short!("claim_new"), batch_hash),
+            (token, total, claims.len(), now)
+        );
+    }
+
+    /// Pull the caller's own payment for a batch once escrow has released
+    pub fn claim(env: Env, claimant: Address, batch_hash: BytesN<32>) -> i128 {
+        claimant.require_auth();
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        escrow::require_released(&env, &batch_hash);
+
+        let mut claims = load_claim_set(&env, &batch_hash);
+        if claims.clawed_back || env.ledger().timestamp() >= claims.expires_at {
+            panic!("Claim expired");
+        }
+
+        let key = ClaimKey::Claim(batch_hash.clone(), claimant.clone());
+        let mut share: Claim = storage::get(&env, DataClass::Records, &key)
+            .unwrap_or_else(|| panic!("Nothing to claim"));
+        if share.claimed_at.is_some() {
+            panic!("Already claimed");
+        }
+
+        share.claimed_at = Some(env.ledger().timestamp());
+        storage::set(&env, DataClass::Records, &key, &share);
+        claims.claimed += share.amount;
+        save_claim_set(&env, &batch_hash, &claims);
+
+        token::Client::new(&env, &claims.token).transfer(&env.current_contract_address(), &claimant, &share.amount);
+
+        env.events().publish(
+            (symbol_short!("claimed"), batch_hash),
+            (claimant, share.amount, env.ledger().timestamp())
+        );
+
+        share.amount
+    }
+
+    /// Return unclaimed amounts to the funder after the claim window closes (owner or PayrollUploader)
+    pub fn clawback_claims(env: Env, caller: Address, batch_hash: BytesN<32>) -> i128 {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        let mut claims = load_claim_set(&env, &batch_hash);
+        if claims.clawed_back {
+            panic!("Already clawed back");
+        }
+        if env.ledger().timestamp() < claims.expires_at {
+            panic!("Claim window open");
+        }
+
+        let unclaimed = claims.total - claims.claimed;
+        claims.clawed_back = true;
+        save_claim_set(&env, &batch_hash, &claims);
+
+        if unclaimed > 0 {
+            token::Client::new(&env, &claims.token).transfer(&env.current_contract_address(), &claims.funder, &unclaimed);
+        }
+
+        audit::log(&env, symbol_short!("claim_clw"), &caller, &batch_hash);
+
+        env.events().publish(
+            (symbol_short!("claim_clw"), batch_hash),
+            (claims.funder, unclaimed, env.ledger().timestamp())
+        );
+
+        unclaimed
+    }
+
+    /// Get the claim set escrowed for a batch, if any
+    pub fn get_claim_set(env: Env, batch_hash: BytesN<32>) -> Option<ClaimSet> {
+        storage::get(&env, DataClass::Records, &ClaimKey::ClaimSet(batch_hash))
+    }
+
+    /// Get a staff address's share of a batch's claims, if it has one
+    pub fn get_claim(env: Env, batch_hash: BytesN<32>, claimant: Address) -> Option<Claim> {
+        storage::get(&env, DataClass::Records, &ClaimKey::Claim(batch_hash, claimant))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Ledger}, token::StellarAssetClient, Env};
+
+    #[test]
+    fn test_claim_and_clawback() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_challenge_period(&0);
+
+        let token = env.register_stellar_asset_contract(Address::generate(&env));
+        StellarAssetClient::new(&env, &token).mint(&owner, &1_000);
+        let token_client = token::Client::new(&env, &token);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &2);
+        let auditor = Address::generate(&env);
+        client.grant_role(&auditor, &Role::Auditor);
+        client.attest_batch(&auditor, &batch_hash);
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        client.create_claims(&owner, &batch_hash, &Vec::from_array(&env, [(alice.clone(), 600), (bob.clone(), 300)]), &token);
+        assert_eq!(token_client.balance(&contract_id), 900);
+
+        assert_eq!(client.claim(&alice, &batch_hash), 600);
+        assert_eq!(token_client.balance(&alice), 600);
+        assert!(client.get_claim(&batch_hash, &alice).unwrap().claimed_at.is_some());
+
+        // Bob never claims; his share goes back to the funder after expiry
+        let expires_at = client.get_claim_set(&batch_hash).unwrap().expires_at;
+        env.ledger().with_mut(|li| li.timestamp = expires_at);
+        assert_eq!(client.clawback_claims(&owner, &batch_hash), 300);
+        assert_eq!(token_client.balance(&owner), 400);
+        assert_eq!(token_client.balance(&contract_id), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Already claimed")]
+    fn test_claim_twice() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_challenge_period(&0);
+
+        let token = env.register_stellar_asset_contract(Address::generate(&env));
+        StellarAssetClient::new(&env, &token).mint(&owner, &1_000);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &1);
+        let auditor = Address::generate(&env);
+        client.grant_role(&auditor, &Role::Auditor);
+        client.attest_batch(&auditor, &batch_hash);
+
+        let alice = Address::generate(&env);
+        client.create_claims(&owner, &batch_hash, &Vec::from_array(&env, [(alice.clone(), 600)]), &token);
+        client.claim(&alice, &batch_hash);
+
+        env.as_contract(&contract_id, || StaffRegistry::claim(env.clone(), alice, batch_hash));
+    }
+}
@@ -0,0 +1,151 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env, Symbol, Vec};
+
+use super::*;
+
+// Most recoveries recorded against one batch
+pub const MAX_CLAWBACKS_PER_BATCH: u32 = 100;
+
+// Salary recovered from a staff member after a batch was paid (e.g. an overpayment)
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SalaryClawback {
+    pub id: u64,
+    pub batch_hash: BytesN<32>,
+    pub staff_hash: BytesN<32>,
+    pub reason: Symbol,
+    pub amount_commitment: BytesN<32>,
+    pub recorded_by: Address,
+    pub recorded_at: u64,
+}
+
+#[contracttype]
+enum ClawbackKey {
+    ClawbackCount,              // last issued clawback id
+    Clawback(u64),
+    BatchClawbacks(BytesN<32>), // clawback ids per batch
+    StaffClawbacks(BytesN<32>), // clawback ids per staff member
+}
+
+fn clawback_ids(env: &Env, key: &ClawbackKey) -> Vec<u64> {
+    storage::get(env, DataClass::Records, key).unwrap_or(Vec::new(env))
+}
+
+fn append_id(env: &Env, key: ClawbackKey, id: u64) {
+    let mut ids = clawback_ids(env, &key);
+    ids.push_back(id);
+    storage::set(env, DataClass::Records, &key, &ids);
+}
+
+fn load_clawbacks(env: &Env, ids: Vec<u64>) -> Vec<SalaryClawback> {
+    let mut clawbacks = Vec::new(env);
+    for id in ids.iter() {
+        if let Some(clawback) = storage::get(env, DataClass::Records, &ClawbackKey::Clawback(id)) {
+            clawbacks.push_back(clawback);
+        }
+    }
+    clawbacks
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Publish a salary recovery against a paid batch (owner or PayrollUploader); returns its id
+    pub fn record_clawback(env: Env, caller: Address, batch_hash: BytesN<32>, staff_hash: BytesN<32>, reason: Symbol, amount_commitment: BytesN<32>) -> u64 {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        if !Self::is_batch_recorded(env.clone(), batch_hash.clone()) {
+            panic!("Batch not found");
+        }
+        if !Self::is_staff_registered(env.clone(), staff_hash.clone()) {
+            panic!("Staff not found");
+        }
+        if clawback_ids(&env, &ClawbackKey::BatchClawbacks(batch_hash.clone())).len() >= MAX_CLAWBACKS_PER_BATCH {
+            panic!("Too many clawbacks");
+        }
+
+        let id: u64 = storage::get(&env, DataClass::Config, &ClawbackKey::ClawbackCount).unwrap_or(0) + 1;
+        storage::set(&env, DataClass::Config, &ClawbackKey::ClawbackCount, &id);
+
+        let clawback = SalaryClawback {
+            id,
+            batch_hash: batch_hash.clone(),
+            staff_hash: staff_hash.clone(),
+            reason: reason.clone(),
+            amount_commitment,
+            recorded_by: caller.clone(),
+            recorded_at: env.ledger().timestamp(),
+        };
+        let key = ClawbackKey::Clawback(id);
+        storage::set(&env, DataClass::Records, &key, &clawback);
+        append_id(&env, ClawbackKey::BatchClawbacks(batch_hash.clone()), id);
+        append_id(&env, ClawbackKey::StaffClawbacks(staff_hash.clone()), id);
+
+        audit::log(&env, symbol_short!("clawback"), &caller, &batch_hash);
+
+        env.events().publish(
+            (symbol_short!("clawback"), batch_hash, staff_hash),
+            (id, reason, env.ledger().timestamp())
+        );
+
+        id
+    }
+
+    /// Get a clawback by id
+    pub fn get_clawback(env: Env, id: u64) -> SalaryClawback {
+        storage::get(&env, DataClass::Records, &ClawbackKey::Clawback(id))
+            .unwrap_or_else(|| panic!("Clawback not found"))
+    }
+
+    /// Get every clawback recorded against a batch, oldest first
+    pub fn get_batch_clawbacks(env: Env, batch_hash: BytesN<32>) -> Vec<SalaryClawback> {
+        load_clawbacks(&env, clawback_ids(&env, &ClawbackKey::BatchClawbacks(batch_hash)))
+    }
+
+    /// Get a page of clawbacks recorded against a staff member, oldest first
+    pub fn get_staff_clawbacks(env: Env, staff_hash: BytesN<32>, start: u32, limit: u32) -> Vec<SalaryClawback> {
+        let ids = clawback_ids(&env, &ClawbackKey::StaffClawbacks(staff_hash));
+        load_clawbacks(&env, paginate(&env, &ids, start, limit))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_record_clawback() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let january = BytesN::from_array(&env, &[2u8; 32]);
+        let february = BytesN::from_array(&env, &[3u8; 32]);
+        let commitment = BytesN::from_array(&env, &[9u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        client.record_payroll_batch(&owner, &january, &1);
+        client.record_payroll_batch(&owner, &february, &1);
+
+        let overpaid = Symbol::new(&env, "overpaid");
+        assert_eq!(client.record_clawback(&owner, &january, &staff_hash, &overpaid, &commitment), 1);
+        assert_eq!(client.record_clawback(&owner, &february, &staff_hash, &symbol_short!("advance"), &commitment), 2);
+
+        let clawback = client.get_clawback(&1);
+        assert_eq!(clawback.reason, overpaid);
+        assert_eq!(clawback.batch_hash, january);
+        assert_eq!(client.get_batch_clawbacks(&january).len(), 1);
+        assert_eq!(client.get_staff_clawbacks(&staff_hash, &0, &10).len(), 2);
+        assert_eq!(client.get_staff_clawbacks(&staff_hash, &1, &10).get(0).unwrap().batch_hash, february);
+    }
+}
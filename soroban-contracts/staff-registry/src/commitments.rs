@@ -0,0 +1,128 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env};
+
+use super::*;
+
+// Domain tag for batch total commitments
+const TOTAL_COMMITMENT_TAG: &[u8] = b"payroll-total-v1";
+
+// Opening of a batch's aggregate payroll commitment
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TotalOpening {
+    pub amount: i128,         // aggregate payroll amount in the batch
+    pub blinding: BytesN<32>, // random blinding factor chosen at upload
+}
+
+#[contracttype]
+enum CommitmentKey {
+    TotalCommitment(BytesN<32>),
+}
+
+// digest(tag || amount as 16 big-endian bytes || blinding) in the active hash mode.
+// A hash commitment is hiding and binding but, unlike Pedersen, not additive.
+pub(crate) fn amount_commitment(env: &Env, tag: &[u8], opening: &TotalOpening) -> BytesN<32> {
+    let mut preimage = Bytes::from_slice(env, tag);
+    preimage.extend_from_array(&opening.amount.to_be_bytes());
+    preimage.extend_from_array(&opening.blinding.to_array());
+    hashing::digest(env, hashing::hash_mode(env), &preimage)
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Commit to a batch's aggregate payroll amount without revealing it (owner or PayrollUploader)
+    pub fn set_total_commitment(env: Env, caller: Address, batch_hash: BytesN<32>, commitment: BytesN<32>) {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        if !Self::is_batch_recorded(env.clone(), batch_hash.clone()) {
+            panic!("Batch not found");
+        }
+        let key = CommitmentKey::TotalCommitment(batch_hash.clone());
+        if storage::has(&env, DataClass::Records, &key) {
+            panic!("Total commitment already set");
+        }
+
+        storage::set(&env, DataClass::Records, &key, &commitment);
+
+        audit::log(&env, symbol_short!("total_com"), &caller, &batch_hash);
+
+        env.events().publish(
+            (symbol_short!("total_com"), batch_hash),
+            (commitment, env.ledger().timestamp())
+        );
+    }
+
+    /// Get a batch's aggregate payroll commitment
+    pub fn get_total_commitment(env: Env, batch_hash: BytesN<32>) -> Option<BytesN<32>> {
+        storage::get(&env, DataClass::Records, &CommitmentKey::TotalCommitment(batch_hash))
+    }
+
+    /// Compute the commitment for an opening, so uploaders can derive it off-chain identically
+    pub fn compute_total_commitment(env: Env, opening: TotalOpening) -> BytesN<32> {
+        amount_commitment(&env, TOTAL_COMMITMENT_TAG, &opening)
+    }
+
+    /// Check that an opening matches the commitment recorded for a batch
+    pub fn verify_total_commitment(env: Env, batch_hash: BytesN<32>, opening: TotalOpening) -> bool {
+        let commitment = Self::get_total_commitment(env.clone(), batch_hash)
+            .unwrap_or_else(|| panic!("No total commitment"));
+        amount_commitment(&env, TOTAL_COMMITMENT_TAG, &opening) == commitment
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_total_commitment_opening() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &10);
+
+        let opening = TotalOpening { amount: 1_250_000, blinding: BytesN::from_array(&env, &[42u8; 32]) };
+        let commitment = client.compute_total_commitment(&opening);
+        client.set_total_commitment(&owner, &batch_hash, &commitment);
+
+        assert!(client.verify_total_commitment(&batch_hash, &opening));
+        let wrong_amount = TotalOpening { amount: 1_250_001, ..opening.clone() };
+        assert!(!client.verify_total_commitment(&batch_hash, &wrong_amount));
+        let wrong_blinding = TotalOpening { blinding: BytesN::from_array(&env, &[43u8; 32]), ..opening };
+        assert!(!client.verify_total_commitment(&batch_hash, &wrong_blinding));
+    }
+
+    #[test]
+    #[should_panic(expected = "Total commitment already set")]
+    fn test_commitment_is_write_once() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        let commitment = BytesN::from_array(&env, &[3u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &10);
+        client.set_total_commitment(&owner, &batch_hash, &commitment);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::set_total_commitment(env.clone(), owner, batch_hash, commitment)
+        });
+    }
+}
@@ -0,0 +1,175 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env, Vec};
+
+use super::*;
+
+// Review outcome published by an auditor or regulator against a batch
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ComplianceStatus {
+    Unreviewed,
+    Cleared,
+    Flagged,
+    UnderInvestigation,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ComplianceRecord {
+    pub status: ComplianceStatus,
+    pub reason_hash: BytesN<32>,  // hash of the published finding
+    pub set_by: Address,
+    pub set_at: u64,
+}
+
+#[contracttype]
+enum ComplianceKey {
+    Compliance(BytesN<32>),
+    FlaggedBatches,  // batches currently Flagged or UnderInvestigation
+}
+
+fn is_open_finding(status: ComplianceStatus) -> bool {
+    matches!(status, ComplianceStatus::Flagged | ComplianceStatus::UnderInvestigation)
+}
+
+fn flagged_batches(env: &Env) -> Vec<BytesN<32>> {
+    storage::get(env, DataClass::Indexes, &ComplianceKey::FlaggedBatches)
+        .unwrap_or(Vec::new(env))
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Publish a compliance finding on a batch (Auditor or Regulator; the owner
+    /// deliberately can't review its own uploads)
+    pub fn set_compliance_status(
+        env: Env,
+        caller: Address,
+        batch_hash: BytesN<32>,
+        status: ComplianceStatus,
+        reason_hash: BytesN<32>,
+    ) {
+        caller.require_auth();
+
+        let roles = roles::roles_of(&env, &caller);
+        if !roles.contains(Role::Auditor) && !roles.contains(Role::Regulator) {
+            panic!("Missing role");
+        }
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        if !Self::is_batch_recorded(env.clone(), batch_hash.clone()) {
+            panic!("Batch not found");
+        }
+        if is_open_finding(status) && reason_hash == BytesN::from_array(&env, &[0u8; 32]) {
+            panic!("Reason required");
+        }
+
+        let previous = Self::get_compliance_status(env.clone(), batch_hash.clone());
+        let record = ComplianceRecord {
+            status,
+            reason_hash: reason_hash.clone(),
+            set_by: caller.clone(),
+            set_at: env.ledger().timestamp(),
+        };
+        let key = ComplianceKey::Compliance(batch_hash.clone());
+        storage::set(&env, DataClass::Records, &key, &record);
+
+        if is_open_finding(previous) != is_open_finding(status) {
+            let mut flagged = flagged_batches(&env);
+            if is_open_finding(status) {
+                flagged.push_back(batch_hash.clone());
+            } else if let Some(index) = flagged.first_index_of(&batch_hash) {
+                flagged.remove(index);
+            }
+            storage::set(&env, DataClass::Indexes, &ComplianceKey::FlaggedBatches, &flagged);
+        }
+
+        audit::log(&env, symbol_short!("batch_cmp"), &caller, &batch_hash);
+
+        env.events().publish(
+            (symbol_short!("batch_cmp"), batch_hash),
+            (caller, status, reason_hash, record.set_at)
+        );
+    }
+
+    /// Get a batch's compliance status; Unreviewed until a finding is published
+    pub fn get_compliance_status(env: Env, batch_hash: BytesN<32>) -> ComplianceStatus {
+        Self::get_compliance_record(env, batch_hash)
+            .map(|record| record.status)
+            .unwrap_or(ComplianceStatus::Unreviewed)
+    }
+
+    /// Get the latest compliance finding on a batch
+    pub fn get_compliance_record(env: Env, batch_hash: BytesN<32>) -> Option<ComplianceRecord> {
+        storage::get(&env, DataClass::Records, &ComplianceKey::Compliance(batch_hash))
+    }
+
+    /// Get a page of batches currently Flagged or UnderInvestigation, in flag order
+    pub fn get_flagged_batches(env: Env, start: u32, limit: u32) -> Vec<BytesN<32>> {
+        paginate(&env, &flagged_batches(&env), start, limit)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_compliance_status() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let regulator = Address::generate(&env);
+        client.initialize(&owner);
+        client.grant_role(&regulator, &Role::Regulator);
+
+        let july = BytesN::from_array(&env, &[1u8; 32]);
+        let august = BytesN::from_array(&env, &[2u8; 32]);
+        let finding = BytesN::from_array(&env, &[9u8; 32]);
+        client.record_payroll_batch(&owner, &july, &10);
+        client.record_payroll_batch(&owner, &august, &10);
+        assert_eq!(client.get_compliance_status(&july), ComplianceStatus::Unreviewed);
+
+        client.set_compliance_status(&regulator, &july, &ComplianceStatus::Flagged, &finding);
+        client.set_compliance_status(&regulator, &august, &ComplianceStatus::UnderInvestigation, &finding);
+        client.set_compliance_status(&regulator, &july, &ComplianceStatus::UnderInvestigation, &finding);
+        assert_eq!(client.get_flagged_batches(&0, &10), Vec::from_array(&env, [july.clone(), august.clone()]));
+
+        client.set_compliance_status(&regulator, &july, &ComplianceStatus::Cleared, &BytesN::from_array(&env, &[0u8; 32]));
+        assert_eq!(client.get_compliance_status(&july), ComplianceStatus::Cleared);
+        assert_eq!(client.get_compliance_record(&july).unwrap().set_by, regulator);
+        assert_eq!(client.get_flagged_batches(&0, &10), Vec::from_array(&env, [august]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Missing role")]
+    fn test_owner_cannot_clear_own_batch() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let batch_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &10);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::set_compliance_status(
+                env.clone(),
+                owner,
+                batch_hash,
+                ComplianceStatus::Cleared,
+                BytesN::from_array(&env, &[0u8; 32]),
+            )
+        });
+    }
+}
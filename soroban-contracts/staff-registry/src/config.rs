@@ -0,0 +1,153 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env};
+
+use super::*;
+
+// Rolling window that max_batches_per_period counts against (~30 days)
+pub const CONFIG_PERIOD_LEDGERS: u32 = 30 * ttl::DAY_IN_LEDGERS;
+
+// Operational limits on batch uploads; 0 disables a limit
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    pub max_staff_per_batch: u32,
+    pub max_batches_per_period: u32,      // per CONFIG_PERIOD_LEDGERS window
+    pub min_batch_interval_ledgers: u32,  // ledgers between consecutive batches
+}
+
+#[contracttype]
+enum ConfigKey {
+    Config,
+    PeriodUsage,      // (window index, batches recorded in it)
+    LastBatchLedger,  // ledger of the most recent batch
+}
+
+pub(crate) fn config(env: &Env) -> Config {
+    storage::get(env, DataClass::Config, &ConfigKey::Config)
+        .unwrap_or(Config { max_staff_per_batch: 0, max_batches_per_period: 0, min_batch_interval_ledgers: 0 })
+}
+
+// Check a new batch against the configured limits and count it
+pub(crate) fn enforce_batch_limits(env: &Env, staff_count: u32) {
+    let config = config(env);
+    let now = env.ledger().sequence();
+
+    if config.max_staff_per_batch > 0 && staff_count > config.max_staff_per_batch {
+        panic!("Too many staff in batch");
+    }
+
+    let last: Option<u32> = storage::get(env, DataClass::Config, &ConfigKey::LastBatchLedger);
+    if let Some(last) = last {
+        if now < last.saturating_add(config.min_batch_interval_ledgers) {
+            panic!("Batch interval too short");
+        }
+    }
+
+    let window = now / CONFIG_PERIOD_LEDGERS;
+    let (usage_window, used): (u32, u32) = storage::get(env, DataClass::Config, &ConfigKey::PeriodUsage)
+        .unwrap_or((window, 0));
+    let used = if usage_window == window { used } else { 0 };
+    if config.max_batches_per_period > 0 && used >= config.max_batches_per_period {
+        panic!("Batch limit reached for period");
+    }
+
+    storage::set(env, DataClass::Config, &ConfigKey::PeriodUsage, &(window, used + 1));
+    storage::set(env, DataClass::Config, &ConfigKey::LastBatchLedger, &now);
+}
+
+pub(crate) fn apply_config(env: &Env, owner: &Address, config: Config) {
+    storage::set(env, DataClass::Config, &ConfigKey::Config, &config);
+    ttl::extend_instance(env);
+
+    audit::log_action(env, symbol_short!("config"), owner);
+
+    env.events().publish(
+        (symbol_short!("config"),),
+        (config.max_staff_per_batch, config.max_batches_per_period, config.min_batch_interval_ledgers, env.ledger().timestamp())
+    );
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Set the operational limits on batch uploads (owner only)
+    pub fn set_config(env: Env, config: Config) {
+        let owner = auth::require_owner(&env);
+        timelock::require_not_timelocked(&env);
+
+        apply_config(&env, &owner, config);
+    }
+
+    /// Get the operational limits on batch uploads
+    pub fn get_config(env: Env) -> Config {
+        config(&env)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Ledger}, Address, BytesN, Env};
+
+    #[test]
+    fn test_batch_limits() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let config = Config { max_staff_per_batch: 50, max_batches_per_period: 2, min_batch_interval_ledgers: 10 };
+        client.set_config(&config);
+        assert_eq!(client.get_config(), config);
+
+        client.record_payroll_batch(&owner, &BytesN::from_array(&env, &[1u8; 32]), &50);
+        env.ledger().with_mut(|li| li.sequence_number += 10);
+        client.record_payroll_batch(&owner, &BytesN::from_array(&env, &[2u8; 32]), &1);
+
+        // The period allowance resets in the next window
+        let far = CONFIG_PERIOD_LEDGERS;
+        env.as_contract(&contract_id, || env.storage().instance().extend_ttl(far, far));
+        env.ledger().with_mut(|li| li.sequence_number = (li.sequence_number / CONFIG_PERIOD_LEDGERS + 1) * CONFIG_PERIOD_LEDGERS);
+        client.record_payroll_batch(&owner, &BytesN::from_array(&env, &[3u8; 32]), &1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Batch interval too short")]
+    fn test_batch_interval() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_config(&Config { max_staff_per_batch: 0, max_batches_per_period: 0, min_batch_interval_ledgers: 10 });
+        client.record_payroll_batch(&owner, &BytesN::from_array(&env, &[1u8; 32]), &1);
+
+        env.ledger().with_mut(|li| li.sequence_number += 9);
+        env.as_contract(&contract_id, || {
+            StaffRegistry::record_payroll_batch(env.clone(), owner, BytesN::from_array(&env, &[2u8; 32]), 1)
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Too many staff in batch")]
+    fn test_oversized_batch() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_config(&Config { max_staff_per_batch: 5, max_batches_per_period: 0, min_batch_interval_ledgers: 0 });
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::record_payroll_batch(env.clone(), owner, BytesN::from_array(&env, &[1u8; 32]), 6)
+        });
+    }
+}
@@ -0,0 +1,147 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env};
+
+use super::*;
+
+// Ledgers after an upload during which it can be replaced outright (~1 hour)
+pub const DEFAULT_CORRECTION_GRACE_LEDGERS: u32 = 720;
+
+#[contracttype]
+enum CorrectionKey {
+    CorrectionGrace,             // grace window length in ledgers
+    CorrectedBatch(BytesN<32>),  // set on batches recorded as a grace-window replacement
+}
+
+// Whether a batch was invalidated by its own uploader's grace-window replacement
+pub(crate) fn is_tombstoned(batch: &PayrollBatch) -> bool {
+    !batch.is_valid && batch.invalidation_reason == symbol_short!("replaced")
+}
+
+fn correction_grace(env: &Env) -> u32 {
+    storage::get(env, DataClass::Config, &CorrectionKey::CorrectionGrace)
+        .unwrap_or(DEFAULT_CORRECTION_GRACE_LEDGERS)
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Set how many ledgers after upload a batch may be replaced without the supersede workflow (owner only)
+    pub fn set_correction_grace(env: Env, ledgers: u32) {
+        let owner = auth::require_owner(&env);
+
+        storage::set(&env, DataClass::Config, &CorrectionKey::CorrectionGrace, &ledgers);
+        ttl::extend_instance(&env);
+
+        audit::log_action(&env, symbol_short!("grace"), &owner);
+
+        env.events().publish(
+            (symbol_short!("grace"),),
+            (ledgers, env.ledger().timestamp())
+        );
+    }
+
+    /// Get the correction grace window in ledgers
+    pub fn get_correction_grace(env: Env) -> u32 {
+        correction_grace(&env)
+    }
+
+    /// Replace a just-uploaded batch: the old batch is tombstoned (invalidated and linked
+    /// to its replacement) and the new one is recorded with the corrected flag set
+    pub fn replace_batch_within_grace(env: Env, caller: Address, old_hash: BytesN<32>, new_hash: BytesN<32>, staff_count: u32) {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        let zero_hash = BytesN::from_array(&env, &[0u8; 32]);
+        let mut old_batch = Self::get_payroll_batch(env.clone(), old_hash.clone());
+        if !old_batch.is_valid || old_batch.superseded_by != zero_hash {
+            panic!("Batch already replaced");
+        }
+
+        let recorded_at = challenge::recorded_ledger(&env, &old_hash);
+        if recorded_at == 0 || env.ledger().sequence() > recorded_at.saturating_add(correction_grace(&env)) {
+            panic!("Grace period over");
+        }
+
+        let mut batch = new_batch(&env, caller.clone(), new_hash.clone(), staff_count);
+        batch.supersedes = old_hash.clone();
+        batch.correction_reason = symbol_short!("grace");
+        store_new_batch(&env, &batch);
+        periods::inherit_period(&env, &old_hash, &new_hash);
+
+        let corrected_key = CorrectionKey::CorrectedBatch(new_hash.clone());
+        storage::set(&env, DataClass::Records, &corrected_key, &true);
+
+        old_batch.superseded_by = new_hash.clone();
+        old_batch.is_valid = false;
+        old_batch.invalidation_reason = symbol_short!("replaced");
+        history::save_payroll_batch(&env, &Scope::Main, &old_batch);
+
+        audit::log(&env, symbol_short!("batch_fix"), &caller, &old_hash);
+
+        events::batch_event(&env, symbol_short!("batch_fix"), &caller, &old_batch);
+    }
+
+    /// Check whether a batch was recorded as a grace-window replacement
+    pub fn is_batch_corrected(env: Env, batch_hash: BytesN<32>) -> bool {
+        storage::get(&env, DataClass::Records, &CorrectionKey::CorrectedBatch(batch_hash))
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Ledger}, Env};
+
+    #[test]
+    fn test_replace_within_grace() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.sequence_number = 100);
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let original = BytesN::from_array(&env, &[1u8; 32]);
+        let fixed = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&owner, &original, &10);
+
+        env.ledger().with_mut(|li| li.sequence_number += DEFAULT_CORRECTION_GRACE_LEDGERS);
+        client.replace_batch_within_grace(&owner, &original, &fixed, &11);
+
+        let old = client.get_payroll_batch(&original);
+        assert!(!old.is_valid);
+        assert_eq!(old.superseded_by, fixed);
+        assert_eq!(client.get_payroll_batch(&fixed).supersedes, original);
+        assert!(client.is_batch_corrected(&fixed));
+        assert!(!client.is_batch_corrected(&original));
+        assert_eq!(client.get_batch_status(&original), BatchStatus::Invalidated);
+    }
+
+    #[test]
+    #[should_panic(expected = "Grace period over")]
+    fn test_replace_after_grace() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.sequence_number = 100);
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let original = BytesN::from_array(&env, &[1u8; 32]);
+        client.record_payroll_batch(&owner, &original, &10);
+        env.ledger().with_mut(|li| li.sequence_number += DEFAULT_CORRECTION_GRACE_LEDGERS + 1);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::replace_batch_within_grace(env.clone(), owner, original, BytesN::from_array(&env, &[2u8; 32]), 10)
+        });
+    }
+}
@@ -0,0 +1,166 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env, Map, Symbol};
+
+use super::*;
+
+// Domain tag for deduction commitments, distinct from batch totals
+const DEDUCTION_COMMITMENT_TAG: &[u8] = b"payroll-deduction-v1";
+
+// Most deduction categories (pension, tax, union, ...) one batch can carry
+pub const MAX_DEDUCTION_CATEGORIES: u32 = 10;
+
+#[contracttype]
+enum DeductionKey {
+    DeductionCommitments(BytesN<32>),  // category -> commitment per batch
+    RevealedDeductions(BytesN<32>),    // category -> published total per batch
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Commit to a batch's deduction totals per category (owner or PayrollUploader); set once
+    pub fn set_deduction_commitments(env: Env, caller: Address, batch_hash: BytesN<32>, commitments: Map<Symbol, BytesN<32>>) {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        if !Self::is_batch_recorded(env.clone(), batch_hash.clone()) {
+            panic!("Batch not found");
+        }
+        if commitments.is_empty() || commitments.len() > MAX_DEDUCTION_CATEGORIES {
+            panic!("Invalid deduction categories");
+        }
+        let key = DeductionKey::DeductionCommitments(batch_hash.clone());
+        if storage::has(&env, DataClass::Records, &key) {
+            panic!("Deductions already set");
+        }
+
+        storage::set(&env, DataClass::Records, &key, &commitments);
+
+        audit::log(&env, symbol_short!("deduct"), &caller, &batch_hash);
+
+        env.events().publish(
+            (symbol_short!("deduct"), batch_hash),
+            (commitments.keys(), env.ledger().timestamp())
+        );
+    }
+
+    /// Compute the commitment for a deduction total, so uploaders can derive it off-chain identically
+    pub fn compute_deduction_commitment(env: Env, opening: TotalOpening) -> BytesN<32> {
+        commitments::amount_commitment(&env, DEDUCTION_COMMITMENT_TAG, &opening)
+    }
+
+    /// Check an opening against a batch's committed deduction total for a category
+    pub fn verify_deduction(env: Env, batch_hash: BytesN<32>, category: Symbol, opening: TotalOpening) -> bool {
+        let commitment = Self::get_deduction_commitments(env.clone(), batch_hash)
+            .get(category)
+            .unwrap_or_else(|| panic!("No deduction commitment"));
+        commitments::amount_commitment(&env, DEDUCTION_COMMITMENT_TAG, &opening) == commitment
+    }
+
+    /// Publish a batch's deduction total for a category; anyone holding the opening may call
+    pub fn reveal_deduction(env: Env, batch_hash: BytesN<32>, category: Symbol, opening: TotalOpening) {
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        let mut revealed = Self::get_revealed_deductions(env.clone(), batch_hash.clone());
+        if revealed.contains_key(category.clone()) {
+            panic!("Deduction already revealed");
+        }
+        if opening.amount < 0 {
+            panic!("Invalid amount");
+        }
+        if !Self::verify_deduction(env.clone(), batch_hash.clone(), category.clone(), opening.clone()) {
+            panic!("Commitment mismatch");
+        }
+
+        revealed.set(category.clone(), opening.amount);
+        let key = DeductionKey::RevealedDeductions(batch_hash.clone());
+        storage::set(&env, DataClass::Records, &key, &revealed);
+
+        env.events().publish(
+            (symbol_short!("deduct_rv"), batch_hash, category),
+            (opening.amount, env.ledger().timestamp())
+        );
+    }
+
+    /// Get a batch's deduction commitments by category
+    pub fn get_deduction_commitments(env: Env, batch_hash: BytesN<32>) -> Map<Symbol, BytesN<32>> {
+        storage::get(&env, DataClass::Records, &DeductionKey::DeductionCommitments(batch_hash))
+            .unwrap_or(Map::new(&env))
+    }
+
+    /// Get the deduction totals published so far for a batch, by category
+    pub fn get_revealed_deductions(env: Env, batch_hash: BytesN<32>) -> Map<Symbol, i128> {
+        storage::get(&env, DataClass::Records, &DeductionKey::RevealedDeductions(batch_hash))
+            .unwrap_or(Map::new(&env))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_deduction_commit_and_reveal() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &10);
+
+        let pension = symbol_short!("pension");
+        let tax = symbol_short!("tax");
+        let pension_opening = TotalOpening { amount: 80_000, blinding: BytesN::from_array(&env, &[1u8; 32]) };
+        let tax_opening = TotalOpening { amount: 150_000, blinding: BytesN::from_array(&env, &[2u8; 32]) };
+
+        let mut commitments = Map::new(&env);
+        commitments.set(pension.clone(), client.compute_deduction_commitment(&pension_opening));
+        commitments.set(tax.clone(), client.compute_deduction_commitment(&tax_opening));
+        client.set_deduction_commitments(&owner, &batch_hash, &commitments);
+
+        // A batch-total commitment over the same opening does not open a deduction
+        assert_ne!(client.compute_total_commitment(&tax_opening), client.compute_deduction_commitment(&tax_opening));
+        assert!(client.verify_deduction(&batch_hash, &tax, &tax_opening));
+        assert!(!client.verify_deduction(&batch_hash, &pension, &tax_opening));
+
+        client.reveal_deduction(&batch_hash, &pension, &pension_opening);
+        let revealed = client.get_revealed_deductions(&batch_hash);
+        assert_eq!(revealed.get(pension), Some(80_000));
+        assert_eq!(revealed.get(tax), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Commitment mismatch")]
+    fn test_reveal_wrong_opening() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &10);
+
+        let union = symbol_short!("union");
+        let opening = TotalOpening { amount: 5_000, blinding: BytesN::from_array(&env, &[1u8; 32]) };
+        let mut commitments = Map::new(&env);
+        commitments.set(union.clone(), client.compute_deduction_commitment(&opening));
+        client.set_deduction_commitments(&owner, &batch_hash, &commitments);
+
+        let forged = TotalOpening { amount: 1, ..opening };
+        env.as_contract(&contract_id, || StaffRegistry::reveal_deduction(env.clone(), batch_hash, union, forged));
+    }
+}
@@ -0,0 +1,161 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env, Vec};
+
+use super::*;
+
+// Maximum number of staff hashes (added plus removed) in one delta
+pub const MAX_DELTA_ENTRIES: u32 = 200;
+
+// Headcount churn between a batch and the batch before it in the chain
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchDelta {
+    pub batch_hash: BytesN<32>,
+    pub prev_batch_hash: BytesN<32>,
+    pub added: Vec<BytesN<32>>,
+    pub removed: Vec<BytesN<32>>,
+    pub recorded_by: Address,
+    pub recorded_at: u64,
+}
+
+#[contracttype]
+enum DeltaKey {
+    BatchDelta(BytesN<32>),
+}
+
+fn require_distinct_staff(env: &Env, hashes: &Vec<BytesN<32>>, other: &Vec<BytesN<32>>) {
+    for i in 0..hashes.len() {
+        let staff_hash = hashes.get_unchecked(i);
+        if hashes.first_index_of(&staff_hash) != Some(i) || other.contains(&staff_hash) {
+            panic!("Duplicate staff hash");
+        }
+        if !StaffRegistry::is_staff_registered(env.clone(), staff_hash) {
+            panic!("Staff not found");
+        }
+    }
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Explain how a batch's headcount changed from the previous batch; the staff
+    /// counts must agree with the delta (owner or PayrollUploader)
+    pub fn record_batch_delta(
+        env: Env,
+        caller: Address,
+        new_batch_hash: BytesN<32>,
+        added: Vec<BytesN<32>>,
+        removed: Vec<BytesN<32>>,
+    ) {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        let key = DeltaKey::BatchDelta(new_batch_hash.clone());
+        if storage::has(&env, DataClass::Records, &key) {
+            panic!("Delta already recorded");
+        }
+        if added.len() + removed.len() > MAX_DELTA_ENTRIES {
+            panic!("Too many delta entries");
+        }
+        require_distinct_staff(&env, &added, &removed);
+        require_distinct_staff(&env, &removed, &added);
+
+        let batch = Self::get_payroll_batch(env.clone(), new_batch_hash.clone());
+        if batch.prev_batch_hash == BytesN::from_array(&env, &[0u8; 32]) {
+            panic!("No previous batch");
+        }
+        let prev = Self::get_payroll_batch(env.clone(), batch.prev_batch_hash.clone());
+        let expected = (prev.staff_count + added.len()).checked_sub(removed.len());
+        if expected != Some(batch.staff_count) {
+            panic!("Delta does not match headcount");
+        }
+
+        let delta = BatchDelta {
+            batch_hash: new_batch_hash.clone(),
+            prev_batch_hash: batch.prev_batch_hash,
+            added,
+            removed,
+            recorded_by: caller.clone(),
+            recorded_at: env.ledger().timestamp(),
+        };
+        storage::set(&env, DataClass::Records, &key, &delta);
+
+        audit::log(&env, symbol_short!("batch_dlt"), &caller, &new_batch_hash);
+
+        env.events().publish(
+            (symbol_short!("batch_dlt"), new_batch_hash),
+            (caller, delta.added.len(), delta.removed.len(), delta.recorded_at)
+        );
+    }
+
+    /// Get the headcount delta recorded for a batch
+    pub fn get_delta(env: Env, batch_hash: BytesN<32>) -> Option<BatchDelta> {
+        storage::get(&env, DataClass::Records, &DeltaKey::BatchDelta(batch_hash))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_record_batch_delta() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let leaver = BytesN::from_array(&env, &[1u8; 32]);
+        let joiner_a = BytesN::from_array(&env, &[2u8; 32]);
+        let joiner_b = BytesN::from_array(&env, &[3u8; 32]);
+        let july = BytesN::from_array(&env, &[10u8; 32]);
+        let august = BytesN::from_array(&env, &[11u8; 32]);
+        client.register_staff(&owner, &leaver);
+        client.register_staff(&owner, &joiner_a);
+        client.register_staff(&owner, &joiner_b);
+        client.record_payroll_batch(&owner, &july, &10);
+        client.record_payroll_batch(&owner, &august, &11);
+
+        let added = Vec::from_array(&env, [joiner_a, joiner_b]);
+        let removed = Vec::from_array(&env, [leaver]);
+        client.record_batch_delta(&owner, &august, &added, &removed);
+
+        let delta = client.get_delta(&august).unwrap();
+        assert_eq!(delta.prev_batch_hash, july);
+        assert_eq!(delta.added, added);
+        assert_eq!(delta.removed, removed);
+        assert_eq!(client.get_delta(&july), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Delta does not match headcount")]
+    fn test_delta_must_match_headcount() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let joiner = BytesN::from_array(&env, &[2u8; 32]);
+        let august = BytesN::from_array(&env, &[11u8; 32]);
+        client.register_staff(&owner, &joiner);
+        client.record_payroll_batch(&owner, &BytesN::from_array(&env, &[10u8; 32]), &10);
+        client.record_payroll_batch(&owner, &august, &12);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::record_batch_delta(env.clone(), owner, august, Vec::from_array(&env, [joiner]), Vec::new(&env))
+        });
+    }
+}
@@ -0,0 +1,175 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env, String, Symbol, Vec};
+
+use super::*;
+
+// Organizational unit staff can be assigned to
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Department {
+    pub id: Symbol,
+    pub name: String,
+    pub head: Address,
+}
+
+// Take a staff member out of their department, if any
+pub(crate) fn unassign(env: &Env, staff_hash: &BytesN<32>) {
+    let key = DataKey::StaffDepartment(staff_hash.clone());
+    if let Some(dept_id) = storage::get::<DataKey, Symbol>(env, DataClass::Records, &key) {
+        lists::keyed_remove_hash(env, DataClass::Indexes, &DataKey::DepartmentStaff(dept_id), staff_hash);
+        storage::remove(env, DataClass::Records, &key);
+    }
+}
+
+// Carry a rotated staff hash's department over to its replacement
+pub(crate) fn rekey(env: &Env, old_hash: &BytesN<32>, new_hash: &BytesN<32>) {
+    let key = DataKey::StaffDepartment(old_hash.clone());
+    if let Some(dept_id) = storage::get::<DataKey, Symbol>(env, DataClass::Records, &key) {
+        lists::keyed_replace_hash(env, DataClass::Indexes, &DataKey::DepartmentStaff(dept_id), old_hash, new_hash);
+        rotation::move_entry(env, &key, &DataKey::StaffDepartment(new_hash.clone()));
+    }
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Create a department (owner only)
+    pub fn create_department(env: Env, id: Symbol, name: String, head: Address) {
+        let owner = auth::require_owner(&env);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        let key = DataKey::Department(id.clone());
+        if storage::has(&env, DataClass::Records, &key) {
+            panic!("Department already exists");
+        }
+
+        let department = Department { id: id.clone(), name, head };
+        storage::set(&env, DataClass::Records, &key, &department);
+
+        audit::log_action(&env, symbol_short!("dept_new"), &owner);
+
+        env.events().publish(
+            (symbol_short!("dept_new"), id),
+            (department.head, env.ledger().timestamp())
+        );
+    }
+
+    /// Get a department by id
+    pub fn get_department(env: Env, id: Symbol) -> Department {
+        storage::get(&env, DataClass::Records, &DataKey::Department(id))
+            .unwrap_or_else(|| panic!("Department not found"))
+    }
+
+    /// Assign (or move) a staff member to a department
+    pub fn assign_staff_department(env: Env, caller: Address, staff_hash: BytesN<32>, dept_id: Symbol) {
+        // onlyOwner or Registrar
+        auth::require_role(&env, &caller, Role::Registrar);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        rotation::require_current(&env, &staff_hash);
+        erasure::require_not_erased(&env, &staff_hash);
+
+        if !Self::is_staff_registered(env.clone(), staff_hash.clone()) {
+            panic!("Staff not found");
+        }
+        if !storage::has(&env, DataClass::Records, &DataKey::Department(dept_id.clone())) {
+            panic!("Department not found");
+        }
+
+        // Remove from the previous department's index, if any
+        let staff_dept_key = DataKey::StaffDepartment(staff_hash.clone());
+        if let Some(previous) = storage::get::<DataKey, Symbol>(&env, DataClass::Records, &staff_dept_key) {
+            if previous == dept_id {
+                panic!("Staff already in department");
+            }
+            lists::keyed_remove_hash(&env, DataClass::Indexes, &DataKey::DepartmentStaff(previous), &staff_hash);
+        }
+
+        lists::keyed_push_hash(&env, DataClass::Indexes, &DataKey::DepartmentStaff(dept_id.clone()), &staff_hash);
+
+        storage::set(&env, DataClass::Records, &staff_dept_key, &dept_id);
+
+        audit::log(&env, symbol_short!("dept_asgn"), &caller, &staff_hash);
+
+        env.events().publish(
+            (symbol_short!("dept_asgn"), staff_hash),
+            (dept_id, caller, env.ledger().timestamp())
+        );
+    }
+
+    /// Get the department a staff member is assigned to, if any
+    pub fn get_staff_department(env: Env, staff_hash: BytesN<32>) -> Option<Symbol> {
+        storage::get(&env, DataClass::Records, &DataKey::StaffDepartment(staff_hash))
+    }
+
+    /// Get a page of staff hashes assigned to a department
+    pub fn get_staff_by_department(env: Env, dept_id: Symbol, start: u32, limit: u32) -> Vec<BytesN<32>> {
+        lists::keyed_page(&env, DataClass::Indexes, &DataKey::DepartmentStaff(dept_id), start, limit)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_department_assignment() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let head = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let finance = symbol_short!("finance");
+        let health = symbol_short!("health");
+        client.create_department(&finance, &String::from_str(&env, "Ministry of Finance"), &head);
+        client.create_department(&health, &String::from_str(&env, "Ministry of Health"), &head);
+        assert_eq!(client.get_department(&finance).head, head);
+
+        let alice = BytesN::from_array(&env, &[1u8; 32]);
+        let bob = BytesN::from_array(&env, &[2u8; 32]);
+        client.register_staff(&owner, &alice);
+        client.register_staff(&owner, &bob);
+
+        client.assign_staff_department(&owner, &alice, &finance);
+        client.assign_staff_department(&owner, &bob, &finance);
+        assert_eq!(client.get_staff_by_department(&finance, &0, &10).len(), 2);
+
+        // Moving a staff member updates both indexes
+        client.assign_staff_department(&owner, &alice, &health);
+        assert_eq!(client.get_staff_department(&alice), Some(health.clone()));
+        assert_eq!(client.get_staff_by_department(&finance, &0, &10), Vec::from_array(&env, [bob]));
+        assert_eq!(client.get_staff_by_department(&health, &0, &10), Vec::from_array(&env, [alice]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Department not found")]
+    fn test_assign_to_unknown_department() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::assign_staff_department(env.clone(), owner, staff_hash, symbol_short!("nowhere"))
+        });
+    }
+}
@@ -0,0 +1,241 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env, Vec};
+
+use super::*;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DisputeStatus {
+    Open,
+    Acknowledged,
+    Resolved,
+    Rejected,
+}
+
+// Employee challenge against a published batch
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dispute {
+    pub id: u64,
+    pub batch_hash: BytesN<32>,
+    pub staff_hash: BytesN<32>,
+    pub filed_by: Address,
+    pub reason_hash: BytesN<32>,     // hash of the off-chain complaint
+    pub status: DisputeStatus,
+    pub opened_at: u64,
+    pub updated_at: u64,
+    pub resolution_hash: BytesN<32>, // hash of the outcome note, zero while unresolved
+}
+
+// Allowed state machine moves; Resolved and Rejected are terminal
+fn can_transition(from: DisputeStatus, to: DisputeStatus) -> bool {
+    matches!(
+        (from, to),
+        (DisputeStatus::Open, DisputeStatus::Acknowledged)
+            | (DisputeStatus::Open, DisputeStatus::Resolved)
+            | (DisputeStatus::Open, DisputeStatus::Rejected)
+            | (DisputeStatus::Acknowledged, DisputeStatus::Resolved)
+            | (DisputeStatus::Acknowledged, DisputeStatus::Rejected)
+    )
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// File a dispute against a batch the staff member was paid in (bound staff address only);
+    /// returns the dispute id
+    pub fn open_dispute(env: Env, filer: Address, batch_hash: BytesN<32>, staff_hash: BytesN<32>, reason_hash: BytesN<32>) -> u64 {
+        filer.require_auth();
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        if !Self::is_batch_recorded(env.clone(), batch_hash.clone()) {
+            panic!("Batch not found");
+        }
+        if !Self::is_staff_registered(env.clone(), staff_hash.clone()) {
+            panic!("Staff not found");
+        }
+
+        // Only the employee's own bound wallet may dispute, and only a batch that pays them
+        if acknowledgements::bound_address(&env, &staff_hash) != Some(filer.clone()) {
+            panic!("Staff address not bound");
+        }
+        if !Self::is_staff_in_batch(env.clone(), batch_hash.clone(), staff_hash.clone()) {
+            panic!("Staff not in batch");
+        }
+
+        challenge::dispute_opened(&env, &batch_hash);
+
+        let id: u64 = storage::get(&env, DataClass::Config, &DataKey::DisputeCount).unwrap_or(0) + 1;
+        storage::set(&env, DataClass::Config, &DataKey::DisputeCount, &id);
+
+        let dispute = Dispute {
+            id,
+            batch_hash: batch_hash.clone(),
+            staff_hash: staff_hash.clone(),
+            filed_by: filer.clone(),
+            reason_hash,
+            status: DisputeStatus::Open,
+            opened_at: env.ledger().timestamp(),
+            updated_at: env.ledger().timestamp(),
+            resolution_hash: BytesN::from_array(&env, &[0u8; 32]),
+        };
+        storage::set(&env, DataClass::Records, &DataKey::Dispute(id), &dispute);
+
+        lists::keyed_push(&env, DataClass::Indexes, &DataKey::BatchDisputes(batch_hash.clone()), &id);
+
+        audit::log(&env, symbol_short!("disp_open"), &filer, &batch_hash);
+
+        env.events().publish(
+            (symbol_short!("disp_open"), batch_hash, staff_hash),
+            (id, filer, env.ledger().timestamp())
+        );
+
+        id
+    }
+
+    /// Move a dispute through its lifecycle (owner or Auditor)
+    pub fn set_dispute_status(env: Env, caller: Address, dispute_id: u64, status: DisputeStatus, resolution_hash: BytesN<32>) {
+        // onlyOwner or Auditor
+        auth::require_role(&env, &caller, Role::Auditor);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        let mut dispute = Self::get_dispute(env.clone(), dispute_id);
+        if !can_transition(dispute.status, status) {
+            panic!("Invalid dispute transition");
+        }
+
+        if matches!(status, DisputeStatus::Resolved | DisputeStatus::Rejected) {
+            challenge::dispute_closed(&env, &dispute.batch_hash);
+        }
+
+        dispute.status = status;
+        dispute.updated_at = env.ledger().timestamp();
+        dispute.resolution_hash = resolution_hash;
+        storage::set(&env, DataClass::Records, &DataKey::Dispute(dispute_id), &dispute);
+
+        audit::log(&env, symbol_short!("disp_upd"), &caller, &dispute.batch_hash);
+
+        env.events().publish(
+            (symbol_short!("disp_upd"), dispute.batch_hash),
+            (dispute_id, status, caller, env.ledger().timestamp())
+        );
+    }
+
+    /// Get a dispute by id
+    pub fn get_dispute(env: Env, dispute_id: u64) -> Dispute {
+        storage::get(&env, DataClass::Records, &DataKey::Dispute(dispute_id))
+            .unwrap_or_else(|| panic!("Dispute not found"))
+    }
+
+    /// Get a page of disputes filed against a batch, oldest first
+    pub fn get_disputes(env: Env, batch_hash: BytesN<32>, start: u32, limit: u32) -> Vec<Dispute> {
+        let ids: Vec<u64> = lists::keyed_page(&env, DataClass::Indexes, &DataKey::BatchDisputes(batch_hash), start, limit);
+
+        let mut disputes = Vec::new(&env);
+        for id in ids.iter() {
+            disputes.push_back(Self::get_dispute(env.clone(), id));
+        }
+
+        disputes
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_dispute_lifecycle() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let auditor = Address::generate(&env);
+        let employee = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+        client.grant_role(&auditor, &Role::Auditor);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        client.bind_staff_address(&owner, &staff_hash, &employee);
+        client.record_batch_with_members(&owner, &batch_hash, &Vec::from_array(&env, [staff_hash.clone()]));
+
+        let reason = BytesN::from_array(&env, &[3u8; 32]);
+        let id = client.open_dispute(&employee, &batch_hash, &staff_hash, &reason);
+        assert_eq!(id, 1);
+        assert_eq!(client.get_dispute(&id).status, DisputeStatus::Open);
+
+        let zero = BytesN::from_array(&env, &[0u8; 32]);
+        let outcome = BytesN::from_array(&env, &[4u8; 32]);
+        client.set_dispute_status(&auditor, &id, &DisputeStatus::Acknowledged, &zero);
+        client.set_dispute_status(&auditor, &id, &DisputeStatus::Resolved, &outcome);
+
+        let disputes = client.get_disputes(&batch_hash, &0, &10);
+        assert_eq!(disputes.len(), 1);
+        let dispute = disputes.get(0).unwrap();
+        assert_eq!(dispute.status, DisputeStatus::Resolved);
+        assert_eq!(dispute.resolution_hash, outcome);
+        assert_eq!(dispute.filed_by, employee);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid dispute transition")]
+    fn test_resolved_dispute_is_terminal() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let employee = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        let zero = BytesN::from_array(&env, &[0u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        client.bind_staff_address(&owner, &staff_hash, &employee);
+        client.record_batch_with_members(&owner, &batch_hash, &Vec::from_array(&env, [staff_hash.clone()]));
+        let id = client.open_dispute(&employee, &batch_hash, &staff_hash, &BytesN::from_array(&env, &[3u8; 32]));
+        client.set_dispute_status(&owner, &id, &DisputeStatus::Rejected, &zero);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::set_dispute_status(env.clone(), owner, id, DisputeStatus::Open, zero)
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Staff address not bound")]
+    fn test_dispute_from_unbound_filer() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        client.record_batch_with_members(&owner, &batch_hash, &Vec::from_array(&env, [staff_hash.clone()]));
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::open_dispute(env.clone(), Address::generate(&env), batch_hash, staff_hash, BytesN::from_array(&env, &[3u8; 32]))
+        });
+    }
+}
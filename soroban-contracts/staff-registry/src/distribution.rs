@@ -0,0 +1,184 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env, Vec};
+
+use super::*;
+
+// Maximum number of salary bands in a published distribution
+pub const MAX_DISTRIBUTION_BUCKETS: u32 = 20;
+
+// Non-empty bands smaller than this could point at an individual's pay
+pub const MIN_BUCKET_HEADCOUNT: u32 = 3;
+
+// Headcount per salary band for a pay period; carries no per-staff data
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SalaryDistribution {
+    pub period_id: u32,
+    pub buckets: Vec<(i128, i128, u32)>,  // (band min, band max, headcount), ascending
+    pub total_headcount: u32,
+    pub published_by: Address,
+    pub published_at: u64,
+}
+
+#[contracttype]
+enum DistributionKey {
+    Distribution(u32),
+    DistributionAttestations(u32),
+}
+
+fn validate_buckets(buckets: &Vec<(i128, i128, u32)>) -> u32 {
+    if buckets.is_empty() || buckets.len() > MAX_DISTRIBUTION_BUCKETS {
+        panic!("Invalid bucket count");
+    }
+
+    let mut total: u32 = 0;
+    let mut prev_max: Option<i128> = None;
+    for (min, max, headcount) in buckets.iter() {
+        if min < 0 || min >= max {
+            panic!("Invalid band");
+        }
+        // Bands are ascending and don't overlap, so each salary falls in at most one
+        if prev_max.is_some_and(|prev| min < prev) {
+            panic!("Bands overlap");
+        }
+        if headcount != 0 && headcount < MIN_BUCKET_HEADCOUNT {
+            panic!("Bucket too small");
+        }
+        total = total.checked_add(headcount).unwrap_or_else(|| panic!("Headcount overflow"));
+        prev_max = Some(max);
+    }
+    total
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Publish the salary distribution for a pay period (YYYYMM) as headcount per band (owner only)
+    pub fn publish_distribution(env: Env, period_id: u32, buckets: Vec<(i128, i128, u32)>) {
+        let owner = auth::require_owner(&env);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        periods::require_valid_period(period_id);
+        let key = DistributionKey::Distribution(period_id);
+        if storage::has(&env, DataClass::Records, &key) {
+            panic!("Distribution already published");
+        }
+        let total_headcount = validate_buckets(&buckets);
+
+        let distribution = SalaryDistribution {
+            period_id,
+            buckets,
+            total_headcount,
+            published_by: owner.clone(),
+            published_at: env.ledger().timestamp(),
+        };
+        storage::set(&env, DataClass::Records, &key, &distribution);
+
+        audit::log_action(&env, symbol_short!("dist_pub"), &owner);
+
+        env.events().publish(
+            (symbol_short!("dist_pub"), period_id),
+            (total_headcount, distribution.buckets.len(), env.ledger().timestamp())
+        );
+    }
+
+    /// Attest a published distribution as an auditor; it counts as attested once the
+    /// batch attestation threshold is met
+    pub fn attest_distribution(env: Env, auditor: Address, period_id: u32) {
+        auditor.require_auth();
+
+        // Owner publishes, so only independent auditors can attest
+        if !roles::roles_of(&env, &auditor).contains(Role::Auditor) {
+            panic!("Missing role");
+        }
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        if Self::get_distribution(env.clone(), period_id).is_none() {
+            panic!("Distribution not found");
+        }
+
+        let key = DistributionKey::DistributionAttestations(period_id);
+        let mut attestations = Self::get_distribution_attestations(env.clone(), period_id);
+        if attestations.contains(&auditor) {
+            panic!("Already attested");
+        }
+        attestations.push_back(auditor.clone());
+        storage::set(&env, DataClass::Records, &key, &attestations);
+
+        audit::log_action(&env, symbol_short!("dist_att"), &auditor);
+
+        env.events().publish(
+            (symbol_short!("dist_att"), period_id),
+            (auditor, attestations.len(), env.ledger().timestamp())
+        );
+    }
+
+    /// Get the salary distribution published for a pay period
+    pub fn get_distribution(env: Env, period_id: u32) -> Option<SalaryDistribution> {
+        storage::get(&env, DataClass::Records, &DistributionKey::Distribution(period_id))
+    }
+
+    /// Get the auditors that have attested a period's distribution
+    pub fn get_distribution_attestations(env: Env, period_id: u32) -> Vec<Address> {
+        storage::get(&env, DataClass::Records, &DistributionKey::DistributionAttestations(period_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Check whether a period's distribution has reached the attestation threshold
+    pub fn is_distribution_attested(env: Env, period_id: u32) -> bool {
+        let attestations = Self::get_distribution_attestations(env.clone(), period_id);
+        attestation::current_attestations(&env, &attestations) >= attestation::attestation_threshold(&env)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_publish_and_attest_distribution() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let auditor = Address::generate(&env);
+        client.initialize(&owner);
+        client.grant_role(&auditor, &Role::Auditor);
+
+        let buckets = Vec::from_array(&env, [(0i128, 100_000i128, 12u32), (100_000, 250_000, 5), (250_000, 1_000_000, 0)]);
+        client.publish_distribution(&202608, &buckets);
+
+        let distribution = client.get_distribution(&202608).unwrap();
+        assert_eq!(distribution.total_headcount, 17);
+        assert_eq!(distribution.buckets, buckets);
+        assert_eq!(distribution.published_by, owner);
+        assert!(!client.is_distribution_attested(&202608));
+
+        client.attest_distribution(&auditor, &202608);
+        assert!(client.is_distribution_attested(&202608));
+        assert_eq!(client.get_distribution(&202609), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Bucket too small")]
+    fn test_distribution_rejects_identifying_bucket() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+
+        // A single executive in the top band would be identifiable
+        let buckets = Vec::from_array(&env, [(0i128, 100_000i128, 12u32), (100_000, 1_000_000, 1)]);
+        env.as_contract(&contract_id, || StaffRegistry::publish_distribution(env.clone(), 202608, buckets));
+    }
+}
@@ -0,0 +1,253 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env, String, Vec};
+
+use super::*;
+
+// Upper bound on supporting documents attached to one batch
+pub const MAX_BATCH_DOCUMENTS: u32 = 20;
+
+// Upper bound on employment contract versions kept per staff member
+pub const MAX_EMPLOYMENT_CONTRACTS: u32 = 50;
+
+// Supporting artifact anchored to a batch (signed CSV hash, bank export hash...)
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchDocument {
+    pub doc_hash: BytesN<32>,
+    pub uri: String,
+    pub attached_by: Address,
+    pub attached_at: u64,
+}
+
+// Hashed employment agreement in force for a staff member from `effective_ts`
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EmploymentContract {
+    pub version: u32,           // 1 for the original agreement, incremented per amendment
+    pub doc_hash: BytesN<32>,
+    pub effective_ts: u64,
+    pub anchored_by: Address,
+    pub anchored_at: u64,
+}
+
+#[contracttype]
+enum DocumentKey {
+    EmploymentContracts(BytesN<32>),  // contract versions per staff member, oldest first
+}
+
+// Drop every employment contract version anchored for a staff member
+pub(crate) fn erase_employment_contracts(env: &Env, staff_hash: &BytesN<32>) {
+    storage::remove(env, DataClass::Records, &DocumentKey::EmploymentContracts(staff_hash.clone()));
+}
+
+// Carry a rotated staff hash's contract versions over to its replacement
+pub(crate) fn move_employment_contracts(env: &Env, old_hash: &BytesN<32>, new_hash: &BytesN<32>) {
+    rotation::move_entry(
+        env,
+        &DocumentKey::EmploymentContracts(old_hash.clone()),
+        &DocumentKey::EmploymentContracts(new_hash.clone()),
+    );
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Attach a supporting document to a recorded batch
+    pub fn attach_batch_document(env: Env, caller: Address, batch_hash: BytesN<32>, doc_hash: BytesN<32>, uri: String) {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        if !Self::is_batch_recorded(env.clone(), batch_hash.clone()) {
+            panic!("Batch not found");
+        }
+        if doc_hash == BytesN::from_array(&env, &[0u8; 32]) {
+            panic!("Invalid document hash");
+        }
+        if uri.len() > MAX_URI_LENGTH {
+            panic!("URI too long");
+        }
+
+        let key = DataKey::BatchDocuments(batch_hash.clone());
+        let mut documents = Self::get_batch_documents(env.clone(), batch_hash.clone());
+        if documents.len() >= MAX_BATCH_DOCUMENTS {
+            panic!("Too many documents");
+        }
+        for existing in documents.iter() {
+            if existing.doc_hash == doc_hash {
+                panic!("Document already attached");
+            }
+        }
+
+        documents.push_back(BatchDocument {
+            doc_hash: doc_hash.clone(),
+            uri,
+            attached_by: caller.clone(),
+            attached_at: env.ledger().timestamp(),
+        });
+        storage::set(&env, DataClass::Records, &key, &documents);
+
+        audit::log(&env, symbol_short!("batch_doc"), &caller, &batch_hash);
+
+        env.events().publish(
+            (symbol_short!("batch_doc"), batch_hash),
+            (doc_hash, caller, env.ledger().timestamp())
+        );
+    }
+
+    /// Anchor a new version of a staff member's employment contract (owner or Registrar);
+    /// returns the version number
+    pub fn anchor_employment_contract(env: Env, caller: Address, staff_hash: BytesN<32>, contract_doc_hash: BytesN<32>, effective_ts: u64) -> u32 {
+        // onlyOwner or Registrar
+        auth::require_role(&env, &caller, Role::Registrar);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        rotation::require_current(&env, &staff_hash);
+        erasure::require_not_erased(&env, &staff_hash);
+
+        if !Self::is_staff_registered(env.clone(), staff_hash.clone()) {
+            panic!("Staff not found");
+        }
+        if contract_doc_hash == BytesN::from_array(&env, &[0u8; 32]) {
+            panic!("Invalid document hash");
+        }
+
+        let mut contracts = Self::get_employment_contracts(env.clone(), staff_hash.clone());
+        if contracts.len() >= MAX_EMPLOYMENT_CONTRACTS {
+            panic!("Too many contract versions");
+        }
+        if let Some(latest) = contracts.last() {
+            if effective_ts < latest.effective_ts {
+                panic!("Effective date before current contract");
+            }
+        }
+
+        let version = contracts.len() + 1;
+        contracts.push_back(EmploymentContract {
+            version,
+            doc_hash: contract_doc_hash.clone(),
+            effective_ts,
+            anchored_by: caller.clone(),
+            anchored_at: env.ledger().timestamp(),
+        });
+        let key = DocumentKey::EmploymentContracts(staff_hash.clone());
+        storage::set(&env, DataClass::Records, &key, &contracts);
+
+        audit::log(&env, symbol_short!("emp_ctr"), &caller, &staff_hash);
+
+        env.events().publish(
+            (symbol_short!("emp_ctr"), staff_hash),
+            (version, contract_doc_hash, effective_ts, env.ledger().timestamp())
+        );
+
+        version
+    }
+
+    /// Get every anchored employment contract version for a staff member, oldest first
+    pub fn get_employment_contracts(env: Env, staff_hash: BytesN<32>) -> Vec<EmploymentContract> {
+        storage::get(&env, DataClass::Records, &DocumentKey::EmploymentContracts(staff_hash))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the employment contract version in force now, if any has taken effect
+    pub fn get_current_employment_contract(env: Env, staff_hash: BytesN<32>) -> Option<EmploymentContract> {
+        let now = env.ledger().timestamp();
+        let contracts = Self::get_employment_contracts(env.clone(), staff_hash);
+        contracts.iter().rev().find(|contract| contract.effective_ts <= now)
+    }
+
+    /// Get all documents attached to a batch, in attachment order
+    pub fn get_batch_documents(env: Env, batch_hash: BytesN<32>) -> Vec<BatchDocument> {
+        storage::get(&env, DataClass::Records, &DataKey::BatchDocuments(batch_hash))
+            .unwrap_or(Vec::new(&env))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Ledger}, Env};
+
+    #[test]
+    fn test_attach_batch_documents() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &10);
+
+        let csv_hash = BytesN::from_array(&env, &[3u8; 32]);
+        let bank_hash = BytesN::from_array(&env, &[4u8; 32]);
+        client.attach_batch_document(&owner, &batch_hash, &csv_hash, &String::from_str(&env, "ar://payroll-csv"));
+        client.attach_batch_document(&owner, &batch_hash, &bank_hash, &String::from_str(&env, "ar://bank-export"));
+
+        let documents = client.get_batch_documents(&batch_hash);
+        assert_eq!(documents.len(), 2);
+        assert_eq!(documents.get(0).unwrap().doc_hash, csv_hash);
+        assert_eq!(documents.get(1).unwrap().uri, String::from_str(&env, "ar://bank-export"));
+        assert_eq!(documents.get(1).unwrap().attached_by, owner);
+    }
+
+    #[test]
+    fn test_employment_contract_versions() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+
+        let original = BytesN::from_array(&env, &[5u8; 32]);
+        let amendment = BytesN::from_array(&env, &[6u8; 32]);
+        assert_eq!(client.anchor_employment_contract(&owner, &staff_hash, &original, &500), 1);
+        assert_eq!(client.anchor_employment_contract(&owner, &staff_hash, &amendment, &2_000), 2);
+
+        assert_eq!(client.get_employment_contracts(&staff_hash).len(), 2);
+        assert_eq!(client.get_current_employment_contract(&staff_hash).unwrap().doc_hash, original);
+
+        env.ledger().with_mut(|li| li.timestamp = 2_000);
+        let current = client.get_current_employment_contract(&staff_hash).unwrap();
+        assert_eq!(current.version, 2);
+        assert_eq!(current.doc_hash, amendment);
+    }
+
+    #[test]
+    #[should_panic(expected = "Document already attached")]
+    fn test_duplicate_document_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        let doc_hash = BytesN::from_array(&env, &[3u8; 32]);
+        let uri = String::from_str(&env, "ar://payroll-csv");
+        client.record_payroll_batch(&owner, &batch_hash, &10);
+        client.attach_batch_document(&owner, &batch_hash, &doc_hash, &uri);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::attach_batch_document(env.clone(), owner, batch_hash, doc_hash, uri)
+        });
+    }
+}
@@ -0,0 +1,173 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, BytesN, Env, String};
+
+use super::*;
+
+#[contracttype]
+enum ErasureKey {
+    ErasedAt(BytesN<32>),  // when a staff member's personal data was erased
+}
+
+pub(crate) fn erased_at(env: &Env, staff_hash: &BytesN<32>) -> Option<u64> {
+    storage::get(env, DataClass::Records, &ErasureKey::ErasedAt(staff_hash.clone()))
+}
+
+// Erased staff can't have personal data attached again
+pub(crate) fn require_not_erased(env: &Env, staff_hash: &BytesN<32>) {
+    if erased_at(env, staff_hash).is_some() {
+        panic!("Staff erased");
+    }
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Honor a right-to-erasure request (owner only). Metadata, tags, department,
+    /// bound address, salary commitment, contract anchors, sealed payloads, external
+    /// id mappings, off-boarding trail and pending registration are deleted and the
+    /// record is terminated; the bare hash stays so batch membership, merkle roots
+    /// and payment history keep verifying.
+    pub fn tombstone_staff(env: Env, staff_hash: BytesN<32>) {
+        let owner = auth::require_owner(&env);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        rotation::require_current(&env, &staff_hash);
+        require_not_erased(&env, &staff_hash);
+        let mut record = Self::get_staff_record(env.clone(), staff_hash.clone());
+        if record.status != StaffStatus::Terminated {
+            staff_status::apply(&env, &Scope::Main, &mut record, StaffStatus::Terminated);
+        }
+        record.metadata_uri = String::from_str(&env, "");
+
+        // Earlier revisions still carry the erased fields, including under retired hashes
+        for retired in Self::get_previous_hashes(env.clone(), staff_hash.clone()).iter() {
+            history::erase_staff_history(&env, &retired);
+        }
+        history::erase_staff_history(&env, &staff_hash);
+
+        history::save_staff_record(&env, &Scope::Main, &record);
+
+        tags::clear_staff_tags(&env, &staff_hash);
+        departments::unassign(&env, &staff_hash);
+        documents::erase_employment_contracts(&env, &staff_hash);
+        sealed::erase(&env, &staff_hash);
+        external_ids::erase(&env, &staff_hash);
+        acknowledgements::unbind(&env, &staff_hash);
+        offboarding::erase(&env, &staff_hash);
+        onboarding::erase(&env, &staff_hash);
+        storage::remove(&env, DataClass::Records, &DataKey::SalaryCommitment(staff_hash.clone()));
+
+        let erased_key = ErasureKey::ErasedAt(staff_hash.clone());
+        storage::set(&env, DataClass::Records, &erased_key, &env.ledger().timestamp());
+
+        audit::log(&env, symbol_short!("staff_era"), &owner, &staff_hash);
+
+        events::staff_event(&env, symbol_short!("staff_era"), &owner, &record);
+    }
+
+    /// Get when a staff member's personal data was erased, if it has been
+    pub fn get_erased_at(env: Env, staff_hash: BytesN<32>) -> Option<u64> {
+        erased_at(&env, &staff_hash)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Bytes, Env, Vec};
+
+    #[test]
+    fn test_tombstone_staff() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        let viewer = BytesN::from_array(&env, &[7u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        client.set_staff_metadata(&owner, &staff_hash, &String::from_str(&env, "ipfs://employee"));
+        client.set_staff_tags(&owner, &staff_hash, &Vec::from_array(&env, [symbol_short!("contract")]));
+        client.bind_staff_address(&owner, &staff_hash, &Address::generate(&env));
+        client.anchor_employment_contract(&owner, &staff_hash, &BytesN::from_array(&env, &[3u8; 32]), &0);
+        client.store_sealed_payload(&owner, &staff_hash, &Bytes::from_slice(&env, b"payslip"), &viewer);
+        client.record_batch_with_members(&owner, &batch_hash, &Vec::from_array(&env, [staff_hash.clone()]));
+
+        client.tombstone_staff(&staff_hash);
+
+        let record = client.get_staff_record(&staff_hash);
+        assert_eq!(record.status, StaffStatus::Terminated);
+        assert_eq!(record.metadata_uri, String::from_str(&env, ""));
+        assert!(client.get_erased_at(&staff_hash).is_some());
+        let history = client.get_staff_record_history(&staff_hash, &0, &10);
+        assert_eq!(history.len(), 1);
+        assert_eq!(history.get(0).unwrap().record, record);
+        assert_eq!(client.get_staff_tags(&staff_hash).len(), 0);
+        assert_eq!(client.get_staff_tag_count(&symbol_short!("contract")), 0);
+        assert_eq!(client.get_staff_address(&staff_hash), None);
+        assert_eq!(client.get_employment_contracts(&staff_hash).len(), 0);
+        assert_eq!(client.get_sealed_payload(&staff_hash, &viewer), None);
+        assert_eq!(client.get_sealed_viewers(&staff_hash).len(), 0);
+
+        // Historical batch integrity is untouched
+        assert!(client.is_staff_registered(&staff_hash));
+        assert!(client.is_staff_in_batch(&batch_hash, &staff_hash));
+        assert_eq!(client.get_batches_for_staff(&staff_hash, &0, &10).len(), 1);
+    }
+
+    #[test]
+    fn test_tombstone_clears_onboarding_and_offboarding() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        // A lapsed proposal stays behind when the staff member is later registered directly
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.propose_staff(&owner, &staff_hash, &Address::generate(&env));
+        env.ledger().with_mut(|li| li.timestamp += onboarding::ONBOARDING_EXPIRY_SECONDS);
+        client.register_staff(&owner, &staff_hash);
+
+        client.begin_offboarding(&owner, &staff_hash, &env.ledger().timestamp());
+        client.record_final_settlement(&owner, &staff_hash, &BytesN::from_array(&env, &[5u8; 32]));
+        assert!(client.get_pending_registration(&staff_hash).is_some());
+
+        client.tombstone_staff(&staff_hash);
+
+        assert_eq!(client.get_offboarding(&staff_hash), None);
+        assert_eq!(client.get_final_settlement(&staff_hash), None);
+        assert_eq!(client.get_pending_registration(&staff_hash), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Staff erased")]
+    fn test_erased_staff_cannot_be_reactivated() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        client.tombstone_staff(&staff_hash);
+
+        env.as_contract(&contract_id, || StaffRegistry::reactivate_staff(env.clone(), owner, staff_hash));
+    }
+}
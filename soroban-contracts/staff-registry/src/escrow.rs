@@ -0,0 +1,178 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, token, BytesN, Env};
+
+use super::*;
+
+// Release conditions for batch funding held by the contract
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct EscrowConfig {
+    pub release_delay: u64,    // seconds after finalization before funds may be disbursed
+    pub finalize_window: u64,  // seconds after funding before an unfinalized batch may be refunded
+}
+
+pub(crate) fn escrow_config(env: &Env) -> EscrowConfig {
+    storage::get(env, DataClass::Config, &DataKey::EscrowConfig)
+        .unwrap_or(EscrowConfig { release_delay: 0, finalize_window: 0 })
+}
+
+// Panic unless a batch's escrowed funds may be paid out
+pub(crate) fn require_released(env: &Env, batch_hash: &BytesN<32>) {
+    let batch = StaffRegistry::get_payroll_batch(env.clone(), batch_hash.clone());
+    if !batch.is_valid {
+        panic!("Batch is invalidated");
+    }
+
+    let release_at = StaffRegistry::escrow_release_at(env.clone(), batch_hash.clone())
+        .unwrap_or_else(|| panic!("Batch not finalized"));
+    if env.ledger().timestamp() < release_at {
+        panic!("Escrow locked");
+    }
+
+    // Nothing is paid while the batch can still be, or is being, challenged
+    match StaffRegistry::get_batch_status(env.clone(), batch_hash.clone()) {
+        BatchStatus::Final => {}
+        BatchStatus::Pending => panic!("Challenge period open"),
+        BatchStatus::Disputed => panic!("Batch disputed"),
+        BatchStatus::Superseded => panic!("Batch superseded"),
+        BatchStatus::Invalidated => panic!("Batch is invalidated"),
+    }
+}
+
+pub(crate) fn apply_escrow_config(env: &Env, owner: &Address, config: EscrowConfig) {
+    storage::set(env, DataClass::Config, &DataKey::EscrowConfig, &config);
+
+    audit::log_action(env, symbol_short!("esc_cfg"), owner);
+
+    env.events().publish(
+        (symbol_short!("esc_cfg"),),
+        (config.release_delay, config.finalize_window, env.ledger().timestamp())
+    );
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Configure escrow release and refund timing (owner only)
+    pub fn set_escrow_config(env: Env, config: EscrowConfig) {
+        let owner = auth::require_owner(&env);
+        timelock::require_not_timelocked(&env);
+
+        apply_escrow_config(&env, &owner, config);
+    }
+
+    /// Get the escrow configuration
+    pub fn get_escrow_config(env: Env) -> EscrowConfig {
+        escrow_config(&env)
+    }
+
+    /// Get when a batch's escrowed funds unlock, if the batch has been finalized
+    pub fn escrow_release_at(env: Env, batch_hash: BytesN<32>) -> Option<u64> {
+        let finalized_at: Option<u64> = storage::get(&env, DataClass::Records, &DataKey::BatchFinalizedAt(batch_hash));
+        finalized_at.map(|at| at.saturating_add(escrow_config(&env).release_delay))
+    }
+
+    /// Return undisbursed funds to the funder when a batch fails finalization (owner only)
+    pub fn refund_escrow(env: Env, batch_hash: BytesN<32>) -> i128 {
+        let owner = auth::require_owner(&env);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        let key = DataKey::BatchFunding(batch_hash.clone());
+        let mut funding: BatchFunding = storage::get(&env, DataClass::Records, &key)
+            .unwrap_or_else(|| panic!("Batch not funded"));
+
+        // Refundable once the batch is invalidated, or left unfinalized past the window
+        let batch = Self::get_payroll_batch(env.clone(), batch_hash.clone());
+        let window_elapsed = !Self::is_batch_finalized(env.clone(), batch_hash.clone())
+            && env.ledger().timestamp() >= funding.funded_at.saturating_add(escrow_config(&env).finalize_window);
+        if batch.is_valid && !window_elapsed {
+            panic!("Refund not available");
+        }
+
+        let remaining = funding.funded - funding.disbursed - funding.refunded;
+        if remaining <= 0 {
+            panic!("Nothing to refund");
+        }
+
+        token::Client::new(&env, &funding.token).transfer(&env.current_contract_address(), &funding.funder, &remaining);
+
+        funding.refunded += remaining;
+        storage::set(&env, DataClass::Records, &key, &funding);
+
+        audit::log(&env, symbol_short!("esc_rfnd"), &owner, &batch_hash);
+
+        env.events().publish(
+            (symbol_short!("esc_rfnd"), batch_hash),
+            (funding.funder, remaining, env.ledger().timestamp())
+        );
+
+        remaining
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Ledger}, token::StellarAssetClient, Address, Env, Symbol, Vec};
+
+    #[test]
+    fn test_refund_invalidated_batch() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        let uploader = Address::generate(&env);
+        client.grant_role(&uploader, &Role::PayrollUploader);
+
+        let token = env.register_stellar_asset_contract(Address::generate(&env));
+        StellarAssetClient::new(&env, &token).mint(&uploader, &1_000);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &2);
+        client.fund_batch(&uploader, &batch_hash, &token, &1_000);
+        assert_eq!(token::Client::new(&env, &token).balance(&uploader), 0);
+
+        // The refund goes back to whoever funded the batch, not the owner
+        client.invalidate_batch(&owner, &batch_hash, &Symbol::new(&env, "wrong_period"));
+        assert_eq!(client.refund_escrow(&batch_hash), 1_000);
+        assert_eq!(token::Client::new(&env, &token).balance(&uploader), 1_000);
+        assert_eq!(token::Client::new(&env, &token).balance(&owner), 0);
+        assert_eq!(client.get_batch_funding(&batch_hash).unwrap().refunded, 1_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Escrow locked")]
+    fn test_disburse_before_release_delay() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_escrow_config(&EscrowConfig { release_delay: 3_600, finalize_window: 86_400 });
+
+        let token = env.register_stellar_asset_contract(Address::generate(&env));
+        StellarAssetClient::new(&env, &token).mint(&owner, &1_000);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &2);
+        client.fund_batch(&owner, &batch_hash, &token, &1_000);
+
+        let auditor = Address::generate(&env);
+        client.grant_role(&auditor, &Role::Auditor);
+        client.attest_batch(&auditor, &batch_hash);
+        assert_eq!(client.escrow_release_at(&batch_hash), Some(4_600));
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::disburse(env.clone(), owner, batch_hash, Vec::new(&env))
+        });
+    }
+}
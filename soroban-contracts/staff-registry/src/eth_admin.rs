@@ -0,0 +1,172 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, xdr::ToXdr, Bytes, BytesN, Env};
+
+use super::*;
+
+// Prefix applied by eth_sign / personal_sign to 32-byte messages
+const ETH_SIGNED_MESSAGE_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n32";
+
+#[contracttype]
+enum EthAdminKey {
+    EthAdminPubkey, // uncompressed secp256k1 key (0x04 || x || y) of the Ethereum-side admin
+}
+
+// Digest the Ethereum admin signs:
+// personal_sign(keccak256(network_id || contract address (XDR) || batch_hash || staff_count_be)).
+// Binding the network and deployment stops a signature being replayed on another registry.
+pub(crate) fn batch_signing_digest(env: &Env, batch_hash: &BytesN<32>, staff_count: u32) -> BytesN<32> {
+    let mut message = Bytes::from_array(env, &env.ledger().network_id().to_array());
+    message.append(&env.current_contract_address().to_xdr(env));
+    message.extend_from_array(&batch_hash.to_array());
+    message.extend_from_array(&staff_count.to_be_bytes());
+    let message_hash = env.crypto().keccak256(&message);
+
+    let mut prefixed = Bytes::from_slice(env, ETH_SIGNED_MESSAGE_PREFIX);
+    prefixed.extend_from_array(&message_hash.to_array());
+    env.crypto().keccak256(&prefixed)
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Set the secp256k1 public key of the Ethereum payroll admin (owner only)
+    pub fn set_eth_admin(env: Env, pubkey: BytesN<65>) {
+        let owner = auth::require_owner(&env);
+
+        if pubkey.get(0) != Some(0x04) {
+            panic!("Invalid public key");
+        }
+
+        storage::set(&env, DataClass::Config, &EthAdminKey::EthAdminPubkey, &pubkey);
+        ttl::extend_instance(&env);
+
+        audit::log_action(&env, symbol_short!("eth_admin"), &owner);
+
+        env.events().publish(
+            (symbol_short!("eth_admin"),),
+            (pubkey, env.ledger().timestamp())
+        );
+    }
+
+    /// Get the Ethereum admin public key, if one is set
+    pub fn get_eth_admin(env: Env) -> Option<BytesN<65>> {
+        storage::get(&env, DataClass::Config, &EthAdminKey::EthAdminPubkey)
+    }
+
+    /// Record a batch authorized by an Ethereum admin signature (r || s || v) instead of a Stellar key
+    pub fn record_batch_signed(env: Env, batch_hash: BytesN<32>, staff_count: u32, sig: BytesN<65>) {
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        let admin = Self::get_eth_admin(env.clone()).unwrap_or_else(|| panic!("Ethereum admin not set"));
+
+        // v is 27/28 from eth_sign, or the raw 0/1 recovery id
+        let sig = sig.to_array();
+        let recovery_id = match sig[64] {
+            0 | 1 => sig[64] as u32,
+            27 | 28 => sig[64] as u32 - 27,
+            _ => panic!("Invalid signature"),
+        };
+        let mut rs = [0u8; 64];
+        rs.copy_from_slice(&sig[..64]);
+
+        // Batch hashes are recorded once, so a signature cannot be replayed here
+        let digest = batch_signing_digest(&env, &batch_hash, staff_count);
+        let signer = env.crypto().secp256k1_recover(&digest, &BytesN::from_array(&env, &rs), recovery_id);
+        if signer != admin {
+            panic!("Invalid signature");
+        }
+
+        store_new_batch(&env, &new_batch(&env, env.current_contract_address(), batch_hash.clone(), staff_count));
+
+        env.events().publish(
+            (symbol_short!("batch_sig"), batch_hash),
+            (signer, env.ledger().timestamp())
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use k256::ecdsa::SigningKey;
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+    // r || s || v with v = 27 + recovery id, as eth_sign returns it
+    fn sign(env: &Env, key: &SigningKey, batch_hash: &BytesN<32>, staff_count: u32) -> BytesN<65> {
+        let digest = batch_signing_digest(env, batch_hash, staff_count);
+        let (signature, recovery_id) = key.sign_prehash_recoverable(&digest.to_array()).unwrap();
+        let mut sig = [0u8; 65];
+        sig[..64].copy_from_slice(&signature.to_bytes());
+        sig[64] = 27 + recovery_id.to_byte();
+        BytesN::from_array(env, &sig)
+    }
+
+    fn pubkey(env: &Env, key: &SigningKey) -> BytesN<65> {
+        let point = key.verifying_key().to_encoded_point(false);
+        BytesN::from_array(env, point.as_bytes().try_into().unwrap())
+    }
+
+    #[test]
+    fn test_record_batch_signed() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+
+        let key = SigningKey::from_bytes(&[7u8; 32].into()).unwrap();
+        client.set_eth_admin(&pubkey(&env, &key));
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        let sig = env.as_contract(&contract_id, || sign(&env, &key, &batch_hash, 12));
+        client.record_batch_signed(&batch_hash, &12, &sig);
+
+        let batch = client.get_payroll_batch(&batch_hash);
+        assert_eq!(batch.staff_count, 12);
+        assert_eq!(batch.uploaded_by, contract_id);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid signature")]
+    fn test_record_batch_signed_by_other_key() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+
+        let admin = SigningKey::from_bytes(&[7u8; 32].into()).unwrap();
+        let intruder = SigningKey::from_bytes(&[8u8; 32].into()).unwrap();
+        client.set_eth_admin(&pubkey(&env, &admin));
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        env.as_contract(&contract_id, || {
+            let sig = sign(&env, &intruder, &batch_hash, 12);
+            StaffRegistry::record_batch_signed(env.clone(), batch_hash, 12, sig)
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid signature")]
+    fn test_signature_bound_to_deployment() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let key = SigningKey::from_bytes(&[7u8; 32].into()).unwrap();
+        let registries = [env.register_contract(None, StaffRegistry), env.register_contract(None, StaffRegistry)];
+        for contract_id in registries.iter() {
+            let client = StaffRegistryClient::new(&env, contract_id);
+            client.initialize(&Address::generate(&env));
+            client.set_eth_admin(&pubkey(&env, &key));
+        }
+
+        // Signed for the first registry, replayed on the second
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        let sig = env.as_contract(&registries[0], || sign(&env, &key, &batch_hash, 12));
+        env.as_contract(&registries[1], || {
+            StaffRegistry::record_batch_signed(env.clone(), batch_hash, 12, sig)
+        });
+    }
+}
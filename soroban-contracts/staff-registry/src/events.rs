@@ -0,0 +1,115 @@
+use soroban_sdk::{contractimpl, symbol_short, Address, Env, Symbol};
+
+use super::*;
+
+// Version of the staff/batch event layout: topics (version, event, hash), data (actor, full struct)
+pub const EVENT_SCHEMA_VERSION: u32 = 2;
+
+// Publish a staff lifecycle event carrying the record as stored after the change
+pub(crate) fn staff_event(env: &Env, event: Symbol, actor: &Address, record: &StaffRecord) {
+    env.events().publish(
+        (symbol_short!("v2"), event, record.staff_hash.clone()),
+        (actor.clone(), record.clone())
+    );
+}
+
+// Publish a batch lifecycle event carrying the batch as stored after the change
+pub(crate) fn batch_event(env: &Env, event: Symbol, actor: &Address, batch: &PayrollBatch) {
+    env.events().publish(
+        (symbol_short!("v2"), event, batch.batch_hash.clone()),
+        (actor.clone(), batch.clone())
+    );
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Get the version of the staff/batch event schema, so indexers can pick a decoder
+    pub fn get_event_schema_version(_env: Env) -> u32 {
+        EVENT_SCHEMA_VERSION
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Events}, BytesN, Env, IntoVal, TryFromVal, Val};
+
+    #[test]
+    fn test_batch_event_carries_full_batch() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        assert_eq!(client.get_event_schema_version(), 2);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &3);
+
+        let (_, topics, data) = env
+            .events()
+            .all()
+            .iter()
+            .find(|(_, topics, _)| topics.len() == 3)
+            .unwrap();
+        let expected_topics: soroban_sdk::Vec<Val> =
+            (symbol_short!("v2"), symbol_short!("batch_rec"), batch_hash.clone()).into_val(&env);
+        assert_eq!(topics, expected_topics);
+
+        let (actor, batch) = <(Address, PayrollBatch)>::try_from_val(&env, &data).unwrap();
+        assert_eq!(actor, owner);
+        assert_eq!(batch, client.get_payroll_batch(&batch_hash));
+    }
+
+    #[test]
+    fn test_indexer_decodes_contract_events() {
+        extern crate std;
+        use soroban_sdk::xdr::{Limits, ScVal, WriteXdr};
+        use std::string::{String, ToString};
+
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        client.record_payroll_batch(&owner, &batch_hash, &1);
+        client.revoke_staff(&owner, &staff_hash);
+
+        // Feed the events through the indexer exactly as RPC would deliver them
+        let b64 = |val: &Val| ScVal::try_from_val(&env, val).unwrap().to_xdr_base64(Limits::none()).unwrap();
+        let mut decoded = std::vec::Vec::new();
+        for (index, (_, topics, data)) in env.events().all().iter().enumerate() {
+            let raw = indexer::RawEvent {
+                id: index.to_string(),
+                ledger: env.ledger().sequence(),
+                ledger_closed_at: String::new(),
+                topic: topics.iter().map(|topic| b64(&topic)).collect(),
+                value: b64(&data),
+            };
+            decoded.extend(indexer::decode(&raw).unwrap());
+        }
+
+        // Address::to_string is the G... strkey as a contract String
+        let owner_strkey = owner.to_string().to_string();
+        let [indexer::RegistryEvent::Staff(registered), indexer::RegistryEvent::Batch(batch), indexer::RegistryEvent::Staff(revoked)] =
+            decoded.as_slice()
+        else {
+            panic!("unexpected events: {:?}", decoded);
+        };
+        assert_eq!((registered.kind, registered.staff_hash, registered.status.as_str()), (indexer::StaffEventKind::Registered, [1u8; 32], "Active"));
+        assert_eq!(registered.actor, owner_strkey);
+        assert_eq!((batch.batch_hash, batch.staff_count, batch.is_valid), ([2u8; 32], 1, true));
+        assert_eq!(batch.uploaded_by, owner_strkey);
+        assert_eq!((revoked.kind, revoked.status.as_str()), (indexer::StaffEventKind::Revoked, "Terminated"));
+    }
+}
@@ -0,0 +1,141 @@
+use soroban_sdk::{contractimpl, symbol_short, Address, BytesN, Env, Vec};
+
+use super::*;
+
+// Active in status and, if an end date is set, not yet past it
+pub(crate) fn is_active(env: &Env, record: &StaffRecord) -> bool {
+    record.status == StaffStatus::Active
+        && record.employment_end_ts.is_none_or(|end| env.ledger().timestamp() < end)
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Set or clear the date a staff member's employment contract ends (owner or Registrar)
+    pub fn set_end_date(env: Env, caller: Address, staff_hash: BytesN<32>, end_ts: Option<u64>) {
+        // onlyOwner or Registrar
+        auth::require_role(&env, &caller, Role::Registrar);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        rotation::require_current(&env, &staff_hash);
+        erasure::require_not_erased(&env, &staff_hash);
+
+        let mut record = Self::get_staff_record(env.clone(), staff_hash.clone());
+        if end_ts.is_some_and(|end| end < record.registered_at) {
+            panic!("End date before registration");
+        }
+        record.employment_end_ts = end_ts;
+
+        history::save_staff_record(&env, &Scope::Main, &record);
+
+        audit::log(&env, symbol_short!("staff_end"), &caller, &staff_hash);
+
+        events::staff_event(&env, symbol_short!("staff_end"), &caller, &record);
+    }
+
+    /// Terminate a staff member whose employment end date has passed (anyone, e.g. a keeper)
+    pub fn expire_staff(env: Env, caller: Address, staff_hash: BytesN<32>) {
+        caller.require_auth();
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        rotation::require_current(&env, &staff_hash);
+
+        let mut record = Self::get_staff_record(env.clone(), staff_hash.clone());
+        let ended = record.employment_end_ts.is_some_and(|end| env.ledger().timestamp() >= end);
+        if !ended || record.status == StaffStatus::Terminated {
+            panic!("Employment not ended");
+        }
+        staff_status::apply(&env, &Scope::Main, &mut record, StaffStatus::Terminated);
+
+        history::save_staff_record(&env, &Scope::Main, &record);
+
+        audit::log(&env, symbol_short!("staff_exp"), &caller, &staff_hash);
+
+        events::staff_event(&env, symbol_short!("staff_exp"), &caller, &record);
+    }
+
+    /// Scan a page of the registry for staff not yet terminated whose end date is
+    /// at or before `before_ts`; may return fewer than `limit` hashes
+    pub fn get_expiring_staff(env: Env, before_ts: u64, start: u32, limit: u32) -> Vec<BytesN<32>> {
+        let mut expiring = Vec::new(&env);
+        for staff_hash in Self::get_staff_hashes(env.clone(), start, limit).iter() {
+            let record = Self::get_staff_record(env.clone(), staff_hash.clone());
+            if record.status != StaffStatus::Terminated
+                && record.employment_end_ts.is_some_and(|end| end <= before_ts)
+            {
+                expiring.push_back(staff_hash);
+            }
+        }
+        expiring
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Ledger}, Env};
+
+    #[test]
+    fn test_staff_end_date() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let contractor = BytesN::from_array(&env, &[1u8; 32]);
+        let permanent = BytesN::from_array(&env, &[2u8; 32]);
+        let later = BytesN::from_array(&env, &[3u8; 32]);
+        client.register_staff(&owner, &contractor);
+        client.register_staff(&owner, &permanent);
+        client.register_staff(&owner, &later);
+        client.set_end_date(&owner, &contractor, &Some(2_000));
+        client.set_end_date(&owner, &later, &Some(5_000));
+
+        assert!(client.is_staff_active(&contractor));
+        assert_eq!(client.get_expiring_staff(&2_000, &0, &10), Vec::from_array(&env, [contractor.clone()]));
+
+        env.ledger().with_mut(|li| li.timestamp = 2_000);
+        assert!(!client.is_staff_active(&contractor));
+        assert!(client.is_staff_active(&permanent));
+        assert_eq!(client.get_staff_status(&contractor), StaffStatus::Active);
+
+        // A keeper terminates the expired record, after which it no longer shows up
+        client.expire_staff(&Address::generate(&env), &contractor);
+        assert_eq!(client.get_staff_status(&contractor), StaffStatus::Terminated);
+        assert_eq!(client.get_expiring_staff(&2_000, &0, &10).len(), 0);
+        assert_eq!(client.get_expiring_staff(&5_000, &0, &10), Vec::from_array(&env, [later]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Employment not ended")]
+    fn test_expire_before_end_date() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        client.set_end_date(&owner, &staff_hash, &Some(2_000));
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::expire_staff(env.clone(), Address::generate(&env), staff_hash)
+        });
+    }
+}
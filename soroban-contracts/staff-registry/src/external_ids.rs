@@ -0,0 +1,201 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env, Map, Symbol};
+
+use super::*;
+
+// Maximum number of external systems (SAP, Workday, ...) a staff member can be mapped in
+pub const MAX_EXTERNAL_SYSTEMS: u32 = 8;
+
+#[contracttype]
+enum ExternalIdKey {
+    ExternalIds(BytesN<32>),                // staff hash -> system -> hashed external id
+    StaffByExternalId(Symbol, BytesN<32>),  // (system, hashed external id) -> staff hash
+}
+
+fn external_ids(env: &Env, staff_hash: &BytesN<32>) -> Map<Symbol, BytesN<32>> {
+    storage::get(env, DataClass::Records, &ExternalIdKey::ExternalIds(staff_hash.clone()))
+        .unwrap_or(Map::new(env))
+}
+
+fn set_external_ids(env: &Env, staff_hash: &BytesN<32>, ids: &Map<Symbol, BytesN<32>>) {
+    let key = ExternalIdKey::ExternalIds(staff_hash.clone());
+    if ids.is_empty() {
+        storage::remove(env, DataClass::Records, &key);
+    } else {
+        storage::set(env, DataClass::Records, &key, ids);
+    }
+}
+
+fn set_reverse(env: &Env, system: Symbol, external_id_hash: BytesN<32>, staff_hash: &BytesN<32>) {
+    let key = ExternalIdKey::StaffByExternalId(system, external_id_hash);
+    storage::set(env, DataClass::Indexes, &key, staff_hash);
+}
+
+// Drop every external id mapping for a staff member
+pub(crate) fn erase(env: &Env, staff_hash: &BytesN<32>) {
+    for (system, external_id_hash) in external_ids(env, staff_hash).iter() {
+        storage::remove(env, DataClass::Indexes, &ExternalIdKey::StaffByExternalId(system, external_id_hash));
+    }
+    storage::remove(env, DataClass::Records, &ExternalIdKey::ExternalIds(staff_hash.clone()));
+}
+
+// Point a rotated staff hash's external ids at its replacement
+pub(crate) fn rekey(env: &Env, old_hash: &BytesN<32>, new_hash: &BytesN<32>) {
+    for (system, external_id_hash) in external_ids(env, old_hash).iter() {
+        set_reverse(env, system, external_id_hash, new_hash);
+    }
+    rotation::move_entry(
+        env,
+        &ExternalIdKey::ExternalIds(old_hash.clone()),
+        &ExternalIdKey::ExternalIds(new_hash.clone()),
+    );
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Map a staff member to the hash of their identifier in an external HR system;
+    /// rebinding the same system replaces the previous id (owner or Registrar)
+    pub fn bind_external_id(env: Env, caller: Address, staff_hash: BytesN<32>, system: Symbol, external_id_hash: BytesN<32>) {
+        // onlyOwner or Registrar
+        auth::require_role(&env, &caller, Role::Registrar);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        rotation::require_current(&env, &staff_hash);
+        erasure::require_not_erased(&env, &staff_hash);
+
+        if !Self::is_staff_registered(env.clone(), staff_hash.clone()) {
+            panic!("Staff not found");
+        }
+        if external_id_hash == BytesN::from_array(&env, &[0u8; 32]) {
+            panic!("Invalid external id hash");
+        }
+        if Self::get_staff_by_external_id(env.clone(), system.clone(), external_id_hash.clone())
+            .is_some_and(|bound| bound != staff_hash)
+        {
+            panic!("External id already bound");
+        }
+
+        let mut ids = external_ids(&env, &staff_hash);
+        if let Some(previous) = ids.get(system.clone()) {
+            storage::remove(&env, DataClass::Indexes, &ExternalIdKey::StaffByExternalId(system.clone(), previous));
+        } else if ids.len() >= MAX_EXTERNAL_SYSTEMS {
+            panic!("Too many external systems");
+        }
+        ids.set(system.clone(), external_id_hash.clone());
+        set_external_ids(&env, &staff_hash, &ids);
+        set_reverse(&env, system.clone(), external_id_hash.clone(), &staff_hash);
+
+        audit::log(&env, symbol_short!("ext_bind"), &caller, &staff_hash);
+
+        env.events().publish(
+            (symbol_short!("ext_bind"), staff_hash, system),
+            (caller, external_id_hash, env.ledger().timestamp())
+        );
+    }
+
+    /// Remove a staff member's mapping in an external system (owner or Registrar)
+    pub fn unbind_external_id(env: Env, caller: Address, staff_hash: BytesN<32>, system: Symbol) {
+        // onlyOwner or Registrar
+        auth::require_role(&env, &caller, Role::Registrar);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        rotation::require_current(&env, &staff_hash);
+
+        let mut ids = external_ids(&env, &staff_hash);
+        let external_id_hash = ids.get(system.clone()).unwrap_or_else(|| panic!("External id not bound"));
+        ids.remove(system.clone());
+        set_external_ids(&env, &staff_hash, &ids);
+        storage::remove(&env, DataClass::Indexes, &ExternalIdKey::StaffByExternalId(system.clone(), external_id_hash));
+
+        audit::log(&env, symbol_short!("ext_unbnd"), &caller, &staff_hash);
+
+        env.events().publish(
+            (symbol_short!("ext_unbnd"), staff_hash, system),
+            (caller, env.ledger().timestamp())
+        );
+    }
+
+    /// Get a staff member's hashed ids, keyed by external system
+    pub fn get_external_ids(env: Env, staff_hash: BytesN<32>) -> Map<Symbol, BytesN<32>> {
+        external_ids(&env, &rotation::resolve(&env, &staff_hash))
+    }
+
+    /// Find the staff hash mapped to a hashed id in an external system
+    pub fn get_staff_by_external_id(env: Env, system: Symbol, external_id_hash: BytesN<32>) -> Option<BytesN<32>> {
+        storage::get(&env, DataClass::Indexes, &ExternalIdKey::StaffByExternalId(system, external_id_hash))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_external_id_mapping() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let rotated = BytesN::from_array(&env, &[2u8; 32]);
+        let sap_id = BytesN::from_array(&env, &[10u8; 32]);
+        let workday_id = BytesN::from_array(&env, &[11u8; 32]);
+        let new_sap_id = BytesN::from_array(&env, &[12u8; 32]);
+        let sap = symbol_short!("sap");
+        let workday = symbol_short!("workday");
+        client.register_staff(&owner, &staff_hash);
+
+        client.bind_external_id(&owner, &staff_hash, &sap, &sap_id);
+        client.bind_external_id(&owner, &staff_hash, &workday, &workday_id);
+        assert_eq!(client.get_staff_by_external_id(&sap, &sap_id), Some(staff_hash.clone()));
+        assert_eq!(client.get_external_ids(&staff_hash).len(), 2);
+
+        // Rebinding a system replaces the old id; a rotation carries the mapping along
+        client.bind_external_id(&owner, &staff_hash, &sap, &new_sap_id);
+        assert_eq!(client.get_staff_by_external_id(&sap, &sap_id), None);
+        client.rotate_staff_hash(&owner, &staff_hash, &rotated);
+        assert_eq!(client.get_staff_by_external_id(&sap, &new_sap_id), Some(rotated.clone()));
+        assert_eq!(client.get_external_ids(&staff_hash).get(workday.clone()), Some(workday_id.clone()));
+
+        client.unbind_external_id(&owner, &rotated, &workday);
+        assert_eq!(client.get_staff_by_external_id(&workday, &workday_id), None);
+        assert_eq!(client.get_external_ids(&rotated).len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "External id already bound")]
+    fn test_external_id_bound_twice() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let first = BytesN::from_array(&env, &[1u8; 32]);
+        let second = BytesN::from_array(&env, &[2u8; 32]);
+        let external_id_hash = BytesN::from_array(&env, &[10u8; 32]);
+        client.register_staff(&owner, &first);
+        client.register_staff(&owner, &second);
+        client.bind_external_id(&owner, &first, &symbol_short!("sap"), &external_id_hash);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::bind_external_id(env.clone(), owner, second, symbol_short!("sap"), external_id_hash)
+        });
+    }
+}
@@ -0,0 +1,108 @@
+use soroban_sdk::{contractimpl, symbol_short, BytesN, Env};
+
+use super::*;
+
+// Registration is implied by the record itself: a staff hash is registered while
+// its record exists or it was rotated away from, a batch while its record exists.
+// Deployments from before v8 also wrote IsStaffRegistered / IsBatchRecorded flags;
+// those are still honored on a miss until `migrate_flags` has cleared them.
+pub(crate) fn staff_exists(env: &Env, staff_hash: &BytesN<32>) -> bool {
+    storage::has(env, DataClass::Records, &DataKey::StaffRecord(staff_hash.clone()))
+        || rotation::is_retired(env, staff_hash)
+        || legacy_flag(env, &DataKey::IsStaffRegistered(staff_hash.clone()))
+}
+
+pub(crate) fn batch_exists(env: &Env, batch_hash: &BytesN<32>) -> bool {
+    storage::has(env, DataClass::Records, &DataKey::PayrollBatch(batch_hash.clone()))
+        || legacy_flag(env, &DataKey::IsBatchRecorded(batch_hash.clone()))
+}
+
+fn legacy_flag(env: &Env, key: &DataKey) -> bool {
+    storage::get(env, DataClass::Records, key).unwrap_or(false)
+}
+
+fn clear_flag(env: &Env, key: &DataKey) -> u32 {
+    if storage::has(env, DataClass::Records, key) {
+        storage::remove(env, DataClass::Records, key);
+        1
+    } else {
+        0
+    }
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Delete the legacy registration flags of list positions `start..start + limit`
+    /// in both the staff and batch lists, including flags left under rotated-away
+    /// staff hashes (owner only). Returns the number of flags removed.
+    pub fn migrate_flags(env: Env, start: u32, limit: u32) -> u32 {
+        let owner = auth::require_owner(&env);
+        ttl::extend_instance(&env);
+
+        let mut removed = 0;
+        for staff_hash in lists::page(&env, lists::List::Staff, start, limit).iter() {
+            for retired in Self::get_previous_hashes(env.clone(), staff_hash.clone()).iter() {
+                removed += clear_flag(&env, &DataKey::IsStaffRegistered(retired));
+            }
+            removed += clear_flag(&env, &DataKey::IsStaffRegistered(staff_hash));
+        }
+        for batch_hash in lists::page(&env, lists::List::Batch, start, limit).iter() {
+            removed += clear_flag(&env, &DataKey::IsBatchRecorded(batch_hash));
+        }
+
+        audit::log_action(&env, symbol_short!("flg_migr"), &owner);
+
+        env.events().publish(
+            (symbol_short!("flg_migr"),),
+            (start, removed, env.ledger().timestamp())
+        );
+
+        removed
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+    #[test]
+    fn test_migrate_flags_keeps_lookups_working() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let rotated = BytesN::from_array(&env, &[2u8; 32]);
+        let batch_hash = BytesN::from_array(&env, &[3u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        client.record_payroll_batch(&owner, &batch_hash, &1);
+        client.rotate_staff_hash(&owner, &staff_hash, &rotated);
+
+        // New writes no longer create flags; simulate a pre-v8 deployment that did
+        env.as_contract(&contract_id, || {
+            assert!(!env.storage().persistent().has(&DataKey::IsStaffRegistered(rotated.clone())));
+            for key in [
+                DataKey::IsStaffRegistered(staff_hash.clone()),
+                DataKey::IsStaffRegistered(rotated.clone()),
+                DataKey::IsBatchRecorded(batch_hash.clone()),
+            ] {
+                env.storage().persistent().set(&key, &true);
+            }
+        });
+
+        assert_eq!(client.migrate_flags(&0, &10), 3);
+        assert_eq!(client.migrate_flags(&0, &10), 0);
+
+        assert!(client.is_staff_registered(&staff_hash));
+        assert!(client.is_staff_active(&rotated));
+        assert!(client.is_batch_recorded(&batch_hash));
+        assert!(!client.is_staff_registered(&BytesN::from_array(&env, &[9u8; 32])));
+    }
+}
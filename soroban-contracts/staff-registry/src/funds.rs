@@ -0,0 +1,154 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, token, Address, BytesN, Env};
+
+use super::*;
+
+// Balance a batch's declared total must be covered by before it is accepted
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FundsCheck {
+    pub token: Address,     // funding token whose balance is read
+    pub treasury: Address,  // holder checked; the contract's own address checks escrowed funds
+}
+
+#[contracttype]
+enum FundsKey {
+    FundsCheck,
+    DeclaredTotal(BytesN<32>),  // declared payroll total per batch
+}
+
+fn funds_check(env: &Env) -> Option<FundsCheck> {
+    storage::get(env, DataClass::Config, &FundsKey::FundsCheck)
+}
+
+// Cross-call the funding token and reject totals the treasury can't cover
+fn require_funds(env: &Env, declared_total: i128) {
+    if let Some(check) = funds_check(env) {
+        let balance = token::Client::new(env, &check.token).balance(&check.treasury);
+        if balance < declared_total {
+            panic!("Insufficient funds");
+        }
+    }
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Require batches with a declared total to be covered by a treasury's token balance (owner only)
+    pub fn set_funds_check(env: Env, token: Address, treasury: Address) {
+        let owner = auth::require_owner(&env);
+
+        storage::set(&env, DataClass::Config, &FundsKey::FundsCheck, &FundsCheck { token: token.clone(), treasury: treasury.clone() });
+        ttl::extend_instance(&env);
+
+        audit::log_action(&env, symbol_short!("funds_chk"), &owner);
+
+        env.events().publish(
+            (symbol_short!("funds_chk"),),
+            (token, treasury, env.ledger().timestamp())
+        );
+    }
+
+    /// Stop checking declared totals against a treasury balance (owner only)
+    pub fn clear_funds_check(env: Env) {
+        let owner = auth::require_owner(&env);
+
+        storage::remove(&env, DataClass::Config, &FundsKey::FundsCheck);
+        ttl::extend_instance(&env);
+
+        audit::log_action(&env, symbol_short!("funds_clr"), &owner);
+
+        env.events().publish(
+            (symbol_short!("funds_clr"),),
+            env.ledger().timestamp()
+        );
+    }
+
+    /// Get the proof-of-funds check, if enabled
+    pub fn get_funds_check(env: Env) -> Option<FundsCheck> {
+        funds_check(&env)
+    }
+
+    /// Record a payroll batch with its declared total; when the funds check is on,
+    /// the batch is rejected unless the treasury holds at least that much
+    pub fn record_payroll_batch_with_total(env: Env, caller: Address, batch_hash: BytesN<32>, staff_count: u32, declared_total: i128) {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        if declared_total <= 0 {
+            panic!("Invalid amount");
+        }
+        require_funds(&env, declared_total);
+
+        store_new_batch(&env, &new_batch(&env, caller.clone(), batch_hash.clone(), staff_count));
+
+        let key = FundsKey::DeclaredTotal(batch_hash.clone());
+        storage::set(&env, DataClass::Records, &key, &declared_total);
+
+        audit::log(&env, symbol_short!("batch_tot"), &caller, &batch_hash);
+
+        env.events().publish(
+            (symbol_short!("batch_tot"), batch_hash),
+            (declared_total, env.ledger().timestamp())
+        );
+    }
+
+    /// Get the total declared for a batch, if one was
+    pub fn get_declared_total(env: Env, batch_hash: BytesN<32>) -> Option<i128> {
+        storage::get(&env, DataClass::Records, &FundsKey::DeclaredTotal(batch_hash))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, token::StellarAssetClient, Env};
+
+    #[test]
+    fn test_batch_within_treasury_balance() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let treasury = Address::generate(&env);
+        client.initialize(&owner);
+
+        let token = env.register_stellar_asset_contract(Address::generate(&env));
+        StellarAssetClient::new(&env, &token).mint(&treasury, &10_000);
+        client.set_funds_check(&token, &treasury);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch_with_total(&owner, &batch_hash, &5, &10_000);
+        assert_eq!(client.get_declared_total(&batch_hash), Some(10_000));
+
+        // Batches without a declared total are not checked
+        client.record_payroll_batch(&owner, &BytesN::from_array(&env, &[3u8; 32]), &5);
+        assert_eq!(client.get_declared_total(&BytesN::from_array(&env, &[3u8; 32])), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Insufficient funds")]
+    fn test_underfunded_batch_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        // Checking the contract itself: nothing escrowed yet
+        let token = env.register_stellar_asset_contract(Address::generate(&env));
+        client.set_funds_check(&token, &contract_id);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::record_payroll_batch_with_total(env.clone(), owner, BytesN::from_array(&env, &[2u8; 32]), 5, 1)
+        });
+    }
+}
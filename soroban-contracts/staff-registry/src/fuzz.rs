@@ -0,0 +1,182 @@
+// Randomized operation sequences checked against registry invariants. Slow, so
+// only built with `cargo test --features fuzz`; FUZZ_CASES and FUZZ_SEED override
+// the defaults, and a failing case prints the seed that reproduces it.
+
+extern crate std;
+
+use std::{format, string::String, vec, vec::Vec as StdVec};
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use soroban_sdk::{BytesN, Env, Vec};
+
+use super::*;
+use crate::testutils::{advance_ledgers, fake_batch_hash, fake_staff_hash, TestRegistry};
+
+const DEFAULT_CASES: u64 = 32;
+const MAX_OPS: usize = 60;
+
+#[derive(Clone, Debug)]
+enum Op {
+    Register(u32),
+    Revoke(usize),
+    Record(u32, u32),
+    Supersede(usize, u32, u32),
+}
+
+// What the registry should hold after the ops applied so far
+#[derive(Default)]
+struct Model {
+    staff: StdVec<(u32, bool)>,  // (seed, active), in registration order
+    batches: StdVec<(u32, bool)>, // (seed, superseded), in upload order
+    next_seed: u32,
+}
+
+impl Model {
+    // Only generate ops the contract should accept; rejections abort through the client
+    fn next_op(&mut self, rng: &mut StdRng) -> Op {
+        let roll = rng.gen_range(0..10);
+        let open: StdVec<usize> = (0..self.batches.len()).filter(|&i| !self.batches[i].1).collect();
+
+        if roll < 3 || (roll < 5 && self.staff.is_empty()) {
+            self.next_seed += 1;
+            self.staff.push((self.next_seed, true));
+            Op::Register(self.next_seed)
+        } else if roll < 5 {
+            // Revoking an already revoked member is a no-op and must stay one
+            let index = rng.gen_range(0..self.staff.len());
+            self.staff[index].1 = false;
+            Op::Revoke(index)
+        } else if roll < 8 || open.is_empty() {
+            self.next_seed += 1;
+            self.batches.push((self.next_seed, false));
+            Op::Record(self.next_seed, rng.gen_range(1..50))
+        } else {
+            let index = open[rng.gen_range(0..open.len())];
+            self.next_seed += 1;
+            self.batches[index].1 = true;
+            self.batches.push((self.next_seed, false));
+            Op::Supersede(index, self.next_seed, rng.gen_range(1..50))
+        }
+    }
+}
+
+fn apply(registry: &TestRegistry, model: &Model, op: &Op) {
+    let (env, client, owner) = (&registry.env, &registry.client, &registry.owner);
+    match *op {
+        Op::Register(seed) => client.register_staff(owner, &fake_staff_hash(env, seed)),
+        Op::Revoke(index) => client.revoke_staff(owner, &fake_staff_hash(env, model.staff[index].0)),
+        Op::Record(seed, staff_count) => client.record_payroll_batch(owner, &fake_batch_hash(env, seed), &staff_count),
+        Op::Supersede(index, seed, staff_count) => client.supersede_batch(
+            owner,
+            &fake_batch_hash(env, model.batches[index].0),
+            &fake_batch_hash(env, seed),
+            &staff_count,
+            &symbol_short!("fuzz"),
+        ),
+    }
+}
+
+fn read_all(page: impl Fn(u32, u32) -> Vec<BytesN<32>>, total: u32) -> StdVec<BytesN<32>> {
+    let mut all = StdVec::new();
+    while (all.len() as u32) < total {
+        let next = page(all.len() as u32, MAX_PAGE_SIZE);
+        assert!(!next.is_empty(), "page ended before the reported total");
+        all.extend(next.iter());
+    }
+    assert!(page(total, MAX_PAGE_SIZE).is_empty(), "page continues past the reported total");
+    all
+}
+
+fn check_invariants(registry: &TestRegistry, model: &Model) -> Result<(), String> {
+    let (env, client) = (&registry.env, &registry.client);
+    let zero = BytesN::from_array(env, &[0u8; 32]);
+
+    // Counters match the staff index and the model
+    let staff = read_all(|start, limit| client.get_staff_hashes(&start, &limit), client.get_total_staff());
+    let stats = client.get_staff_stats();
+    let expected: StdVec<BytesN<32>> = model.staff.iter().map(|(seed, _)| fake_staff_hash(env, *seed)).collect();
+    if staff != expected {
+        return Err(format!("staff index {} entries, model {}", staff.len(), expected.len()));
+    }
+    let active = model.staff.iter().filter(|(_, active)| *active).count() as u32;
+    if (stats.total, stats.active_count, stats.revoked_count) != (staff.len() as u32, active, staff.len() as u32 - active) {
+        return Err(format!("stats {:?} for {} staff, {} active", stats, staff.len(), active));
+    }
+    for (staff_hash, (_, is_active)) in staff.iter().zip(model.staff.iter()) {
+        if !client.is_staff_registered(staff_hash) || client.is_staff_active(staff_hash) != *is_active {
+            return Err(format!("staff record out of step with index for {:?}", staff_hash));
+        }
+    }
+
+    // No hash is listed twice
+    let batches = read_all(|start, limit| client.get_batch_hashes(&start, &limit), client.get_total_batches());
+    for hashes in [&staff, &batches] {
+        let mut sorted: StdVec<[u8; 32]> = hashes.iter().map(|hash| hash.to_array()).collect();
+        sorted.sort();
+        sorted.dedup();
+        if sorted.len() != hashes.len() {
+            return Err(String::from("duplicate hash in a registry list"));
+        }
+    }
+
+    // Every batch links to the one uploaded before it, and correction links agree both ways
+    if batches.len() != model.batches.len() {
+        return Err(format!("batch index {} entries, model {}", batches.len(), model.batches.len()));
+    }
+    let mut prev = zero.clone();
+    for (batch_hash, (_, superseded)) in batches.iter().zip(model.batches.iter()) {
+        let batch = client.get_payroll_batch(batch_hash);
+        if batch.prev_batch_hash != prev {
+            return Err(format!("broken chain link at {:?}", batch_hash));
+        }
+        if (batch.superseded_by != zero) != *superseded {
+            return Err(format!("superseded flag out of step at {:?}", batch_hash));
+        }
+        if batch.superseded_by != zero && client.get_payroll_batch(&batch.superseded_by).supersedes != *batch_hash {
+            return Err(format!("one-way correction link at {:?}", batch_hash));
+        }
+        if batch.supersedes != zero && client.get_payroll_batch(&batch.supersedes).superseded_by != *batch_hash {
+            return Err(format!("one-way correction link back from {:?}", batch_hash));
+        }
+        prev = batch_hash.clone();
+    }
+    if client.get_latest_batch().map(|batch| batch.batch_hash) != batches.last().cloned() {
+        return Err(String::from("latest batch is not the end of the chain"));
+    }
+
+    Ok(())
+}
+
+fn run_case(seed: u64) {
+    let env = Env::default();
+    env.budget().reset_unlimited();
+    let registry = TestRegistry::new(&env);
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut model = Model::default();
+    let mut ops = vec![];
+
+    for _ in 0..rng.gen_range(1..=MAX_OPS) {
+        let op = model.next_op(&mut rng);
+        apply(&registry, &model, &op);
+        ops.push(op);
+        advance_ledgers(&env, rng.gen_range(0..3));
+
+        if let Err(message) = check_invariants(&registry, &model) {
+            panic!("FUZZ_SEED={} failed after {:?}: {}", seed, ops, message);
+        }
+    }
+}
+
+fn env_u64(name: &str) -> Option<u64> {
+    std::env::var(name).ok().map(|value| value.parse().unwrap_or_else(|_| panic!("{} must be a number", name)))
+}
+
+#[test]
+fn test_random_operation_sequences() {
+    if let Some(seed) = env_u64("FUZZ_SEED") {
+        return run_case(seed);
+    }
+    for seed in 0..env_u64("FUZZ_CASES").unwrap_or(DEFAULT_CASES) {
+        run_case(seed);
+    }
+}
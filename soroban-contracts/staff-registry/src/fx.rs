@@ -0,0 +1,233 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, vec, Address, BytesN, Env, IntoVal, Symbol, Vec};
+
+use super::*;
+
+// Fixed-point scale for anchored rates: 1 base unit = rate / 10^7 quote units
+pub const FX_RATE_DECIMALS: u32 = 7;
+
+// Most currency pairs anchored to a single batch
+pub const MAX_FX_RATES_PER_BATCH: u32 = 10;
+
+// Exchange rate used to normalize a batch into another currency
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FxRate {
+    pub base: Symbol,
+    pub quote: Symbol,
+    pub rate: i128,         // scaled by 10^FX_RATE_DECIMALS
+    pub oracle: Address,    // price source the rate was taken from
+    pub verified: bool,     // checked against the oracle on-chain when anchored
+    pub anchored_by: Address,
+    pub anchored_at: u64,
+}
+
+// Trusted oracle anchored rates are checked against; tolerance in basis points
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FxVerification {
+    pub oracle: Address,
+    pub tolerance_bps: u32,
+}
+
+// SEP-40 asset identifier and price entry, as returned by Soroban price oracles
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum OracleAsset {
+    Stellar(Address),
+    Other(Symbol),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+enum FxKey {
+    FxVerification,
+    BatchFxRates(BytesN<32>),  // rates anchored per batch
+}
+
+fn fx_verification(env: &Env) -> Option<FxVerification> {
+    storage::get(env, DataClass::Config, &FxKey::FxVerification)
+}
+
+// Cross rate from a SEP-40 oracle, rescaled to FX_RATE_DECIMALS
+fn oracle_rate(env: &Env, oracle: &Address, base: &Symbol, quote: &Symbol) -> i128 {
+    let decimals: u32 = env.invoke_contract(oracle, &Symbol::new(env, "decimals"), Vec::new(env));
+    let args = vec![
+        env,
+        OracleAsset::Other(base.clone()).into_val(env),
+        OracleAsset::Other(quote.clone()).into_val(env),
+    ];
+    let price: Option<PriceData> = env.invoke_contract(oracle, &Symbol::new(env, "x_last_price"), args);
+    let price = price.unwrap_or_else(|| panic!("Oracle has no price")).price;
+
+    if decimals >= FX_RATE_DECIMALS {
+        price / 10i128.pow(decimals - FX_RATE_DECIMALS)
+    } else {
+        price * 10i128.pow(FX_RATE_DECIMALS - decimals)
+    }
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Require anchored rates to come from a trusted oracle and stay within a tolerance of its price (owner only)
+    pub fn set_fx_verification(env: Env, oracle: Address, tolerance_bps: u32) {
+        let owner = auth::require_owner(&env);
+
+        if tolerance_bps > 10_000 {
+            panic!("Invalid tolerance");
+        }
+        storage::set(&env, DataClass::Config, &FxKey::FxVerification, &FxVerification { oracle: oracle.clone(), tolerance_bps });
+        ttl::extend_instance(&env);
+
+        audit::log_action(&env, symbol_short!("fx_cfg"), &owner);
+
+        env.events().publish(
+            (symbol_short!("fx_cfg"),),
+            (oracle, tolerance_bps, env.ledger().timestamp())
+        );
+    }
+
+    /// Get the FX verification settings, if enabled
+    pub fn get_fx_verification(env: Env) -> Option<FxVerification> {
+        fx_verification(&env)
+    }
+
+    /// Anchor the exchange rate used to normalize a batch (owner or PayrollUploader)
+    pub fn anchor_fx_rate(env: Env, caller: Address, batch_hash: BytesN<32>, base: Symbol, quote: Symbol, rate: i128, oracle: Address) {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        if !Self::is_batch_recorded(env.clone(), batch_hash.clone()) {
+            panic!("Batch not found");
+        }
+        if rate <= 0 || base == quote {
+            panic!("Invalid rate");
+        }
+
+        let mut rates = Self::get_fx_rates(env.clone(), batch_hash.clone());
+        if rates.iter().any(|fx| fx.base == base && fx.quote == quote) {
+            panic!("FX rate already anchored");
+        }
+        if rates.len() >= MAX_FX_RATES_PER_BATCH {
+            panic!("Too many FX rates");
+        }
+
+        let verification = fx_verification(&env);
+        if let Some(verification) = &verification {
+            if oracle != verification.oracle {
+                panic!("Untrusted oracle");
+            }
+            let reference = oracle_rate(&env, &oracle, &base, &quote);
+            if (rate - reference).abs() * 10_000 > reference * verification.tolerance_bps as i128 {
+                panic!("FX rate deviates from oracle");
+            }
+        }
+
+        rates.push_back(FxRate {
+            base: base.clone(),
+            quote: quote.clone(),
+            rate,
+            oracle,
+            verified: verification.is_some(),
+            anchored_by: caller.clone(),
+            anchored_at: env.ledger().timestamp(),
+        });
+        let key = FxKey::BatchFxRates(batch_hash.clone());
+        storage::set(&env, DataClass::Records, &key, &rates);
+
+        audit::log(&env, symbol_short!("fx_rate"), &caller, &batch_hash);
+
+        env.events().publish(
+            (symbol_short!("fx_rate"), batch_hash),
+            (base, quote, rate, env.ledger().timestamp())
+        );
+    }
+
+    /// Get the exchange rates anchored to a batch
+    pub fn get_fx_rates(env: Env, batch_hash: BytesN<32>) -> Vec<FxRate> {
+        storage::get(&env, DataClass::Records, &FxKey::BatchFxRates(batch_hash))
+            .unwrap_or(Vec::new(&env))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{contract, testutils::Address as _, Env};
+
+    // Quotes 1 USD = 1550 NGN with 14 decimals, as many SEP-40 feeds do
+    #[contract]
+    struct MockOracle;
+
+    #[contractimpl]
+    impl MockOracle {
+        pub fn decimals(_env: Env) -> u32 {
+            14
+        }
+
+        pub fn x_last_price(env: Env, _base: OracleAsset, _quote: OracleAsset) -> Option<PriceData> {
+            Some(PriceData { price: 1_550 * 10i128.pow(14), timestamp: env.ledger().timestamp() })
+        }
+    }
+
+    #[test]
+    fn test_anchor_fx_rate() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        let oracle = env.register_contract(None, MockOracle);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &10);
+
+        // Unverified while no trusted oracle is configured
+        let usd = symbol_short!("USD");
+        let ngn = symbol_short!("NGN");
+        client.anchor_fx_rate(&owner, &batch_hash, &usd, &symbol_short!("EUR"), &9_200_000, &Address::generate(&env));
+
+        client.set_fx_verification(&oracle, &100);
+        client.anchor_fx_rate(&owner, &batch_hash, &usd, &ngn, &(1_560 * 10_000_000), &oracle);
+
+        let rates = client.get_fx_rates(&batch_hash);
+        assert_eq!(rates.len(), 2);
+        assert!(!rates.get(0).unwrap().verified);
+        assert!(rates.get(1).unwrap().verified);
+        assert_eq!(rates.get(1).unwrap().quote, ngn);
+    }
+
+    #[test]
+    #[should_panic(expected = "FX rate deviates from oracle")]
+    fn test_fx_rate_outside_tolerance() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        let oracle = env.register_contract(None, MockOracle);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &10);
+        client.set_fx_verification(&oracle, &100);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::anchor_fx_rate(env.clone(), owner, batch_hash, symbol_short!("USD"), symbol_short!("NGN"), 1_600 * 10_000_000, oracle)
+        });
+    }
+}
@@ -0,0 +1,272 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, xdr::ToXdr, Bytes, BytesN, Env, Symbol};
+
+use super::*;
+
+// Domain tag prefixed to every domain-separated staff hash preimage
+const STAFF_HASH_V2_TAG: &[u8] = b"payroll-staff-v2";
+
+// Digest used for staff hashes and merkle nodes. Keccak256 matches what the
+// Ethereum contracts derive with `keccak256(abi.encodePacked(...))`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HashMode {
+    Sha256,
+    Keccak256,
+}
+
+pub(crate) fn hash_mode(env: &Env) -> HashMode {
+    storage::get(env, DataClass::Config, &DataKey::HashMode)
+        .unwrap_or(HashMode::Sha256)
+}
+
+#[contracttype]
+enum HashKey {
+    LegacyHashesAllowed,       // owner opted out of requiring v2 hashes for new registrations
+    RootPublished,             // a batch merkle root was recorded under the active mode
+}
+
+// Batch roots are verified with the active mode, so it can't change once one exists
+pub(crate) fn mark_root_published(env: &Env) {
+    storage::set(env, DataClass::Config, &HashKey::RootPublished, &true);
+}
+
+fn is_mode_locked(env: &Env) -> bool {
+    registry_tree::leaf_count(env) > 0 || storage::has(env, DataClass::Config, &HashKey::RootPublished)
+}
+
+// v2 staff hash: tag || chain domain || contract address (XDR) || org id (XDR) || preimage.
+// Mixing in the network and deployment means a hash recorded on testnet, on another
+// deployment or on the Ethereum twin never equals one derived here.
+pub(crate) fn staff_hash_v2(env: &Env, preimage: &Bytes, org_id: &Symbol, chain_domain: &BytesN<32>) -> BytesN<32> {
+    let mut data = Bytes::from_slice(env, STAFF_HASH_V2_TAG);
+    data.extend_from_array(&chain_domain.to_array());
+    data.append(&env.current_contract_address().to_xdr(env));
+    data.append(&org_id.clone().to_xdr(env));
+    data.append(preimage);
+    digest(env, hash_mode(env), &data)
+}
+
+// v2 staff hash for this network (the network passphrase hash is the chain domain)
+pub(crate) fn local_staff_hash(env: &Env, org_id: &Symbol, preimage: &Bytes) -> BytesN<32> {
+    staff_hash_v2(env, preimage, org_id, &env.ledger().network_id())
+}
+
+// Org id mixed into main-registry hashes; orgs can't be registered under it
+pub(crate) fn main_registry_org(env: &Env) -> Symbol {
+    Symbol::new(env, "")
+}
+
+// Registrations taking a caller-supplied hash are closed once v2 hashes are required
+pub(crate) fn require_legacy_hash_allowed(env: &Env) {
+    if StaffRegistry::is_domain_separation_required(env.clone()) {
+        panic!("Domain-separated hash required");
+    }
+}
+
+pub(crate) fn digest(env: &Env, mode: HashMode, data: &Bytes) -> BytesN<32> {
+    match mode {
+        HashMode::Sha256 => env.crypto().sha256(data),
+        HashMode::Keccak256 => env.crypto().keccak256(data),
+    }
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Select the digest for leaf and merkle computations (owner only).
+    /// Locked once staff are registered or a batch merkle root is recorded, since the
+    /// registry tree is hashed incrementally and batch proofs are checked in this mode.
+    pub fn set_hash_mode(env: Env, mode: HashMode) {
+        let owner = auth::require_owner(&env);
+
+        if is_mode_locked(&env) && mode != hash_mode(&env) {
+            panic!("Hash mode locked");
+        }
+
+        storage::set(&env, DataClass::Config, &DataKey::HashMode, &mode);
+        ttl::extend_instance(&env);
+
+        audit::log_action(&env, symbol_short!("hash_mode"), &owner);
+
+        env.events().publish(
+            (symbol_short!("hash_mode"),),
+            (mode, env.ledger().timestamp())
+        );
+    }
+
+    /// Get the active hash mode
+    pub fn get_hash_mode(env: Env) -> HashMode {
+        hash_mode(&env)
+    }
+
+    /// Derive a staff hash from its off-chain preimage using the active hash mode
+    pub fn compute_staff_hash(env: Env, preimage: Bytes) -> BytesN<32> {
+        digest(&env, hash_mode(&env), &preimage)
+    }
+
+    /// Derive a domain-separated staff hash for an org namespace (empty symbol for the
+    /// main registry) on the network identified by `chain_domain`
+    pub fn compute_staff_hash_v2(env: Env, preimage: Bytes, org_id: Symbol, chain_domain: BytesN<32>) -> BytesN<32> {
+        staff_hash_v2(&env, &preimage, &org_id, &chain_domain)
+    }
+
+    /// Get the chain domain this deployment mixes into v2 staff hashes
+    pub fn get_chain_domain(env: Env) -> BytesN<32> {
+        env.ledger().network_id()
+    }
+
+    /// Accept caller-supplied staff hashes again for deployments that must keep registering
+    /// legacy hashes (owner only). Off by default: new registrations derive their hash
+    /// on-chain via the v2 entry points.
+    pub fn set_legacy_hashes_allowed(env: Env, allowed: bool) {
+        let owner = auth::require_owner(&env);
+
+        storage::set(&env, DataClass::Config, &HashKey::LegacyHashesAllowed, &allowed);
+        ttl::extend_instance(&env);
+
+        audit::log_action(&env, symbol_short!("hash_dom"), &owner);
+
+        env.events().publish(
+            (symbol_short!("hash_dom"),),
+            (allowed, env.ledger().timestamp())
+        );
+    }
+
+    /// Check whether new registrations must use domain-separated hashes
+    pub fn is_domain_separation_required(env: Env) -> bool {
+        !storage::get(&env, DataClass::Config, &HashKey::LegacyHashesAllowed)
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+    #[test]
+    fn test_keccak_mode() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+
+        let preimage = Bytes::from_slice(&env, b"EMP-0001");
+        assert_eq!(client.get_hash_mode(), HashMode::Sha256);
+        assert_eq!(client.compute_staff_hash(&preimage), env.crypto().sha256(&preimage));
+
+        client.set_hash_mode(&HashMode::Keccak256);
+        assert_eq!(client.compute_staff_hash(&preimage), env.crypto().keccak256(&preimage));
+        assert_ne!(client.compute_staff_hash(&preimage), env.crypto().sha256(&preimage));
+    }
+
+    #[test]
+    fn test_domain_separated_registration() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        assert!(client.is_domain_separation_required());
+        client.register_org(&symbol_short!("lagos"), &owner);
+
+        let preimage = Bytes::from_slice(&env, b"salted-commitment");
+        let main = Symbol::new(&env, "");
+        let staff_hash = client.register_staff_v2(&owner, &preimage);
+        assert!(client.is_staff_active(&staff_hash));
+        assert_eq!(client.compute_staff_hash_v2(&preimage, &main, &client.get_chain_domain()), staff_hash);
+
+        // Another network, namespace or deployment derives a different hash
+        let testnet = BytesN::from_array(&env, &[7u8; 32]);
+        assert_ne!(client.compute_staff_hash_v2(&preimage, &main, &testnet), staff_hash);
+        let org_hash = client.org_register_staff_v2(&symbol_short!("lagos"), &preimage);
+        assert_ne!(org_hash, staff_hash);
+        assert!(client.org_is_staff_active(&symbol_short!("lagos"), &org_hash));
+        assert_ne!(client.compute_staff_hash(&preimage), staff_hash);
+    }
+
+    #[test]
+    fn test_payroll_verify_matches_contract() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_hash_mode(&HashMode::Keccak256);
+        let mode = payroll_verify::HashMode::Keccak256;
+
+        // Contract address XDR is type tags followed by the 32-byte contract id
+        let mut contract = [0u8; 32];
+        contract_id.clone().to_xdr(&env).slice(8..40).copy_into_slice(&mut contract);
+        let domain = [7u8; 32];
+        for org_id in ["", "lagos", "abuja_branch"] {
+            let expected = payroll_verify::staff_hash_v2(mode, b"EMP-0001", org_id, &domain, &contract).unwrap();
+            let hash = client.compute_staff_hash_v2(&Bytes::from_slice(&env, b"EMP-0001"), &Symbol::new(&env, org_id), &BytesN::from_array(&env, &domain));
+            assert_eq!(hash.to_array(), expected);
+        }
+        assert_eq!(
+            client.compute_staff_hash(&Bytes::from_slice(&env, b"EMP-0001")).to_array(),
+            payroll_verify::staff_hash(mode, b"EMP-0001")
+        );
+
+        // Roots and proofs built off-chain verify on-chain
+        let rows: std::vec::Vec<[u8; 32]> = (1..=5u8).map(|i| payroll_verify::payslip_leaf(mode, &[i; 32], 1_000 * i as i128)).collect();
+        let tree = payroll_verify::MerkleTree::new(mode, rows.clone()).unwrap();
+        let batch_hash = BytesN::from_array(&env, &[9u8; 32]);
+        client.record_payroll_batch_with_root(&owner, &batch_hash, &5, &BytesN::from_array(&env, &tree.root()));
+        for (index, leaf) in rows.iter().enumerate() {
+            let mut proof = Vec::new(&env);
+            for sibling in tree.proof(index).unwrap() {
+                proof.push_back(BytesN::from_array(&env, &sibling));
+            }
+            assert!(client.verify_inclusion(&batch_hash, &BytesN::from_array(&env, leaf), &proof));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Domain-separated hash required")]
+    fn test_raw_hash_rejected_when_required() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        // Required by default: no opt-out has been made
+        env.as_contract(&contract_id, || {
+            StaffRegistry::register_staff(env.clone(), owner, BytesN::from_array(&env, &[1u8; 32]))
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Hash mode locked")]
+    fn test_mode_locked_after_batch_root() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.record_payroll_batch_with_root(
+            &owner,
+            &BytesN::from_array(&env, &[2u8; 32]),
+            &1,
+            &BytesN::from_array(&env, &[3u8; 32]),
+        );
+
+        env.as_contract(&contract_id, || StaffRegistry::set_hash_mode(env.clone(), HashMode::Keccak256));
+    }
+}
@@ -0,0 +1,246 @@
+use soroban_sdk::{contractimpl, contracttype, BytesN, Env, IntoVal, Symbol, TryFromVal, Val, Vec};
+
+use super::*;
+
+// One stored revision of a staff record
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StaffRecordVersion {
+    pub version: u32,
+    pub recorded_at: u64,
+    pub record: StaffRecord,
+}
+
+// One stored revision of a payroll batch
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PayrollBatchVersion {
+    pub version: u32,
+    pub recorded_at: u64,
+    pub batch: PayrollBatch,
+}
+
+#[contracttype]
+enum HistoryKey {
+    StaffRecordV(BytesN<32>, u32),   // (staff, version) revision, from 1
+    StaffVersionCount(BytesN<32>),
+    PayrollBatchV(BytesN<32>, u32),  // (batch, version) revision, from 1
+    BatchVersionCount(BytesN<32>),
+    OrgStaffRecordV(Symbol, BytesN<32>, u32),   // (org, staff, version) revision, from 1
+    OrgStaffVersionCount(Symbol, BytesN<32>),
+    OrgPayrollBatchV(Symbol, BytesN<32>, u32),  // (org, batch, version) revision, from 1
+    OrgBatchVersionCount(Symbol, BytesN<32>),
+}
+
+fn staff_count_key(scope: &Scope, staff_hash: &BytesN<32>) -> HistoryKey {
+    match scope {
+        Scope::Main => HistoryKey::StaffVersionCount(staff_hash.clone()),
+        Scope::Org(org_id) => HistoryKey::OrgStaffVersionCount(org_id.clone(), staff_hash.clone()),
+    }
+}
+
+fn staff_version_key(scope: &Scope, staff_hash: &BytesN<32>, version: u32) -> HistoryKey {
+    match scope {
+        Scope::Main => HistoryKey::StaffRecordV(staff_hash.clone(), version),
+        Scope::Org(org_id) => HistoryKey::OrgStaffRecordV(org_id.clone(), staff_hash.clone(), version),
+    }
+}
+
+fn batch_count_key(scope: &Scope, batch_hash: &BytesN<32>) -> HistoryKey {
+    match scope {
+        Scope::Main => HistoryKey::BatchVersionCount(batch_hash.clone()),
+        Scope::Org(org_id) => HistoryKey::OrgBatchVersionCount(org_id.clone(), batch_hash.clone()),
+    }
+}
+
+fn batch_version_key(scope: &Scope, batch_hash: &BytesN<32>, version: u32) -> HistoryKey {
+    match scope {
+        Scope::Main => HistoryKey::PayrollBatchV(batch_hash.clone(), version),
+        Scope::Org(org_id) => HistoryKey::OrgPayrollBatchV(org_id.clone(), batch_hash.clone(), version),
+    }
+}
+
+fn append<T: IntoVal<Env, Val>>(env: &Env, count_key: HistoryKey, version_key: impl Fn(u32) -> HistoryKey, entry: impl Fn(u32) -> T) {
+    let version = storage::get::<HistoryKey, u32>(env, DataClass::Records, &count_key).unwrap_or(0) + 1;
+    let key = version_key(version);
+    storage::set(env, DataClass::Records, &key, &entry(version));
+    storage::set(env, DataClass::Records, &count_key, &version);
+}
+
+fn page<T>(env: &Env, count_key: HistoryKey, version_key: impl Fn(u32) -> HistoryKey, start: u32, limit: u32) -> Vec<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let count: u32 = storage::get(env, DataClass::Records, &count_key).unwrap_or(0);
+    let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+    let mut page = Vec::new(env);
+    for index in start..end {
+        if let Some(entry) = storage::get(env, DataClass::Records, &version_key(index + 1)) {
+            page.push_back(entry);
+        }
+    }
+    page
+}
+
+// Write the current staff record and append it to its change trail. Records
+// that predate versioning get their prior state captured as the first revision.
+pub(crate) fn save_staff_record(env: &Env, scope: &Scope, record: &StaffRecord) {
+    let staff_hash = record.staff_hash.clone();
+    let key = scope.staff_key(&staff_hash);
+    let count_key = || staff_count_key(scope, &staff_hash);
+    let version_key = |version| staff_version_key(scope, &staff_hash, version);
+
+    if !storage::has(env, DataClass::Records, &count_key()) {
+        if let Some(previous) = upgrade::read_staff_record(env, &key) {
+            append(env, count_key(), version_key, |version| StaffRecordVersion {
+                version,
+                recorded_at: previous.registered_at,
+                record: previous.clone(),
+            });
+        }
+    }
+    append(env, count_key(), version_key, |version| StaffRecordVersion {
+        version,
+        recorded_at: env.ledger().timestamp(),
+        record: record.clone(),
+    });
+
+    storage::set(env, DataClass::Records, &key, record);
+}
+
+// Drop a staff hash's whole change trail (right-to-erasure)
+pub(crate) fn erase_staff_history(env: &Env, staff_hash: &BytesN<32>) {
+    let count_key = HistoryKey::StaffVersionCount(staff_hash.clone());
+    let count: u32 = storage::get(env, DataClass::Records, &count_key).unwrap_or(0);
+    for version in 1..=count {
+        storage::remove(env, DataClass::Records, &HistoryKey::StaffRecordV(staff_hash.clone(), version));
+    }
+    // Keep an explicit zero so the next save doesn't re-capture the stored record
+    storage::set(env, DataClass::Records, &count_key, &0u32);
+}
+
+// Write the current payroll batch and append it to its change trail
+pub(crate) fn save_payroll_batch(env: &Env, scope: &Scope, batch: &PayrollBatch) {
+    let batch_hash = batch.batch_hash.clone();
+    let key = scope.batch_key(&batch_hash);
+    let count_key = || batch_count_key(scope, &batch_hash);
+    let version_key = |version| batch_version_key(scope, &batch_hash, version);
+
+    if !storage::has(env, DataClass::Records, &count_key()) {
+        if let Some(previous) = upgrade::read_payroll_batch(env, &key) {
+            append(env, count_key(), version_key, |version| PayrollBatchVersion {
+                version,
+                recorded_at: previous.timestamp,
+                batch: previous.clone(),
+            });
+        }
+    }
+    append(env, count_key(), version_key, |version| PayrollBatchVersion {
+        version,
+        recorded_at: env.ledger().timestamp(),
+        batch: batch.clone(),
+    });
+
+    storage::set(env, DataClass::Records, &key, batch);
+}
+
+fn staff_history(env: &Env, scope: &Scope, staff_hash: &BytesN<32>, start: u32, limit: u32) -> Vec<StaffRecordVersion> {
+    page(env, staff_count_key(scope, staff_hash), |version| staff_version_key(scope, staff_hash, version), start, limit)
+}
+
+fn batch_history(env: &Env, scope: &Scope, batch_hash: &BytesN<32>, start: u32, limit: u32) -> Vec<PayrollBatchVersion> {
+    page(env, batch_count_key(scope, batch_hash), |version| batch_version_key(scope, batch_hash, version), start, limit)
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Get a page of a staff record's revisions, oldest first. A rotated staff
+    /// member's earlier revisions stay under the hash they were made under.
+    pub fn get_staff_record_history(env: Env, staff_hash: BytesN<32>, start: u32, limit: u32) -> Vec<StaffRecordVersion> {
+        staff_history(&env, &Scope::Main, &staff_hash, start, limit)
+    }
+
+    /// Get a page of a payroll batch's revisions, oldest first
+    pub fn get_batch_history(env: Env, batch_hash: BytesN<32>, start: u32, limit: u32) -> Vec<PayrollBatchVersion> {
+        batch_history(&env, &Scope::Main, &batch_hash, start, limit)
+    }
+
+    /// Get a page of a staff record's revisions in an organization's namespace, oldest first
+    pub fn org_get_staff_record_history(env: Env, org_id: Symbol, staff_hash: BytesN<32>, start: u32, limit: u32) -> Vec<StaffRecordVersion> {
+        staff_history(&env, &Scope::Org(org_id), &staff_hash, start, limit)
+    }
+
+    /// Get a page of a payroll batch's revisions in an organization's namespace, oldest first
+    pub fn org_get_batch_history(env: Env, org_id: Symbol, batch_hash: BytesN<32>, start: u32, limit: u32) -> Vec<PayrollBatchVersion> {
+        batch_history(&env, &Scope::Org(org_id), &batch_hash, start, limit)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Address, Env, String, Symbol};
+
+    #[test]
+    fn test_staff_record_history() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        client.set_staff_metadata(&owner, &staff_hash, &String::from_str(&env, "ipfs://v2"));
+        client.revoke_staff(&owner, &staff_hash);
+
+        let history = client.get_staff_record_history(&staff_hash, &0, &10);
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.get(0).unwrap().record.status, StaffStatus::Active);
+        assert_eq!(history.get(0).unwrap().record.metadata_uri, String::from_str(&env, ""));
+        assert_eq!(history.get(1).unwrap().record.metadata_uri, String::from_str(&env, "ipfs://v2"));
+        assert_eq!(history.get(2).unwrap().version, 3);
+        assert_eq!(history.get(2).unwrap().record, client.get_staff_record(&staff_hash));
+        assert_eq!(client.get_staff_record_history(&staff_hash, &2, &10).len(), 1);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &1);
+        client.invalidate_batch(&owner, &batch_hash, &Symbol::new(&env, "duplicate"));
+        let batch_history = client.get_batch_history(&batch_hash, &0, &10);
+        assert_eq!(batch_history.len(), 2);
+        assert!(batch_history.get(0).unwrap().batch.is_valid);
+        assert!(!batch_history.get(1).unwrap().batch.is_valid);
+    }
+
+    #[test]
+    fn test_history_captures_pre_versioning_state() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+
+        // Simulate a record written before history was kept
+        env.as_contract(&contract_id, || {
+            env.storage().persistent().remove(&HistoryKey::StaffVersionCount(staff_hash.clone()));
+            env.storage().persistent().remove(&HistoryKey::StaffRecordV(staff_hash.clone(), 1));
+        });
+        client.revoke_staff(&owner, &staff_hash);
+
+        let history = client.get_staff_record_history(&staff_hash, &0, &10);
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.get(0).unwrap().record.status, StaffStatus::Active);
+        assert_eq!(history.get(1).unwrap().record.status, StaffStatus::Terminated);
+    }
+}
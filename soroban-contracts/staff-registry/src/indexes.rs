@@ -0,0 +1,255 @@
+use soroban_sdk::{contractimpl, contracttype, Address, BytesN, Env, Vec};
+
+use super::*;
+
+// Width of one index bucket, in seconds
+pub const INDEX_BUCKET_SECONDS: u64 = 86_400;
+
+// Upper bound on buckets (days) a single range query may scan
+pub const MAX_RANGE_DAYS: u64 = 366;
+
+#[contracttype]
+enum IndexKey {
+    StaffByDay(u64),            // staff hashes registered on a day (timestamp / 86400)
+    BatchesByDay(u64),          // batch hashes recorded on a day
+    BatchesByUploader(Address), // batch hashes recorded by an address
+    StaffByRegistrar(Address),  // staff hashes registered by an address
+}
+
+// Index a newly registered staff member under its registration day and registrar
+pub(crate) fn index_staff(env: &Env, record: &StaffRecord) {
+    for key in staff_buckets(record) {
+        lists::keyed_push_hash(env, DataClass::Indexes, &key, &record.staff_hash);
+    }
+}
+
+fn staff_buckets(record: &StaffRecord) -> [IndexKey; 2] {
+    [
+        IndexKey::StaffByDay(record.registered_at / INDEX_BUCKET_SECONDS),
+        IndexKey::StaffByRegistrar(record.registered_by.clone()),
+    ]
+}
+
+// Swap a rotated staff hash for its replacement in the buckets it was indexed under
+pub(crate) fn rekey_staff(env: &Env, record: &StaffRecord, old_hash: &BytesN<32>) {
+    for key in staff_buckets(record) {
+        lists::keyed_replace_hash(env, DataClass::Indexes, &key, old_hash, &record.staff_hash);
+    }
+}
+
+// Most recent batch recorded by an address
+pub(crate) fn last_batch_by(env: &Env, uploader: &Address) -> Option<BytesN<32>> {
+    lists::keyed_last(env, DataClass::Indexes, &IndexKey::BatchesByUploader(uploader.clone()))
+}
+
+// Index a newly recorded batch under its day and its uploader
+pub(crate) fn index_batch(env: &Env, batch: &PayrollBatch) {
+    lists::keyed_push(env, DataClass::Indexes, &IndexKey::BatchesByDay(batch.timestamp / INDEX_BUCKET_SECONDS), &batch.batch_hash);
+    lists::keyed_push(env, DataClass::Indexes, &IndexKey::BatchesByUploader(batch.uploaded_by.clone()), &batch.batch_hash);
+}
+
+// Walk the day buckets covering [from_ts, to_ts], keeping hashes whose own
+// timestamp falls in range, and return the requested page of matches
+fn page_between(
+    env: &Env,
+    bucket_key: impl Fn(u64) -> IndexKey,
+    timestamp_of: impl Fn(&BytesN<32>) -> u64,
+    from_ts: u64,
+    to_ts: u64,
+    start: u32,
+    limit: u32,
+) -> Vec<BytesN<32>> {
+    if from_ts > to_ts {
+        panic!("Invalid range");
+    }
+    let (first_day, last_day) = (from_ts / INDEX_BUCKET_SECONDS, to_ts / INDEX_BUCKET_SECONDS);
+    if last_day - first_day >= MAX_RANGE_DAYS {
+        panic!("Range too large");
+    }
+
+    let limit = limit.min(MAX_PAGE_SIZE);
+    let mut page = Vec::new(env);
+    let mut skipped = 0;
+
+    for day in first_day..=last_day {
+        let key = bucket_key(day);
+        let len = lists::keyed_len(env, DataClass::Indexes, &key);
+
+        // Only the edge buckets can hold entries outside the range; inner buckets
+        // are skipped or sliced by position without reading their entries
+        if day != first_day && day != last_day {
+            let offset = (start - skipped).min(len);
+            skipped += offset;
+            let wanted = limit - page.len();
+            page.append(&lists::keyed_range(env, DataClass::Indexes, &key, offset, offset.saturating_add(wanted)));
+            if page.len() >= limit {
+                return page;
+            }
+            continue;
+        }
+
+        let bucket: Vec<BytesN<32>> = lists::keyed_range(env, DataClass::Indexes, &key, 0, len);
+        for hash in bucket.iter() {
+            let ts = timestamp_of(&hash);
+            if ts < from_ts || ts > to_ts {
+                continue;
+            }
+            if skipped < start {
+                skipped += 1;
+                continue;
+            }
+            if page.len() >= limit {
+                return page;
+            }
+            page.push_back(hash);
+        }
+    }
+
+    page
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Get a page of staff hashes registered between two timestamps (inclusive)
+    pub fn get_staff_registered_between(env: Env, from_ts: u64, to_ts: u64, start: u32, limit: u32) -> Vec<BytesN<32>> {
+        page_between(
+            &env,
+            IndexKey::StaffByDay,
+            |hash| Self::get_staff_record(env.clone(), hash.clone()).registered_at,
+            from_ts,
+            to_ts,
+            start,
+            limit,
+        )
+    }
+
+    /// Get a page of batch hashes recorded between two timestamps (inclusive)
+    pub fn get_batches_between(env: Env, from_ts: u64, to_ts: u64, start: u32, limit: u32) -> Vec<BytesN<32>> {
+        page_between(
+            &env,
+            IndexKey::BatchesByDay,
+            |hash| Self::get_payroll_batch(env.clone(), hash.clone()).timestamp,
+            from_ts,
+            to_ts,
+            start,
+            limit,
+        )
+    }
+
+    /// Get a page of batch hashes recorded by an uploader, oldest first
+    pub fn get_batches_by_uploader(env: Env, uploader: Address, start: u32, limit: u32) -> Vec<BytesN<32>> {
+        lists::keyed_page(&env, DataClass::Indexes, &IndexKey::BatchesByUploader(uploader), start, limit)
+    }
+
+    /// Get a page of staff hashes registered by an address, oldest first
+    pub fn get_staff_by_registrar(env: Env, registrar: Address, start: u32, limit: u32) -> Vec<BytesN<32>> {
+        lists::keyed_page(&env, DataClass::Indexes, &IndexKey::StaffByRegistrar(registrar), start, limit)
+    }
+
+    /// Get how many staff members an address has registered
+    pub fn get_registrar_staff_count(env: Env, registrar: Address) -> u32 {
+        lists::keyed_len(&env, DataClass::Indexes, &IndexKey::StaffByRegistrar(registrar))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env};
+
+    #[test]
+    fn test_staff_registered_between() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        // Registered on days 10, 10 (later that day), 11 and 40
+        let times = [864_000u64, 900_000, 950_400, 3_456_000];
+        for (i, ts) in times.iter().enumerate() {
+            env.ledger().with_mut(|li| li.timestamp = *ts);
+            client.register_staff(&owner, &BytesN::from_array(&env, &[i as u8 + 1; 32]));
+        }
+
+        let found = client.get_staff_registered_between(&880_000, &1_000_000, &0, &10);
+        assert_eq!(found.len(), 2);
+        assert_eq!(found.get(0).unwrap(), BytesN::from_array(&env, &[2u8; 32]));
+        assert_eq!(found.get(1).unwrap(), BytesN::from_array(&env, &[3u8; 32]));
+
+        let all = client.get_staff_registered_between(&0, &3_500_000, &1, &2);
+        assert_eq!(all.len(), 2);
+        assert_eq!(all.get(0).unwrap(), BytesN::from_array(&env, &[2u8; 32]));
+    }
+
+    #[test]
+    fn test_batches_by_time_and_uploader() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let uploader = Address::generate(&env);
+        client.initialize(&owner);
+        client.grant_role(&uploader, &Role::PayrollUploader);
+
+        let july = BytesN::from_array(&env, &[1u8; 32]);
+        let august = BytesN::from_array(&env, &[2u8; 32]);
+        let september = BytesN::from_array(&env, &[3u8; 32]);
+
+        env.ledger().with_mut(|li| li.timestamp = 1_719_792_000);
+        client.record_payroll_batch(&uploader, &july, &4);
+        env.ledger().with_mut(|li| li.timestamp = 1_722_470_400);
+        client.record_payroll_batch(&owner, &august, &4);
+        env.ledger().with_mut(|li| li.timestamp = 1_725_148_800);
+        client.record_payroll_batch(&uploader, &september, &4);
+
+        let found = client.get_batches_between(&1_722_470_400, &1_725_148_800, &0, &10);
+        assert_eq!(found, Vec::from_array(&env, [august, september.clone()]));
+
+        let by_uploader = client.get_batches_by_uploader(&uploader, &0, &10);
+        assert_eq!(by_uploader, Vec::from_array(&env, [july, september]));
+    }
+
+    #[test]
+    fn test_staff_by_registrar() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let registrar = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+        client.grant_role(&registrar, &Role::Registrar);
+
+        let first = BytesN::from_array(&env, &[1u8; 32]);
+        let second = BytesN::from_array(&env, &[2u8; 32]);
+        client.register_staff(&registrar, &first);
+        client.register_staff(&owner, &BytesN::from_array(&env, &[3u8; 32]));
+        client.register_staff(&registrar, &second);
+
+        assert_eq!(client.get_registrar_staff_count(&registrar), 2);
+        assert_eq!(client.get_registrar_staff_count(&owner), 1);
+        assert_eq!(client.get_staff_by_registrar(&registrar, &1, &10), Vec::from_array(&env, [second]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Range too large")]
+    fn test_staff_range_too_large() {
+        let env = Env::default();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        env.as_contract(&contract_id, || {
+            StaffRegistry::get_staff_registered_between(env.clone(), 0, 400 * INDEX_BUCKET_SECONDS, 0, 10)
+        });
+    }
+}
@@ -0,0 +1,78 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Env, String, Symbol, Vec};
+
+use super::*;
+
+// What a deployment is and supports, for wallets, explorers and the Ethereum-side tooling
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContractInfo {
+    pub name: String,
+    pub version: String,       // semantic version of the contract code
+    pub storage_version: u32,  // storage layout version on ledger, see CONTRACT_VERSION
+    pub features: Vec<Symbol>,
+    pub hash_mode: HashMode,
+}
+
+// Optional subsystems built into this contract; append only, so tooling can
+// rely on a flag meaning the same thing across versions
+fn features(env: &Env) -> Vec<Symbol> {
+    Vec::from_array(
+        env,
+        [
+            symbol_short!("merkle"),
+            symbol_short!("payouts"),
+            symbol_short!("disputes"),
+            symbol_short!("challenge"),
+            symbol_short!("escrow"),
+            symbol_short!("streams"),
+            symbol_short!("claims"),
+            symbol_short!("fx"),
+            symbol_short!("sealed"),
+            symbol_short!("orgs"),
+            symbol_short!("multisig"),
+            symbol_short!("eth_admin"),
+            symbol_short!("rotation"),
+            symbol_short!("erasure"),
+            symbol_short!("onboard"),
+            symbol_short!("hash_v2"),
+            symbol_short!("salts"),
+        ],
+    )
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Describe this deployment: name, code version, storage layout version,
+    /// supported features and hash mode
+    pub fn contract_info(env: Env) -> ContractInfo {
+        ContractInfo {
+            name: String::from_str(&env, env!("CARGO_PKG_NAME")),
+            version: String::from_str(&env, env!("CARGO_PKG_VERSION")),
+            storage_version: upgrade::stored_version(&env),
+            features: features(&env),
+            hash_mode: hashing::hash_mode(&env),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+    #[test]
+    fn test_contract_info() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+
+        let info = client.contract_info();
+        assert_eq!(info.name, String::from_str(&env, "staff-registry"));
+        assert_eq!(info.storage_version, CONTRACT_VERSION);
+        assert_eq!(info.hash_mode, HashMode::Sha256);
+        assert!(info.features.contains(symbol_short!("merkle")));
+    }
+}
@@ -0,0 +1,97 @@
+use soroban_sdk::{contractclient, BytesN, Env, Vec};
+
+use super::*;
+
+/// Stable read-only surface of the registry for other Soroban contracts
+/// (treasury, grants, benefits). Depend on this crate and call
+/// `StaffRegistryInterfaceClient::new(&env, &registry_id)` instead of hand-rolling
+/// invocations; functions are only ever added here, never changed or removed.
+#[contractclient(name = "StaffRegistryInterfaceClient")]
+pub trait StaffRegistryInterface {
+    /// Describe the deployment: versions, supported features and hash mode
+    fn contract_info(env: Env) -> ContractInfo;
+
+    /// Check whether a staff hash has been registered (including rotated-away hashes)
+    fn is_staff_registered(env: Env, staff_hash: BytesN<32>) -> bool;
+
+    /// Check whether a staff member is currently active
+    fn is_staff_active(env: Env, staff_hash: BytesN<32>) -> bool;
+
+    /// Get a staff record; panics with "Staff not found" if unregistered
+    fn get_staff_record(env: Env, staff_hash: BytesN<32>) -> StaffRecord;
+
+    /// Get a staff member's lifecycle status
+    fn get_staff_status(env: Env, staff_hash: BytesN<32>) -> StaffStatus;
+
+    /// Get the current hash for a staff member, given any hash they were registered under
+    fn get_current_staff_hash(env: Env, staff_hash: BytesN<32>) -> BytesN<32>;
+
+    /// Check whether a batch has been recorded
+    fn is_batch_recorded(env: Env, batch_hash: BytesN<32>) -> bool;
+
+    /// Get a payroll batch; panics with "Batch not found" if unrecorded
+    fn get_payroll_batch(env: Env, batch_hash: BytesN<32>) -> PayrollBatch;
+
+    /// Get the most recently recorded batch
+    fn get_latest_batch(env: Env) -> Option<PayrollBatch>;
+
+    /// Check whether a staff member is in a batch's membership list
+    fn is_staff_in_batch(env: Env, batch_hash: BytesN<32>, staff_hash: BytesN<32>) -> bool;
+
+    /// Verify a merkle inclusion proof against a batch's root
+    fn verify_inclusion(env: Env, batch_hash: BytesN<32>, leaf: BytesN<32>, proof: Vec<BytesN<32>>) -> bool;
+
+    /// Get the pay period (YYYYMM) a batch was recorded for
+    fn get_batch_period_id(env: Env, batch_hash: BytesN<32>) -> Option<u32>;
+
+    /// Check whether a pay period has a valid, unsuperseded batch
+    fn has_period_been_paid(env: Env, period_id: u32) -> bool;
+
+    /// Get the number of registered staff
+    fn get_total_staff(env: Env) -> u32;
+
+    /// Get the number of recorded batches
+    fn get_total_batches(env: Env) -> u32;
+
+    /// Check whether the registry is paused
+    fn is_paused(env: Env) -> bool;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+    #[test]
+    fn test_interface_client_matches_contract() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        client.record_batch_for_period(&owner, &batch_hash, &1, &202608);
+
+        let registry = StaffRegistryInterfaceClient::new(&env, &contract_id);
+        assert_eq!(registry.contract_info(), client.contract_info());
+        assert!(registry.is_staff_registered(&staff_hash));
+        assert!(registry.is_staff_active(&staff_hash));
+        assert_eq!(registry.get_staff_record(&staff_hash), client.get_staff_record(&staff_hash));
+        assert_eq!(registry.get_staff_status(&staff_hash), StaffStatus::Active);
+        assert_eq!(registry.get_current_staff_hash(&staff_hash), staff_hash);
+        assert!(registry.is_batch_recorded(&batch_hash));
+        assert_eq!(registry.get_latest_batch(), Some(registry.get_payroll_batch(&batch_hash)));
+        assert!(!registry.is_staff_in_batch(&batch_hash, &staff_hash));
+        assert_eq!(registry.get_batch_period_id(&batch_hash), Some(202608));
+        assert!(registry.has_period_been_paid(&202608));
+        assert_eq!((registry.get_total_staff(), registry.get_total_batches()), (1, 1));
+        assert!(!registry.is_paused());
+    }
+}
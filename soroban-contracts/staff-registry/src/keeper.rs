@@ -0,0 +1,125 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, token, Address, Env};
+
+use super::*;
+
+// Most challenge windows a single poke will announce
+pub const MAX_POKE_BATCHES: u32 = 20;
+
+// Incentive paid from the contract's own balance for a poke that did due work
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct KeeperReward {
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+enum KeeperKey {
+    KeeperReward,
+}
+
+// Keep the entries every upload reads alive: the chain head and any legacy lists
+fn bump_hot_entries(env: &Env) {
+    ttl::extend_instance(env);
+    for key in [DataKey::AllStaffHashes, DataKey::AllBatchHashes] {
+        storage::extend_to(env, DataClass::Indexes, &key, ttl::PERSISTENT_BUMP_AMOUNT);
+    }
+
+    if let Some(latest) = lists::last(env, lists::List::Batch) {
+        storage::extend_to(env, DataClass::Records, &DataKey::PayrollBatch(latest), ttl::PERSISTENT_BUMP_AMOUNT);
+    }
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Set the reward paid to keepers for useful pokes (owner only)
+    pub fn set_keeper_reward(env: Env, reward: KeeperReward) {
+        let owner = auth::require_owner(&env);
+        ttl::extend_instance(&env);
+
+        if reward.amount < 0 {
+            panic!("Invalid amount");
+        }
+        storage::set(&env, DataClass::Config, &KeeperKey::KeeperReward, &reward);
+
+        audit::log_action(&env, symbol_short!("kpr_rwd"), &owner);
+
+        env.events().publish(
+            (symbol_short!("kpr_rwd"),),
+            (reward.token, reward.amount, env.ledger().timestamp())
+        );
+    }
+
+    /// Get the keeper reward, if one is configured
+    pub fn get_keeper_reward(env: Env) -> Option<KeeperReward> {
+        storage::get(&env, DataClass::Config, &KeeperKey::KeeperReward)
+    }
+
+    /// Run due housekeeping (callable by anyone): bump hot TTLs, announce closed challenge
+    /// windows and flag missed schedule periods. Returns the number of items handled;
+    /// the caller is rewarded when that is non-zero and the contract can cover it.
+    pub fn poke(env: Env, keeper: Address) -> u32 {
+        keeper.require_auth();
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        bump_hot_entries(&env);
+
+        let work = challenge::close_expired_windows(&env, MAX_POKE_BATCHES) + schedule::close_due_periods(&env);
+
+        // A failed or unfunded payout never blocks the maintenance itself
+        let mut paid = 0;
+        if work > 0 {
+            if let Some(reward) = Self::get_keeper_reward(env.clone()) {
+                let transfer = token::Client::new(&env, &reward.token)
+                    .try_transfer(&env.current_contract_address(), &keeper, &reward.amount);
+                if reward.amount > 0 && matches!(transfer, Ok(Ok(()))) {
+                    paid = reward.amount;
+                }
+            }
+        }
+
+        env.events().publish(
+            (symbol_short!("poke"), keeper),
+            (work, paid, env.ledger().timestamp())
+        );
+
+        work
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Ledger}, token::StellarAssetClient, BytesN, Env};
+
+    #[test]
+    fn test_poke_rewards_due_work() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.sequence_number = 100);
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let keeper = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_challenge_period(&10);
+
+        let token = env.register_stellar_asset_contract(Address::generate(&env));
+        StellarAssetClient::new(&env, &token).mint(&contract_id, &100);
+        client.set_keeper_reward(&KeeperReward { token: token.clone(), amount: 5 });
+
+        client.record_payroll_batch(&owner, &BytesN::from_array(&env, &[1u8; 32]), &3);
+        client.record_payroll_batch(&owner, &BytesN::from_array(&env, &[2u8; 32]), &3);
+
+        // Nothing is due while both windows are open
+        assert_eq!(client.poke(&keeper), 0);
+
+        env.ledger().with_mut(|li| li.sequence_number += 10);
+        assert_eq!(client.poke(&keeper), 2);
+        assert_eq!(client.poke(&keeper), 0);
+        assert_eq!(token::Client::new(&env, &token).balance(&keeper), 5);
+    }
+}
@@ -1,5 +1,140 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Vec, BytesN, Symbol, symbol_short};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, IntoVal, TryFromVal, Val, Vec, Bytes, BytesN, String, Symbol, symbol_short};
+
+mod acknowledgements;
+mod anchors;
+mod attestation;
+mod audit;
+mod auth;
+mod auditors;
+mod bands;
+mod bonds;
+mod budgets;
+mod challenge;
+mod checkpoints;
+mod claims;
+mod clawbacks;
+mod commitments;
+mod compliance;
+mod config;
+mod corrections;
+mod deductions;
+mod deltas;
+mod departments;
+mod disputes;
+mod distribution;
+mod documents;
+mod erasure;
+mod escrow;
+mod eth_admin;
+mod events;
+mod expiry;
+mod external_ids;
+mod flags;
+#[cfg(all(test, feature = "fuzz"))]
+mod fuzz;
+mod funds;
+mod fx;
+mod hashing;
+mod history;
+mod indexes;
+mod info;
+pub mod interface;
+mod keeper;
+mod lists;
+mod merkle;
+mod multisig;
+mod offboarding;
+mod onboarding;
+mod orgs;
+mod pausable;
+mod payments;
+mod periods;
+mod rate_limits;
+mod recovery;
+mod registry_tree;
+mod renounce;
+mod reports;
+mod roles;
+mod rotation;
+mod salts;
+mod salary;
+mod schedule;
+mod sealed;
+mod self_service;
+mod signers;
+mod staff_status;
+mod stats;
+mod storage;
+mod streams;
+mod subscribers;
+mod tags;
+#[cfg(any(test, feature = "testutils"))]
+pub mod testutils;
+mod timelock;
+mod tranches;
+mod ttl;
+mod upgrade;
+
+pub use anchors::ExternalAnchor;
+pub use audit::AuditEntry;
+pub use auditors::Auditor;
+pub use bands::SalaryBand;
+pub use bonds::{BondConfig, UploaderBond};
+pub use budgets::PeriodBudget;
+pub use challenge::BatchStatus;
+pub use checkpoints::Checkpoint;
+pub use claims::{Claim, ClaimSet};
+pub use clawbacks::SalaryClawback;
+pub use commitments::TotalOpening;
+pub use compliance::{ComplianceRecord, ComplianceStatus};
+pub use config::Config;
+pub use deltas::BatchDelta;
+pub use departments::Department;
+pub use disputes::{Dispute, DisputeStatus};
+pub use distribution::SalaryDistribution;
+pub use documents::{BatchDocument, EmploymentContract};
+pub use escrow::EscrowConfig;
+pub use funds::FundsCheck;
+pub use fx::{FxRate, FxVerification};
+pub use hashing::HashMode;
+pub use history::{PayrollBatchVersion, StaffRecordVersion};
+pub use info::ContractInfo;
+pub use keeper::KeeperReward;
+pub use multisig::OwnerSigners;
+pub use offboarding::{FinalSettlement, Offboarding};
+pub use onboarding::PendingRegistration;
+pub use orgs::Organization;
+pub use payments::{BatchFunding, Payout};
+pub use rate_limits::RateLimit;
+pub use recovery::RecoveryConfig;
+pub use reports::Report;
+pub use salts::SaltEpoch;
+pub use roles::Role;
+pub use salary::SalaryCommitment;
+pub use schedule::Schedule;
+pub use sealed::SealedPayload;
+pub use staff_status::StaffStatus;
+pub use stats::AggregateStats;
+pub use storage::{StoragePolicy, StorageTier};
+pub use streams::Stream;
+pub use timelock::{AdminAction, QueuedAction};
+pub use tranches::{DisbursementProgress, Tranche};
+pub use upgrade::CONTRACT_VERSION;
+
+use storage::DataClass;
+
+// Upper bound on page size for paginated getters
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+// Upper bound on entries accepted by bulk write calls
+pub const MAX_BULK_SIZE: u32 = 100;
+
+// Upper bound on off-chain document URI length (bytes)
+pub const MAX_URI_LENGTH: u32 = 256;
+
+// Upper bound on links followed by a single verify_chain call
+pub const MAX_CHAIN_WALK: u32 = 100;
 
 // Staff record - matches Ethereum StaffRecord struct
 #[contracttype]
@@ -8,7 +143,10 @@ pub struct StaffRecord {
     pub staff_hash: BytesN<32>,
     pub registered_by: Address,
     pub registered_at: u64,
-    pub is_active: bool,
+    pub status: StaffStatus,
+    pub reactivated_at: Option<u64>,
+    pub metadata_uri: String,       // off-chain (IPFS/Arweave) document pointer, empty if unset
+    pub employment_end_ts: Option<u64>, // contract end date; the record stops counting as active after it
 }
 
 // Payroll batch - matches Ethereum PayrollBatch struct
@@ -19,18 +157,342 @@ pub struct PayrollBatch {
     pub uploaded_by: Address,
     pub timestamp: u64,
     pub staff_count: u32,
+    pub merkle_root: BytesN<32>,    // zero when the batch carries no merkle root
+    pub supersedes: BytesN<32>,     // batch this one corrects, zero if none
+    pub superseded_by: BytesN<32>,  // correction that replaced this batch, zero if none
+    pub correction_reason: Symbol,  // empty unless this batch is a correction
+    pub is_valid: bool,             // false once publicly flagged as invalid
+    pub invalidation_reason: Symbol, // empty while the batch is valid
+    pub prev_batch_hash: BytesN<32>, // batch recorded just before this one, zero for the first
+}
+
+// Pay period metadata recorded alongside a batch
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchMetadata {
+    pub period_start: u64,
+    pub period_end: u64,
+    pub currency: Symbol,
+    pub total_amount_commitment: BytesN<32>,
+}
+
+// Headcount summary maintained by register/revoke/reactivate
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StaffStats {
+    pub total: u32,
+    pub active_count: u32,
+    pub suspended_count: u32,
+    pub on_leave_count: u32,
+    pub revoked_count: u32,    // terminated
+}
+
+// Role to hand out at deployment
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoleGrant {
+    pub account: Address,
+    pub role: Role,
+}
+
+// Genesis settings for initialize_with_config
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct InitConfig {
+    pub limits: Config,
+    pub role_grants: Vec<RoleGrant>,
+    pub schedule_period_ledgers: u32,   // 0 starts no schedule
+    pub schedule_expected_staff: u32,
+    pub hash_mode: HashMode,
+    pub storage_policy: StoragePolicy,
 }
 
-// Storage keys
+// Storage keys. Contract enums are capped at 50 cases, so newer subsystems
+// declare their own key enums next to the code that uses them. Keys are
+// encoded by variant name, so names must stay unique across all key enums.
 #[contracttype]
 pub enum DataKey {
     Owner,
     StaffRecord(BytesN<32>),          // staffRecords mapping
-    IsStaffRegistered(BytesN<32>),    // isStaffRegistered mapping
+    IsStaffRegistered(BytesN<32>),    // legacy isStaffRegistered flag, read-only since v8
     PayrollBatch(BytesN<32>),         // payrollBatches mapping
-    IsBatchRecorded(BytesN<32>),      // isBatchRecorded mapping
-    AllStaffHashes,                    // allStaffHashes array
-    AllBatchHashes,                    // allBatchHashes array
+    IsBatchRecorded(BytesN<32>),      // legacy isBatchRecorded flag, read-only since v8
+    AllStaffHashes,                    // pre-v7 allStaffHashes array, see lists.rs
+    AllBatchHashes,                    // pre-v7 allBatchHashes array, see lists.rs
+    PendingOwner,                      // pendingOwner (two-step transfer)
+    Role(Address),                     // roles granted to an address
+    Paused,                            // pause flag
+    Version,                           // storage layout version
+    BatchMember(BytesN<32>, BytesN<32>), // (batch, staff) membership flag
+    BatchHasMembers(BytesN<32>),       // batch was recorded with its member list
+    StaffBatches(BytesN<32>),          // batches a staff member appears in
+    BatchMetadata(BytesN<32>),         // pay period metadata per batch
+    BatchDocuments(BytesN<32>),        // supporting documents per batch
+    Department(Symbol),                // department by id
+    DepartmentStaff(Symbol),           // staff hashes assigned to a department
+    StaffDepartment(BytesN<32>),       // department a staff member belongs to
+    Org(Symbol),                       // hosted organization by id
+    OrgStaff(Symbol, BytesN<32>),      // staff record in an org namespace
+    OrgBatch(Symbol, BytesN<32>),      // payroll batch in an org namespace
+    OrgStaffHashes(Symbol),            // staff hashes per org
+    OrgBatchHashes(Symbol),            // batch hashes per org
+    SalaryCommitment(BytesN<32>),      // salary commit-reveal per staff
+    SalaryBand(Symbol),                // salary band by id
+    BandAttestation(BytesN<32>, BytesN<32>), // (batch, staff) attested band
+    BandHeadcount(BytesN<32>, Symbol), // (batch, band) attested headcount
+    DisputeCount,                      // last issued dispute id
+    Dispute(u64),                      // dispute by id
+    BatchDisputes(BytesN<32>),         // dispute ids filed against a batch
+    RoleMembers(Role),                 // addresses holding a role
+    AttestationThreshold,              // auditor attestations needed to finalize
+    BatchAttestations(BytesN<32>),     // auditors that attested a batch
+    BatchFinalizedAt(BytesN<32>),      // finalization timestamp per batch
+    BatchFunding(BytesN<32>),          // token funding held for a batch
+    BatchPayouts(BytesN<32>),          // disbursement outcomes per batch
+    EscrowConfig,                      // escrow release/refund timing
+    StreamCount,                       // last issued stream id
+    Stream(u64),                       // salary stream by id
+    Schedule,                          // active recurring payroll schedule
+    ScheduledBatch(u32),               // batch recorded for a schedule period
+    StaffAddress(BytesN<32>),          // employee wallet bound to a staff hash
+    PaymentAck(BytesN<32>, BytesN<32>), // (batch, staff) receipt acknowledgement time
+    HashMode,                          // digest for staff hashes and merkle nodes
+    StaffCounters,                     // (active, revoked) counts before v3, cleared by migrate
+    BatchSequence,                     // next expected sequenced batch submission
+}
+
+// Return the slice [start, start + limit) of a list, clamped to MAX_PAGE_SIZE
+fn paginate<T>(env: &Env, items: &Vec<T>, start: u32, limit: u32) -> Vec<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let mut page = Vec::new(env);
+    let end = start
+        .saturating_add(limit.min(MAX_PAGE_SIZE))
+        .min(items.len());
+
+    for i in start..end {
+        page.push_back(items.get_unchecked(i));
+    }
+
+    page
+}
+
+// Build an active staff record with default (empty) optional fields
+fn new_staff_record(env: &Env, registered_by: Address, staff_hash: BytesN<32>) -> StaffRecord {
+    StaffRecord {
+        staff_hash,
+        registered_by,
+        registered_at: env.ledger().timestamp(),
+        status: StaffStatus::Active,
+        reactivated_at: None,
+        metadata_uri: String::from_str(env, ""),
+        employment_end_ts: None,
+    }
+}
+
+// Namespace a record is written under: the deployment's own registry or a hosted
+// org. Org entries carry the org id as a key prefix, so the helpers below serve
+// both without their keys colliding.
+#[derive(Clone)]
+pub(crate) enum Scope {
+    Main,
+    Org(Symbol),
+}
+
+impl Scope {
+    fn staff_key(&self, staff_hash: &BytesN<32>) -> DataKey {
+        match self {
+            Scope::Main => DataKey::StaffRecord(staff_hash.clone()),
+            Scope::Org(org_id) => DataKey::OrgStaff(org_id.clone(), staff_hash.clone()),
+        }
+    }
+
+    fn batch_key(&self, batch_hash: &BytesN<32>) -> DataKey {
+        match self {
+            Scope::Main => DataKey::PayrollBatch(batch_hash.clone()),
+            Scope::Org(org_id) => DataKey::OrgBatch(org_id.clone(), batch_hash.clone()),
+        }
+    }
+}
+
+// Write a fresh staff record
+fn store_new_staff(env: &Env, scope: &Scope, caller: &Address, staff_hash: &BytesN<32>) {
+    let record = new_staff_record(env, caller.clone(), staff_hash.clone());
+
+    // Store record in staffRecords mapping
+    history::save_staff_record(env, scope, &record);
+    staff_status::count_new(env, scope, StaffStatus::Active);
+
+    match scope {
+        Scope::Main => {
+            indexes::index_staff(env, &record);
+            registry_tree::insert(env, staff_hash, true);
+            salts::tag_staff(env, staff_hash);
+
+            audit::log(env, symbol_short!("staff_reg"), caller, staff_hash);
+        }
+        Scope::Org(org_id) => {
+            lists::keyed_push(env, DataClass::Indexes, &DataKey::OrgStaffHashes(org_id.clone()), staff_hash);
+            salts::tag_org_staff(env, org_id, staff_hash);
+
+            audit::log(env, symbol_short!("org_sreg"), caller, staff_hash);
+        }
+    }
+}
+
+// Validate, store and list a single new staff member and emit the staff_reg event
+fn register_new_staff(env: &Env, caller: &Address, staff_hash: &BytesN<32>) {
+    // staffNotRegistered modifier
+    if flags::staff_exists(env, staff_hash) {
+        panic!("Staff already registered");
+    }
+
+    // require(_staffHash != bytes32(0))
+    let zero_hash = BytesN::from_array(env, &[0u8; 32]);
+    if *staff_hash == zero_hash {
+        panic!("Invalid staff hash");
+    }
+
+    rate_limits::consume_registrations(env, caller, 1);
+
+    // Create and store staff record
+    store_new_staff(env, &Scope::Main, caller, staff_hash);
+    list_staff_hash(env, staff_hash);
+
+    // Emit event
+    let record = load_staff_record(env, staff_hash).unwrap();
+    events::staff_event(env, symbol_short!("staff_reg"), caller, &record);
+}
+
+// Add to the registry-wide staff list
+fn list_staff_hash(env: &Env, staff_hash: &BytesN<32>) {
+    lists::push(env, lists::List::Staff, staff_hash);
+}
+
+// Look up a staff record without trapping when it is missing
+fn load_staff_record(env: &Env, staff_hash: &BytesN<32>) -> Option<StaffRecord> {
+    upgrade::read_staff_record(env, &DataKey::StaffRecord(rotation::resolve(env, staff_hash)))
+}
+
+// Look up a payroll batch without trapping when it is missing
+// Whether a batch's membership is known, so that is_staff_in_batch is authoritative for it
+fn has_member_list(env: &Env, batch_hash: &BytesN<32>) -> bool {
+    storage::has(env, DataClass::Indexes, &DataKey::BatchHasMembers(batch_hash.clone()))
+}
+
+fn load_payroll_batch(env: &Env, batch_hash: &BytesN<32>) -> Option<PayrollBatch> {
+    upgrade::read_payroll_batch(env, &DataKey::PayrollBatch(batch_hash.clone()))
+}
+
+// Build a batch record with default (empty) optional fields
+fn new_batch(env: &Env, uploaded_by: Address, batch_hash: BytesN<32>, staff_count: u32) -> PayrollBatch {
+    PayrollBatch {
+        batch_hash,
+        uploaded_by,
+        timestamp: env.ledger().timestamp(),
+        staff_count,
+        merkle_root: BytesN::from_array(env, &[0u8; 32]),
+        supersedes: BytesN::from_array(env, &[0u8; 32]),
+        superseded_by: BytesN::from_array(env, &[0u8; 32]),
+        correction_reason: Symbol::new(env, ""),
+        is_valid: true,
+        invalidation_reason: Symbol::new(env, ""),
+        prev_batch_hash: BytesN::from_array(env, &[0u8; 32]),
+    }
+}
+
+// Record a pending owner; shared by propose_owner and the timelocked action
+fn apply_propose_owner(env: &Env, owner: &Address, new_owner: Address) {
+    if new_owner == *owner {
+        panic!("Already owner");
+    }
+
+    storage::set(env, DataClass::Config, &DataKey::PendingOwner, &new_owner);
+
+    audit::log_action(env, symbol_short!("own_prop"), owner);
+
+    env.events().publish(
+        (symbol_short!("own_prop"),),
+        (owner.clone(), new_owner, env.ledger().timestamp())
+    );
+}
+
+// Validate and persist a new batch, link it to the previous one, index it and emit the batch_rec event
+fn store_new_batch(env: &Env, batch: &PayrollBatch) {
+    store_scoped_batch(env, &Scope::Main, batch);
+}
+
+// Validate and persist a new batch in either namespace. Limits, bonds, rate limits,
+// challenge tracking, history and stats apply alike; each namespace chains its own batches.
+fn store_scoped_batch(env: &Env, scope: &Scope, batch: &PayrollBatch) {
+    let batch_hash = batch.batch_hash.clone();
+    let mut batch = batch.clone();
+
+    // Signature-authorized uploads bypass require_role, so check the freeze here too
+    renounce::require_not_renounced(env);
+
+    // batchNotRecorded
+    let recorded = match scope {
+        Scope::Main => flags::batch_exists(env, &batch_hash),
+        Scope::Org(_) => storage::has(env, DataClass::Records, &scope.batch_key(&batch_hash)),
+    };
+    if recorded {
+        panic!("Batch already recorded");
+    }
+
+    // require(_batchHash != bytes32(0))
+    let zero_hash = BytesN::from_array(env, &[0u8; 32]);
+    if batch_hash == zero_hash {
+        panic!("Invalid batch hash");
+    }
+
+    // require(_staffCount > 0)
+    if batch.staff_count == 0 {
+        panic!("Staff count must be greater than 0");
+    }
+
+    config::enforce_batch_limits(env, batch.staff_count);
+    bonds::require_bonded(env, &batch.uploaded_by);
+    rate_limits::consume_batch(env, &batch.uploaded_by);
+
+    // Hash-link to the latest batch so history can't be rewritten unnoticed
+    batch.prev_batch_hash = match scope {
+        Scope::Main => lists::last(env, lists::List::Batch),
+        Scope::Org(org_id) => lists::keyed_last(env, DataClass::Indexes, &DataKey::OrgBatchHashes(org_id.clone())),
+    }
+    .unwrap_or(zero_hash);
+
+    // Store in payrollBatches mapping
+    history::save_payroll_batch(env, scope, &batch);
+
+    challenge::track_batch(env, scope, &batch_hash);
+    stats::record_batch(env, scope, &batch);
+
+    match scope {
+        Scope::Main => {
+            // Add to the registry-wide batch list
+            lists::push(env, lists::List::Batch, &batch_hash);
+            indexes::index_batch(env, &batch);
+
+            // Emit event
+            audit::log(env, symbol_short!("batch_rec"), &batch.uploaded_by, &batch_hash);
+
+            events::batch_event(env, symbol_short!("batch_rec"), &batch.uploaded_by, &batch);
+
+            subscribers::notify_batch_recorded(env, &batch_hash, batch.staff_count);
+        }
+        Scope::Org(org_id) => {
+            lists::keyed_push(env, DataClass::Indexes, &DataKey::OrgBatchHashes(org_id.clone()), &batch_hash);
+
+            audit::log(env, symbol_short!("org_brec"), &batch.uploaded_by, &batch_hash);
+
+            env.events().publish(
+                (symbol_short!("org_brec"), org_id.clone(), batch_hash),
+                (batch.uploaded_by.clone(), env.ledger().timestamp(), batch.staff_count)
+            );
+        }
+    }
 }
 
 #[contract]
@@ -40,8 +502,21 @@ pub struct StaffRegistry;
 impl StaffRegistry {
     /// Initialize contract - similar to Solidity constructor
     pub fn initialize(env: Env, owner: Address) {
+        let config = InitConfig {
+            limits: Config { max_staff_per_batch: 0, max_batches_per_period: 0, min_batch_interval_ledgers: 0 },
+            role_grants: Vec::new(&env),
+            schedule_period_ledgers: 0,
+            schedule_expected_staff: 0,
+            hash_mode: HashMode::Sha256,
+            storage_policy: storage::default_policy(),
+        };
+        Self::initialize_with_config(env, owner, config);
+    }
+
+    /// Initialize with limits, pre-granted roles, a payroll schedule and the hash mode set at genesis
+    pub fn initialize_with_config(env: Env, owner: Address, config: InitConfig) {
         // Check if already initialized
-        if env.storage().instance().has(&DataKey::Owner) {
+        if storage::has(&env, DataClass::Config, &DataKey::Owner) {
             panic!("Already initialized");
         }
         
@@ -49,262 +524,665 @@ impl StaffRegistry {
         owner.require_auth();
         
         // Set owner
-        env.storage().instance().set(&DataKey::Owner, &owner);
-        
-        // Initialize empty arrays
-        let empty_staff: Vec<BytesN<32>> = Vec::new(&env);
-        let empty_batches: Vec<BytesN<32>> = Vec::new(&env);
-        
-        env.storage().persistent().set(&DataKey::AllStaffHashes, &empty_staff);
-        env.storage().persistent().set(&DataKey::AllBatchHashes, &empty_batches);
+        storage::set(&env, DataClass::Config, &DataKey::Owner, &owner);
+        storage::set(&env, DataClass::Config, &DataKey::Version, &CONTRACT_VERSION);
+        recovery::touch_owner(&env);
+        ttl::extend_instance(&env);
+
+        // Fix the storage layout before anything is written under it
+        storage::apply_policy(&env, &config.storage_policy);
+
+        // Start with empty staff and batch lists
+        lists::seal_legacy(&env);
+
+        storage::set(&env, DataClass::Config, &DataKey::HashMode, &config.hash_mode);
+        if config.limits != config::config(&env) {
+            config::apply_config(&env, &owner, config.limits);
+        }
+        for grant in config.role_grants.iter() {
+            roles::grant(&env, &owner, grant.account, grant.role);
+        }
+        if config.schedule_period_ledgers > 0 {
+            schedule::apply_schedule(&env, &owner, config.schedule_period_ledgers, config.schedule_expected_staff);
+        }
     }
 
     /// Get owner (like public owner variable in Solidity)
     pub fn owner(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Owner)
-            .unwrap_or_else(|| panic!("Not initialized"))
+        auth::owner(&env)
     }
 
     /// Register staff - matches registerStaff function
-    pub fn register_staff(env: Env, staff_hash: BytesN<32>) {
-        // onlyOwner modifier
-        let owner = Self::owner(env.clone());
-        owner.require_auth();
+    pub fn register_staff(env: Env, caller: Address, staff_hash: BytesN<32>) {
+        // onlyOwner or Registrar
+        auth::require_role(&env, &caller, Role::Registrar);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
 
-        // staffNotRegistered modifier
-        let is_registered_key = DataKey::IsStaffRegistered(staff_hash.clone());
-        if env.storage().persistent().get::<DataKey, bool>(&is_registered_key).unwrap_or(false) {
-            panic!("Staff already registered");
+        onboarding::require_direct_registration(&env);
+        hashing::require_legacy_hash_allowed(&env);
+
+        register_new_staff(&env, &caller, &staff_hash);
+    }
+
+    /// Register staff under a domain-separated hash derived on-chain from `preimage`
+    /// (the salted off-chain commitment, never raw identity data). Returns the staff hash.
+    pub fn register_staff_v2(env: Env, caller: Address, preimage: Bytes) -> BytesN<32> {
+        // onlyOwner or Registrar
+        auth::require_role(&env, &caller, Role::Registrar);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        onboarding::require_direct_registration(&env);
+
+        let staff_hash = hashing::local_staff_hash(&env, &hashing::main_registry_org(&env), &preimage);
+        register_new_staff(&env, &caller, &staff_hash);
+        staff_hash
+    }
+
+    /// Register many staff in one call; duplicates are skipped or rejected per `skip_duplicates`.
+    /// Returns the number of newly registered staff.
+    pub fn register_staff_batch(env: Env, caller: Address, hashes: Vec<BytesN<32>>, skip_duplicates: bool) -> u32 {
+        // onlyOwner or Registrar
+        auth::require_role(&env, &caller, Role::Registrar);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        onboarding::require_direct_registration(&env);
+        hashing::require_legacy_hash_allowed(&env);
+
+        if hashes.is_empty() {
+            panic!("No staff hashes provided");
+        }
+        if hashes.len() > MAX_BULK_SIZE {
+            panic!("Too many staff hashes");
         }
 
-        // require(_staffHash != bytes32(0))
         let zero_hash = BytesN::from_array(&env, &[0u8; 32]);
-        if staff_hash == zero_hash {
-            panic!("Invalid staff hash");
-        }
+        let mut registered: u32 = 0;
 
-        // Create staff record
-        let record = StaffRecord {
-            staff_hash: staff_hash.clone(),
-            registered_by: owner.clone(),
-            registered_at: env.ledger().timestamp(),
-            is_active: true,
-        };
+        for staff_hash in hashes.iter() {
+            if staff_hash == zero_hash {
+                panic!("Invalid staff hash");
+            }
 
-        // Store record in staffRecords mapping
-        env.storage()
-            .persistent()
-            .set(&DataKey::StaffRecord(staff_hash.clone()), &record);
-
-        // Set isStaffRegistered[_staffHash] = true
-        env.storage()
-            .persistent()
-            .set(&is_registered_key, &true);
-
-        // Add to allStaffHashes array
-        let mut all_staff: Vec<BytesN<32>> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::AllStaffHashes)
-            .unwrap_or(Vec::new(&env));
-        all_staff.push_back(staff_hash.clone());
-        env.storage().persistent().set(&DataKey::AllStaffHashes, &all_staff);
+            // Also catches duplicates within the same call, since records are written as we go
+            if flags::staff_exists(&env, &staff_hash) {
+                if skip_duplicates {
+                    continue;
+                }
+                panic!("Staff already registered");
+            }
 
-        // Emit event
+            store_new_staff(&env, &Scope::Main, &caller, &staff_hash);
+            list_staff_hash(&env, &staff_hash);
+            registered += 1;
+        }
+
+        rate_limits::consume_registrations(&env, &caller, registered);
+
+        // Emit one summary event
         env.events().publish(
-            (symbol_short!("staff_reg"), staff_hash.clone()),
-            (owner, env.ledger().timestamp())
+            (symbol_short!("staff_blk"),),
+            (caller, env.ledger().timestamp(), registered)
         );
+
+        registered
     }
 
     /// Revoke staff - matches revokeStaff function
-    pub fn revoke_staff(env: Env, staff_hash: BytesN<32>) {
-        // onlyOwner
-        let owner = Self::owner(env.clone());
-        owner.require_auth();
+    pub fn revoke_staff(env: Env, caller: Address, staff_hash: BytesN<32>) {
+        // onlyOwner or Registrar
+        auth::require_role(&env, &caller, Role::Registrar);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        rotation::require_current(&env, &staff_hash);
 
         // staffExists
-        let is_registered_key = DataKey::IsStaffRegistered(staff_hash.clone());
-        if !env.storage().persistent().get::<DataKey, bool>(&is_registered_key).unwrap_or(false) {
+        if !flags::staff_exists(&env, &staff_hash) {
             panic!("Staff not found");
         }
 
-        // Get and update record
-        let mut record: StaffRecord = env
-            .storage()
-            .persistent()
-            .get(&DataKey::StaffRecord(staff_hash.clone()))
-            .unwrap();
-        
-        record.is_active = false;
-        
-        env.storage()
-            .persistent()
-            .set(&DataKey::StaffRecord(staff_hash.clone()), &record);
+        // Get and update record; revoking twice is a no-op
+        let mut record = load_staff_record(&env, &staff_hash).unwrap();
+        if record.status != StaffStatus::Terminated {
+            staff_status::apply(&env, &Scope::Main, &mut record, StaffStatus::Terminated);
+        }
+
+        history::save_staff_record(&env, &Scope::Main, &record);
 
         // Emit event
-        env.events().publish(
-            (symbol_short!("staff_rev"), staff_hash.clone()),
-            (owner, env.ledger().timestamp())
-        );
+        audit::log(&env, symbol_short!("staff_rev"), &caller, &staff_hash);
+
+        events::staff_event(&env, symbol_short!("staff_rev"), &caller, &record);
+    }
+
+    /// Reactivate a previously revoked staff member (e.g. a rehire)
+    pub fn reactivate_staff(env: Env, caller: Address, staff_hash: BytesN<32>) {
+        // onlyOwner or Registrar
+        auth::require_role(&env, &caller, Role::Registrar);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        rotation::require_current(&env, &staff_hash);
+        erasure::require_not_erased(&env, &staff_hash);
+
+        // staffExists
+        if !flags::staff_exists(&env, &staff_hash) {
+            panic!("Staff not found");
+        }
+
+        let mut record = load_staff_record(&env, &staff_hash).unwrap();
+
+        if record.status == StaffStatus::Active {
+            panic!("Staff already active");
+        }
+
+        staff_status::apply(&env, &Scope::Main, &mut record, StaffStatus::Active);
+        record.reactivated_at = Some(env.ledger().timestamp());
+
+        history::save_staff_record(&env, &Scope::Main, &record);
+
+        // Emit event
+        audit::log(&env, symbol_short!("staff_rea"), &caller, &staff_hash);
+
+        events::staff_event(&env, symbol_short!("staff_rea"), &caller, &record);
+    }
+
+    /// Point a staff record at an (encrypted) off-chain employment document
+    pub fn set_staff_metadata(env: Env, caller: Address, staff_hash: BytesN<32>, uri: String) {
+        // onlyOwner or Registrar
+        auth::require_role(&env, &caller, Role::Registrar);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        rotation::require_current(&env, &staff_hash);
+        erasure::require_not_erased(&env, &staff_hash);
+
+        if uri.len() > MAX_URI_LENGTH {
+            panic!("URI too long");
+        }
+
+        let mut record = Self::get_staff_record(env.clone(), staff_hash.clone());
+        record.metadata_uri = uri;
+
+        history::save_staff_record(&env, &Scope::Main, &record);
+
+        audit::log(&env, symbol_short!("staff_uri"), &caller, &staff_hash);
+
+        events::staff_event(&env, symbol_short!("staff_uri"), &caller, &record);
     }
 
     /// Record payroll batch - matches recordPayrollBatch function
-    pub fn record_payroll_batch(env: Env, batch_hash: BytesN<32>, staff_count: u32) {
-        // onlyOwner
-        let owner = Self::owner(env.clone());
-        owner.require_auth();
+    pub fn record_payroll_batch(env: Env, caller: Address, batch_hash: BytesN<32>, staff_count: u32) {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        store_new_batch(&env, &new_batch(&env, caller, batch_hash, staff_count));
+    }
+
+    /// Record a payroll batch only if `expected_seq` is the next submission sequence,
+    /// so retried or out-of-order back-office submissions are rejected
+    pub fn record_payroll_batch_seq(env: Env, caller: Address, expected_seq: u64, batch_hash: BytesN<32>, staff_count: u32) {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        let next = Self::next_batch_sequence(env.clone());
+        if expected_seq != next {
+            panic!("Unexpected batch sequence");
+        }
+        storage::set(&env, DataClass::Config, &DataKey::BatchSequence, &(next + 1));
+
+        store_new_batch(&env, &new_batch(&env, caller, batch_hash, staff_count));
+    }
+
+    /// Get the sequence number the next record_payroll_batch_seq call must carry
+    pub fn next_batch_sequence(env: Env) -> u64 {
+        storage::get(&env, DataClass::Config, &DataKey::BatchSequence).unwrap_or(0)
+    }
+
+    /// Record a payroll batch committing to a merkle root of its payslip leaves
+    pub fn record_payroll_batch_with_root(
+        env: Env,
+        caller: Address,
+        batch_hash: BytesN<32>,
+        staff_count: u32,
+        merkle_root: BytesN<32>,
+    ) {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        if merkle_root == BytesN::from_array(&env, &[0u8; 32]) {
+            panic!("Invalid merkle root");
+        }
+
+        let mut batch = new_batch(&env, caller, batch_hash, staff_count);
+        batch.merkle_root = merkle_root;
+        store_new_batch(&env, &batch);
+        hashing::mark_root_published(&env);
+    }
+
+    /// Record a payroll batch together with the registered staff it pays
+    pub fn record_batch_with_members(env: Env, caller: Address, batch_hash: BytesN<32>, staff_hashes: Vec<BytesN<32>>) {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        if staff_hashes.len() > MAX_BULK_SIZE {
+            panic!("Too many staff hashes");
+        }
+
+        store_new_batch(&env, &new_batch(&env, caller, batch_hash.clone(), staff_hashes.len()));
+        storage::set(&env, DataClass::Indexes, &DataKey::BatchHasMembers(batch_hash.clone()), &true);
+
+        for staff_hash in staff_hashes.iter() {
+            if !Self::is_staff_active(env.clone(), staff_hash.clone()) {
+                panic!("Staff not active");
+            }
+
+            let member_key = DataKey::BatchMember(batch_hash.clone(), staff_hash.clone());
+            if storage::has(&env, DataClass::Indexes, &member_key) {
+                panic!("Duplicate batch member");
+            }
+            storage::set(&env, DataClass::Indexes, &member_key, &true);
 
-        // batchNotRecorded
-        let is_recorded_key = DataKey::IsBatchRecorded(batch_hash.clone());
-        if env.storage().persistent().get::<DataKey, bool>(&is_recorded_key).unwrap_or(false) {
-            panic!("Batch already recorded");
+            lists::keyed_push(&env, DataClass::Indexes, &DataKey::StaffBatches(staff_hash), &batch_hash);
         }
+    }
+
+    /// Record a payroll batch with pay period metadata
+    pub fn record_batch_with_metadata(
+        env: Env,
+        caller: Address,
+        batch_hash: BytesN<32>,
+        staff_count: u32,
+        metadata: BatchMetadata,
+    ) {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        if metadata.period_start >= metadata.period_end {
+            panic!("Invalid pay period");
+        }
+
+        store_new_batch(&env, &new_batch(&env, caller, batch_hash.clone(), staff_count));
+
+        storage::set(&env, DataClass::Records, &DataKey::BatchMetadata(batch_hash), &metadata);
+    }
+
+    /// Get the pay period metadata of a batch, if any was recorded
+    pub fn get_batch_metadata(env: Env, batch_hash: BytesN<32>) -> Option<BatchMetadata> {
+        storage::get(&env, DataClass::Records, &DataKey::BatchMetadata(batch_hash))
+    }
+
+    /// Check if a staff member is part of a batch's membership list
+    pub fn is_staff_in_batch(env: Env, batch_hash: BytesN<32>, staff_hash: BytesN<32>) -> bool {
+        storage::get(&env, DataClass::Indexes, &DataKey::BatchMember(batch_hash, staff_hash))
+            .unwrap_or(false)
+    }
+
+    /// Get a page of batches a staff member appears in, oldest first
+    pub fn get_batches_for_staff(env: Env, staff_hash: BytesN<32>, start: u32, limit: u32) -> Vec<BytesN<32>> {
+        lists::keyed_page(&env, DataClass::Indexes, &DataKey::StaffBatches(staff_hash), start, limit)
+    }
+
+    /// Get a page of the batches a staff member was paid in, oldest first, as full records
+    pub fn get_payment_history(env: Env, staff_hash: BytesN<32>, start: u32, limit: u32) -> Vec<PayrollBatch> {
+        let mut history = Vec::new(&env);
+        for batch_hash in Self::get_batches_for_staff(env.clone(), staff_hash, start, limit).iter() {
+            history.push_back(Self::get_payroll_batch(env.clone(), batch_hash));
+        }
+
+        history
+    }
+
+    /// Verify a payslip leaf is included in a batch's merkle tree
+    pub fn verify_inclusion(env: Env, batch_hash: BytesN<32>, leaf: BytesN<32>, proof: Vec<BytesN<32>>) -> bool {
+        let batch = Self::get_payroll_batch(env.clone(), batch_hash);
+
+        if batch.merkle_root == BytesN::from_array(&env, &[0u8; 32]) {
+            panic!("Batch has no merkle root");
+        }
+
+        merkle::verify_proof(&env, hashing::hash_mode(&env), &batch.merkle_root, &leaf, &proof)
+    }
+
+    /// Record a corrected batch that supersedes an earlier one; the old record is kept
+    pub fn supersede_batch(
+        env: Env,
+        caller: Address,
+        old_batch_hash: BytesN<32>,
+        new_batch_hash: BytesN<32>,
+        staff_count: u32,
+        reason: Symbol,
+    ) {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        let zero_hash = BytesN::from_array(&env, &[0u8; 32]);
+        let mut old_batch = Self::get_payroll_batch(env.clone(), old_batch_hash.clone());
+        if old_batch.superseded_by != zero_hash {
+            panic!("Batch already superseded");
+        }
+
+        let mut batch = new_batch(&env, caller.clone(), new_batch_hash.clone(), staff_count);
+        batch.supersedes = old_batch_hash.clone();
+        batch.correction_reason = reason.clone();
+        store_new_batch(&env, &batch);
+        periods::inherit_period(&env, &old_batch_hash, &new_batch_hash);
+
+        old_batch.superseded_by = new_batch_hash.clone();
+        history::save_payroll_batch(&env, &Scope::Main, &old_batch);
+
+        audit::log(&env, symbol_short!("batch_sup"), &caller, &old_batch_hash);
+
+        events::batch_event(&env, symbol_short!("batch_sup"), &caller, &old_batch);
+    }
 
-        // require(_batchHash != bytes32(0))
+    /// Get the full correction chain a batch belongs to, from the original to the latest
+    pub fn get_batch_lineage(env: Env, batch_hash: BytesN<32>) -> Vec<BytesN<32>> {
         let zero_hash = BytesN::from_array(&env, &[0u8; 32]);
-        if batch_hash == zero_hash {
-            panic!("Invalid batch hash");
+        let mut batch = Self::get_payroll_batch(env.clone(), batch_hash);
+
+        // Walk back to the original upload
+        let mut steps: u32 = 0;
+        while batch.supersedes != zero_hash {
+            steps += 1;
+            if steps > MAX_PAGE_SIZE {
+                panic!("Lineage too long");
+            }
+            batch = Self::get_payroll_batch(env.clone(), batch.supersedes);
         }
 
-        // require(_staffCount > 0)
-        if staff_count == 0 {
-            panic!("Staff count must be greater than 0");
+        // Then forward through every correction
+        let mut lineage = Vec::new(&env);
+        lineage.push_back(batch.batch_hash.clone());
+        while batch.superseded_by != zero_hash {
+            if lineage.len() > MAX_PAGE_SIZE {
+                panic!("Lineage too long");
+            }
+            lineage.push_back(batch.superseded_by.clone());
+            batch = Self::get_payroll_batch(env.clone(), batch.superseded_by);
         }
 
-        // Create batch record
-        let batch = PayrollBatch {
-            batch_hash: batch_hash.clone(),
-            uploaded_by: owner.clone(),
-            timestamp: env.ledger().timestamp(),
-            staff_count,
-        };
+        lineage
+    }
 
-        // Store in payrollBatches mapping
-        env.storage()
-            .persistent()
-            .set(&DataKey::PayrollBatch(batch_hash.clone()), &batch);
-
-        // Set isBatchRecorded[_batchHash] = true
-        env.storage()
-            .persistent()
-            .set(&is_recorded_key, &true);
-
-        // Add to allBatchHashes array
-        let mut all_batches: Vec<BytesN<32>> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::AllBatchHashes)
-            .unwrap_or(Vec::new(&env));
-        all_batches.push_back(batch_hash.clone());
-        env.storage().persistent().set(&DataKey::AllBatchHashes, &all_batches);
+    /// Publicly flag a batch as invalid without deleting it (owner or Auditor)
+    pub fn invalidate_batch(env: Env, caller: Address, batch_hash: BytesN<32>, reason: Symbol) {
+        // onlyOwner or Auditor
+        auth::require_role(&env, &caller, Role::Auditor);
 
-        // Emit event
-        env.events().publish(
-            (symbol_short!("batch_rec"), batch_hash.clone()),
-            (owner, env.ledger().timestamp(), staff_count)
-        );
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        let mut batch = Self::get_payroll_batch(env.clone(), batch_hash.clone());
+        if !batch.is_valid {
+            panic!("Batch already invalidated");
+        }
+
+        batch.is_valid = false;
+        batch.invalidation_reason = reason;
+
+        history::save_payroll_batch(&env, &Scope::Main, &batch);
+
+        audit::log(&env, symbol_short!("batch_inv"), &caller, &batch_hash);
+
+        events::batch_event(&env, symbol_short!("batch_inv"), &caller, &batch);
     }
 
     /// Check if staff is active - matches isStaffActive view function
     pub fn is_staff_active(env: Env, staff_hash: BytesN<32>) -> bool {
-        let is_registered_key = DataKey::IsStaffRegistered(staff_hash.clone());
-        let is_registered = env.storage()
-            .persistent()
-            .get::<DataKey, bool>(&is_registered_key)
-            .unwrap_or(false);
-
-        if !is_registered {
+        if !flags::staff_exists(&env, &staff_hash) {
             return false;
         }
 
-        let record: StaffRecord = env
-            .storage()
-            .persistent()
-            .get(&DataKey::StaffRecord(staff_hash))
-            .unwrap();
-
-        record.is_active
+        // Compatibility shim from before lifecycle statuses
+        expiry::is_active(&env, &load_staff_record(&env, &staff_hash).unwrap())
     }
 
     /// Get staff record - matches getStaffRecord view function
     pub fn get_staff_record(env: Env, staff_hash: BytesN<32>) -> StaffRecord {
-        let is_registered_key = DataKey::IsStaffRegistered(staff_hash.clone());
-        if !env.storage().persistent().get::<DataKey, bool>(&is_registered_key).unwrap_or(false) {
+        if !flags::staff_exists(&env, &staff_hash) {
             panic!("Staff not found");
         }
 
-        env.storage()
-            .persistent()
-            .get(&DataKey::StaffRecord(staff_hash))
-            .unwrap()
+        load_staff_record(&env, &staff_hash).unwrap()
     }
 
     /// Get payroll batch - matches getPayrollBatch view function
     pub fn get_payroll_batch(env: Env, batch_hash: BytesN<32>) -> PayrollBatch {
-        let is_recorded_key = DataKey::IsBatchRecorded(batch_hash.clone());
-        if !env.storage().persistent().get::<DataKey, bool>(&is_recorded_key).unwrap_or(false) {
+        if !flags::batch_exists(&env, &batch_hash) {
             panic!("Batch not found");
         }
 
-        env.storage()
-            .persistent()
-            .get(&DataKey::PayrollBatch(batch_hash))
-            .unwrap()
+        load_payroll_batch(&env, &batch_hash).unwrap()
     }
 
-    /// Check if staff is registered - matches isStaffRegistered mapping
-    pub fn is_staff_registered(env: Env, staff_hash: BytesN<32>) -> bool {
-        let key = DataKey::IsStaffRegistered(staff_hash);
-        env.storage().persistent().get(&key).unwrap_or(false)
+    /// Get the most recently recorded batch, the head of the hash chain
+    pub fn get_latest_batch(env: Env) -> Option<PayrollBatch> {
+        lists::last(&env, lists::List::Batch).and_then(|batch_hash| load_payroll_batch(&env, &batch_hash))
     }
 
-    /// Check if batch is recorded - matches isBatchRecorded mapping
-    pub fn is_batch_recorded(env: Env, batch_hash: BytesN<32>) -> bool {
-        let key = DataKey::IsBatchRecorded(batch_hash);
-        env.storage().persistent().get(&key).unwrap_or(false)
+    /// Check that `to` reaches `from` by following prev_batch_hash links
+    pub fn verify_chain(env: Env, from: BytesN<32>, to: BytesN<32>) -> bool {
+        let zero_hash = BytesN::from_array(&env, &[0u8; 32]);
+        let mut current = to;
+
+        for _ in 0..=MAX_CHAIN_WALK {
+            if current == from {
+                return Self::is_batch_recorded(env.clone(), from);
+            }
+            match load_payroll_batch(&env, &current) {
+                Some(batch) if batch.prev_batch_hash != zero_hash => current = batch.prev_batch_hash,
+                _ => return false,
+            }
+        }
+
+        panic!("Chain too long");
     }
 
-    /// Get total staff - matches getTotalStaff view function
-    pub fn get_total_staff(env: Env) -> u32 {
-        let all_staff: Vec<BytesN<32>> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::AllStaffHashes)
-            .unwrap_or(Vec::new(&env));
-        
-        all_staff.len()
+    /// Get a staff record, or None if the hash is not registered.
+    /// (Named find_* because the generated client reserves try_* for every entry point.)
+    pub fn find_staff_record(env: Env, staff_hash: BytesN<32>) -> Option<StaffRecord> {
+        load_staff_record(&env, &staff_hash)
     }
 
-    /// Get total batches - matches getTotalBatches view function
-    pub fn get_total_batches(env: Env) -> u32 {
-        let all_batches: Vec<BytesN<32>> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::AllBatchHashes)
-            .unwrap_or(Vec::new(&env));
-        
-        all_batches.len()
+    /// Get a payroll batch, or None if the hash is not recorded
+    pub fn find_payroll_batch(env: Env, batch_hash: BytesN<32>) -> Option<PayrollBatch> {
+        load_payroll_batch(&env, &batch_hash)
     }
 
-    /// Transfer ownership - matches transferOwnership function
-    pub fn transfer_ownership(env: Env, new_owner: Address) {
-        let owner = Self::owner(env.clone());
-        owner.require_auth();
+    /// Get many staff records in one call; unknown hashes yield None
+    pub fn get_staff_records(env: Env, hashes: Vec<BytesN<32>>) -> Vec<Option<StaffRecord>> {
+        if hashes.len() > MAX_PAGE_SIZE {
+            panic!("Too many hashes");
+        }
+
+        let mut records = Vec::new(&env);
+        for staff_hash in hashes.iter() {
+            records.push_back(load_staff_record(&env, &staff_hash));
+        }
+        records
+    }
+
+    /// Get many payroll batches in one call; unknown hashes yield None
+    pub fn get_payroll_batches(env: Env, hashes: Vec<BytesN<32>>) -> Vec<Option<PayrollBatch>> {
+        if hashes.len() > MAX_PAGE_SIZE {
+            panic!("Too many hashes");
+        }
+
+        let mut batches = Vec::new(&env);
+        for batch_hash in hashes.iter() {
+            batches.push_back(load_payroll_batch(&env, &batch_hash));
+        }
+        batches
+    }
+
+    /// Check if staff is registered - matches isStaffRegistered mapping
+    pub fn is_staff_registered(env: Env, staff_hash: BytesN<32>) -> bool {
+        flags::staff_exists(&env, &staff_hash)
+    }
+
+    /// Check if batch is recorded - matches isBatchRecorded mapping
+    pub fn is_batch_recorded(env: Env, batch_hash: BytesN<32>) -> bool {
+        flags::batch_exists(&env, &batch_hash)
+    }
+
+    /// Get total staff - matches getTotalStaff view function
+    pub fn get_total_staff(env: Env) -> u32 {
+        lists::len(&env, lists::List::Staff)
+    }
+
+    /// Get total, active and revoked headcounts
+    pub fn get_staff_stats(env: Env) -> StaffStats {
+        let counts = staff_status::status_counts(&env, &Scope::Main);
+        StaffStats {
+            total: Self::get_total_staff(env),
+            active_count: counts.get(StaffStatus::Active).unwrap_or(0),
+            suspended_count: counts.get(StaffStatus::Suspended).unwrap_or(0),
+            on_leave_count: counts.get(StaffStatus::OnLeave).unwrap_or(0),
+            revoked_count: counts.get(StaffStatus::Terminated).unwrap_or(0),
+        }
+    }
+
+    /// Get a page of staff hashes in registration order
+    pub fn get_staff_hashes(env: Env, start: u32, limit: u32) -> Vec<BytesN<32>> {
+        lists::page(&env, lists::List::Staff, start, limit)
+    }
+
+    /// Get total batches - matches getTotalBatches view function
+    pub fn get_total_batches(env: Env) -> u32 {
+        lists::len(&env, lists::List::Batch)
+    }
+
+    /// Get a page of batch hashes in recording order
+    pub fn get_batch_hashes(env: Env, start: u32, limit: u32) -> Vec<BytesN<32>> {
+        lists::page(&env, lists::List::Batch, start, limit)
+    }
+
+    /// Get a page of full batch records in recording order
+    pub fn get_batches(env: Env, start: u32, limit: u32) -> Vec<PayrollBatch> {
+        let mut batches = Vec::new(&env);
+
+        for batch_hash in Self::get_batch_hashes(env.clone(), start, limit).iter() {
+            batches.push_back(load_payroll_batch(&env, &batch_hash).unwrap());
+        }
+
+        batches
+    }
+
+    /// Transfer ownership - matches transferOwnership function
+    pub fn transfer_ownership(env: Env, new_owner: Address) {
+        let owner = auth::require_owner(&env);
+        timelock::require_not_timelocked(&env);
         
         // require(newOwner != address(0))
         new_owner.require_auth();
         
-        env.storage().instance().set(&DataKey::Owner, &new_owner);
+        storage::set(&env, DataClass::Config, &DataKey::Owner, &new_owner);
+        storage::remove(&env, DataClass::Config, &DataKey::PendingOwner);
+
+        audit::log_action(&env, symbol_short!("own_xfer"), &owner);
+
+        env.events().publish(
+            (symbol_short!("own_xfer"),),
+            (owner, new_owner, env.ledger().timestamp())
+        );
+    }
+
+    /// Propose a new owner - first step of a two-step transfer
+    pub fn propose_owner(env: Env, new_owner: Address) {
+        let owner = auth::require_owner(&env);
+        timelock::require_not_timelocked(&env);
+
+        apply_propose_owner(&env, &owner, new_owner);
+    }
+
+    /// Accept a pending ownership proposal - second step, signed by the proposed owner
+    pub fn accept_ownership(env: Env) {
+        let owner = auth::owner(&env);
+        let new_owner: Address = storage::get(&env, DataClass::Config, &DataKey::PendingOwner)
+            .unwrap_or_else(|| panic!("No pending owner"));
+
+        new_owner.require_auth();
+
+        storage::set(&env, DataClass::Config, &DataKey::Owner, &new_owner);
+        storage::remove(&env, DataClass::Config, &DataKey::PendingOwner);
+        recovery::touch_owner(&env);
+
+        audit::log_action(&env, symbol_short!("own_xfer"), &new_owner);
+
+        env.events().publish(
+            (symbol_short!("own_xfer"),),
+            (owner, new_owner, env.ledger().timestamp())
+        );
+    }
+
+    /// Cancel a pending ownership proposal
+    pub fn cancel_proposal(env: Env) {
+        let owner = auth::require_owner(&env);
+
+        let pending: Address = storage::get(&env, DataClass::Config, &DataKey::PendingOwner)
+            .unwrap_or_else(|| panic!("No pending owner"));
+
+        storage::remove(&env, DataClass::Config, &DataKey::PendingOwner);
+
+        audit::log_action(&env, symbol_short!("own_canc"), &owner);
+
+        env.events().publish(
+            (symbol_short!("own_canc"),),
+            (owner, pending, env.ledger().timestamp())
+        );
+    }
+
+    /// Get the pending owner, if a transfer has been proposed
+    pub fn pending_owner(env: Env) -> Option<Address> {
+        storage::get(&env, DataClass::Config, &DataKey::PendingOwner)
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use soroban_sdk::{testutils::Address as _, Env};
+    use soroban_sdk::{testutils::{Address as _, Ledger}, Env};
 
     #[test]
     fn test_initialize_and_register() {
@@ -318,11 +1196,12 @@ mod test {
         
         // Initialize
         client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
         assert_eq!(client.owner(), owner);
         
         // Register staff
         let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
-        client.register_staff(&staff_hash);
+        client.register_staff(&owner, &staff_hash);
         
         // Verify
         assert!(client.is_staff_registered(&staff_hash));
@@ -330,6 +1209,33 @@ mod test {
         assert_eq!(client.get_total_staff(), 1);
     }
 
+    #[test]
+    fn test_initialize_with_config() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let uploader = Address::generate(&env);
+        let limits = Config { max_staff_per_batch: 50, max_batches_per_period: 4, min_batch_interval_ledgers: 0 };
+        client.initialize_with_config(&owner, &InitConfig {
+            limits: limits.clone(),
+            role_grants: Vec::from_array(&env, [RoleGrant { account: uploader.clone(), role: Role::PayrollUploader }]),
+            schedule_period_ledgers: 100,
+            schedule_expected_staff: 10,
+            hash_mode: HashMode::Keccak256,
+            storage_policy: storage::default_policy(),
+        });
+
+        assert_eq!(client.owner(), owner);
+        assert_eq!(client.get_config(), limits);
+        assert_eq!(client.get_uploaders(), Vec::from_array(&env, [uploader]));
+        assert_eq!(client.get_schedule().period_length, 100);
+        assert_eq!(client.get_hash_mode(), HashMode::Keccak256);
+    }
+
     #[test]
     fn test_payroll_batch() {
         let env = Env::default();
@@ -342,9 +1248,589 @@ mod test {
         client.initialize(&owner);
         
         let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
-        client.record_payroll_batch(&batch_hash, &10);
+        client.record_payroll_batch(&owner, &batch_hash, &10);
         
         assert!(client.is_batch_recorded(&batch_hash));
         assert_eq!(client.get_total_batches(), 1);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_bulk_record_reads() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let known = BytesN::from_array(&env, &[1u8; 32]);
+        let unknown = BytesN::from_array(&env, &[9u8; 32]);
+        client.register_staff(&owner, &known);
+        client.record_payroll_batch(&owner, &known, &1);
+
+        let hashes = Vec::from_array(&env, [known.clone(), unknown]);
+
+        let records = client.get_staff_records(&hashes);
+        assert_eq!(records.len(), 2);
+        assert_eq!(records.get(0).unwrap().unwrap().staff_hash, known);
+        assert_eq!(records.get(1).unwrap(), None);
+
+        let batches = client.get_payroll_batches(&hashes);
+        assert_eq!(batches.get(0).unwrap().unwrap().staff_count, 1);
+        assert_eq!(batches.get(1).unwrap(), None);
+    }
+
+    #[test]
+    fn test_find_records() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        assert_eq!(client.find_staff_record(&staff_hash), None);
+        assert_eq!(client.find_payroll_batch(&batch_hash), None);
+
+        client.register_staff(&owner, &staff_hash);
+        client.record_payroll_batch(&owner, &batch_hash, &1);
+        assert_eq!(client.find_staff_record(&staff_hash), Some(client.get_staff_record(&staff_hash)));
+        assert_eq!(client.find_payroll_batch(&batch_hash), Some(client.get_payroll_batch(&batch_hash)));
+    }
+
+    #[test]
+    fn test_sequenced_batches() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        assert_eq!(client.next_batch_sequence(), 0);
+        client.record_payroll_batch_seq(&owner, &0, &BytesN::from_array(&env, &[1u8; 32]), &3);
+        client.record_payroll_batch_seq(&owner, &1, &BytesN::from_array(&env, &[2u8; 32]), &3);
+        assert_eq!(client.next_batch_sequence(), 2);
+        assert_eq!(client.get_total_batches(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected batch sequence")]
+    fn test_replayed_batch_sequence() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.record_payroll_batch_seq(&owner, &0, &BytesN::from_array(&env, &[1u8; 32]), &3);
+
+        // A retry carrying a variant hash but the same sequence number
+        let variant = BytesN::from_array(&env, &[7u8; 32]);
+        env.as_contract(&contract_id, || {
+            StaffRegistry::record_payroll_batch_seq(env.clone(), owner, 0, variant, 3)
+        });
+    }
+
+    #[test]
+    fn test_batch_hash_chain() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        assert_eq!(client.get_latest_batch(), None);
+
+        let hashes = [
+            BytesN::from_array(&env, &[1u8; 32]),
+            BytesN::from_array(&env, &[2u8; 32]),
+            BytesN::from_array(&env, &[3u8; 32]),
+        ];
+        for batch_hash in hashes.iter() {
+            client.record_payroll_batch(&owner, batch_hash, &5);
+        }
+
+        let latest = client.get_latest_batch().unwrap();
+        assert_eq!(latest.batch_hash, hashes[2]);
+        assert_eq!(latest.prev_batch_hash, hashes[1]);
+        assert_eq!(client.get_payroll_batch(&hashes[0]).prev_batch_hash, BytesN::from_array(&env, &[0u8; 32]));
+
+        assert!(client.verify_chain(&hashes[0], &hashes[2]));
+        assert!(!client.verify_chain(&hashes[2], &hashes[0]));
+    }
+
+    #[test]
+    fn test_staff_hashes_pagination() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        for i in 1..=5u8 {
+            client.register_staff(&owner, &BytesN::from_array(&env, &[i; 32]));
+        }
+
+        let page = client.get_staff_hashes(&1, &2);
+        assert_eq!(page.len(), 2);
+        assert_eq!(page.get(0).unwrap(), BytesN::from_array(&env, &[2u8; 32]));
+        assert_eq!(page.get(1).unwrap(), BytesN::from_array(&env, &[3u8; 32]));
+
+        // Reading past the end returns a short or empty page
+        assert_eq!(client.get_staff_hashes(&4, &10).len(), 1);
+        assert_eq!(client.get_staff_hashes(&5, &10).len(), 0);
+    }
+
+    #[test]
+    fn test_batch_pagination() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        for i in 1..=3u8 {
+            client.record_payroll_batch(&owner, &BytesN::from_array(&env, &[i; 32]), &(i as u32));
+        }
+
+        let hashes = client.get_batch_hashes(&0, &2);
+        assert_eq!(hashes.len(), 2);
+        assert_eq!(hashes.get(1).unwrap(), BytesN::from_array(&env, &[2u8; 32]));
+
+        let batches = client.get_batches(&2, &5);
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches.get(0).unwrap().staff_count, 3);
+        assert_eq!(batches.get(0).unwrap().uploaded_by, owner);
+    }
+
+    #[test]
+    fn test_two_step_ownership_transfer() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let new_owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        // Cancelled proposals leave the owner untouched
+        client.propose_owner(&new_owner);
+        assert_eq!(client.pending_owner(), Some(new_owner.clone()));
+        client.cancel_proposal();
+        assert_eq!(client.pending_owner(), None);
+        assert_eq!(client.owner(), owner);
+
+        client.propose_owner(&new_owner);
+        client.accept_ownership();
+        assert_eq!(client.owner(), new_owner);
+        assert_eq!(client.pending_owner(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "No pending owner")]
+    fn test_accept_without_proposal() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+
+        env.as_contract(&contract_id, || StaffRegistry::accept_ownership(env.clone()));
+    }
+
+    #[test]
+    fn test_reactivate_staff() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        client.revoke_staff(&owner, &staff_hash);
+        assert!(!client.is_staff_active(&staff_hash));
+
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        client.reactivate_staff(&owner, &staff_hash);
+
+        let record = client.get_staff_record(&staff_hash);
+        assert_eq!(record.status, StaffStatus::Active);
+        assert_eq!(record.reactivated_at, Some(1_000));
+        assert_eq!(client.get_total_staff(), 1);
+    }
+
+    #[test]
+    fn test_staff_stats() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff = [
+            BytesN::from_array(&env, &[1u8; 32]),
+            BytesN::from_array(&env, &[2u8; 32]),
+            BytesN::from_array(&env, &[3u8; 32]),
+        ];
+        for staff_hash in staff.iter() {
+            client.register_staff(&owner, staff_hash);
+        }
+        client.revoke_staff(&owner, &staff[0]);
+        client.revoke_staff(&owner, &staff[1]);
+        client.revoke_staff(&owner, &staff[1]);
+        client.reactivate_staff(&owner, &staff[0]);
+
+        assert_eq!(
+            client.get_staff_stats(),
+            StaffStats { total: 3, active_count: 2, suspended_count: 0, on_leave_count: 0, revoked_count: 1 }
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Staff already active")]
+    fn test_reactivate_active_staff() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::reactivate_staff(env.clone(), owner, staff_hash)
+        });
+    }
+
+    #[test]
+    fn test_register_staff_batch() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let existing = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_staff(&owner, &existing);
+
+        let hashes = Vec::from_array(&env, [
+            existing.clone(),
+            BytesN::from_array(&env, &[2u8; 32]),
+            BytesN::from_array(&env, &[3u8; 32]),
+            BytesN::from_array(&env, &[3u8; 32]),
+        ]);
+
+        assert_eq!(client.register_staff_batch(&owner, &hashes, &true), 2);
+        assert_eq!(client.get_total_staff(), 3);
+        assert!(client.is_staff_active(&BytesN::from_array(&env, &[3u8; 32])));
+    }
+
+    #[test]
+    #[should_panic(expected = "Staff already registered")]
+    fn test_register_staff_batch_rejects_duplicates() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let hashes = Vec::from_array(&env, [
+            BytesN::from_array(&env, &[1u8; 32]),
+            BytesN::from_array(&env, &[1u8; 32]),
+        ]);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::register_staff_batch(env.clone(), owner, hashes, false)
+        });
+    }
+
+    #[test]
+    fn test_verify_inclusion() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        // Four payslip leaves -> two levels of sorted-pair hashing
+        let leaves: [BytesN<32>; 4] = [
+            BytesN::from_array(&env, &[11u8; 32]),
+            BytesN::from_array(&env, &[12u8; 32]),
+            BytesN::from_array(&env, &[13u8; 32]),
+            BytesN::from_array(&env, &[14u8; 32]),
+        ];
+        let left = merkle::hash_pair(&env, HashMode::Sha256, &leaves[0], &leaves[1]);
+        let right = merkle::hash_pair(&env, HashMode::Sha256, &leaves[2], &leaves[3]);
+        let root = merkle::hash_pair(&env, HashMode::Sha256, &left, &right);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch_with_root(&owner, &batch_hash, &4, &root);
+        assert_eq!(client.get_payroll_batch(&batch_hash).merkle_root, root);
+
+        let proof = Vec::from_array(&env, [leaves[3].clone(), left.clone()]);
+        assert!(client.verify_inclusion(&batch_hash, &leaves[2], &proof));
+
+        // Wrong leaf for the same proof
+        assert!(!client.verify_inclusion(&batch_hash, &leaves[0], &proof));
+    }
+
+    #[test]
+    fn test_batch_membership() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let alice = BytesN::from_array(&env, &[1u8; 32]);
+        let bob = BytesN::from_array(&env, &[2u8; 32]);
+        client.register_staff(&owner, &alice);
+        client.register_staff(&owner, &bob);
+
+        let january = BytesN::from_array(&env, &[10u8; 32]);
+        let february = BytesN::from_array(&env, &[11u8; 32]);
+        client.record_batch_with_members(&owner, &january, &Vec::from_array(&env, [alice.clone(), bob.clone()]));
+        client.record_batch_with_members(&owner, &february, &Vec::from_array(&env, [alice.clone()]));
+
+        assert_eq!(client.get_payroll_batch(&january).staff_count, 2);
+        assert!(client.is_staff_in_batch(&february, &alice));
+        assert!(!client.is_staff_in_batch(&february, &bob));
+
+        let alice_batches = client.get_batches_for_staff(&alice, &0, &10);
+        assert_eq!(alice_batches, Vec::from_array(&env, [january.clone(), february]));
+        assert_eq!(client.get_batches_for_staff(&bob, &0, &10), Vec::from_array(&env, [january.clone()]));
+
+        let history = client.get_payment_history(&alice, &1, &10);
+        assert_eq!(history.len(), 1);
+        assert_eq!(history.get(0).unwrap().staff_count, 1);
+        assert_eq!(client.get_payment_history(&bob, &0, &10).get(0).unwrap().batch_hash, january);
+    }
+
+    #[test]
+    #[should_panic(expected = "Staff not active")]
+    fn test_batch_members_must_be_registered() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let batch_hash = BytesN::from_array(&env, &[10u8; 32]);
+        let unknown = Vec::from_array(&env, [BytesN::from_array(&env, &[9u8; 32])]);
+        env.as_contract(&contract_id, || {
+            StaffRegistry::record_batch_with_members(env.clone(), owner, batch_hash, unknown)
+        });
+    }
+
+    #[test]
+    fn test_batch_metadata() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let metadata = BatchMetadata {
+            period_start: 1_722_470_400,
+            period_end: 1_725_148_800,
+            currency: symbol_short!("NGN"),
+            total_amount_commitment: BytesN::from_array(&env, &[7u8; 32]),
+        };
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_batch_with_metadata(&owner, &batch_hash, &25, &metadata);
+
+        assert_eq!(client.get_batch_metadata(&batch_hash), Some(metadata));
+        assert_eq!(client.get_payroll_batch(&batch_hash).staff_count, 25);
+
+        let plain_batch = BytesN::from_array(&env, &[3u8; 32]);
+        client.record_payroll_batch(&owner, &plain_batch, &1);
+        assert_eq!(client.get_batch_metadata(&plain_batch), None);
+    }
+
+    #[test]
+    fn test_supersede_batch() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let original = BytesN::from_array(&env, &[1u8; 32]);
+        let first_fix = BytesN::from_array(&env, &[2u8; 32]);
+        let second_fix = BytesN::from_array(&env, &[3u8; 32]);
+
+        client.record_payroll_batch(&owner, &original, &10);
+        client.supersede_batch(&owner, &original, &first_fix, &11, &symbol_short!("missing"));
+        client.supersede_batch(&owner, &first_fix, &second_fix, &11, &symbol_short!("typo"));
+
+        assert_eq!(client.get_payroll_batch(&original).superseded_by, first_fix);
+        let fix = client.get_payroll_batch(&first_fix);
+        assert_eq!(fix.supersedes, original);
+        assert_eq!(fix.correction_reason, symbol_short!("missing"));
+
+        let expected = Vec::from_array(&env, [original, first_fix.clone(), second_fix.clone()]);
+        assert_eq!(client.get_batch_lineage(&first_fix), expected);
+        assert_eq!(client.get_batch_lineage(&second_fix), expected);
+        assert_eq!(client.get_total_batches(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "Batch already superseded")]
+    fn test_supersede_twice() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let original = BytesN::from_array(&env, &[1u8; 32]);
+        client.record_payroll_batch(&owner, &original, &10);
+        client.supersede_batch(&owner, &original, &BytesN::from_array(&env, &[2u8; 32]), &10, &symbol_short!("fix"));
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::supersede_batch(
+                env.clone(),
+                owner,
+                original,
+                BytesN::from_array(&env, &[3u8; 32]),
+                10,
+                symbol_short!("fix"),
+            )
+        });
+    }
+
+    #[test]
+    fn test_auditor_invalidates_batch() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let auditor = Address::generate(&env);
+        client.initialize(&owner);
+        client.grant_role(&auditor, &Role::Auditor);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &10);
+        assert!(client.get_payroll_batch(&batch_hash).is_valid);
+
+        client.invalidate_batch(&auditor, &batch_hash, &symbol_short!("ghost"));
+
+        let batch = client.get_payroll_batch(&batch_hash);
+        assert!(!batch.is_valid);
+        assert_eq!(batch.invalidation_reason, symbol_short!("ghost"));
+        assert!(client.is_batch_recorded(&batch_hash));
+    }
+
+    #[test]
+    #[should_panic(expected = "Missing role")]
+    fn test_uploader_cannot_invalidate_batch() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let uploader = Address::generate(&env);
+        client.initialize(&owner);
+        client.grant_role(&uploader, &Role::PayrollUploader);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&uploader, &batch_hash, &10);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::invalidate_batch(env.clone(), uploader, batch_hash, symbol_short!("oops"))
+        });
+    }
+
+    #[test]
+    fn test_staff_metadata_uri() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        assert_eq!(client.get_staff_record(&staff_hash).metadata_uri.len(), 0);
+
+        let uri = String::from_str(&env, "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi");
+        client.set_staff_metadata(&owner, &staff_hash, &uri);
+        assert_eq!(client.get_staff_record(&staff_hash).metadata_uri, uri);
+    }
+}
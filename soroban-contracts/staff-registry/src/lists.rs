@@ -0,0 +1,428 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, BytesN, Env, IntoVal, TryFromVal, Val, Vec};
+
+use super::*;
+
+// Registry-wide lists in insertion order. Each hash is its own index entry
+// with the length in instance storage, so appends cost the same however long the
+// list gets. Deployments from before v7 keep their original Vec as a read-only
+// prefix until `migrate_lists` has copied it out.
+#[derive(Clone, Copy)]
+pub(crate) enum List {
+    Staff,
+    Batch,
+}
+
+#[contracttype]
+enum ListKey {
+    StaffLen,                    // instance: number of staff hashes listed
+    BatchLen,                    // instance: number of batch hashes listed
+    StaffAt(u32),                // list position -> staff hash
+    BatchAt(u32),                // list position -> batch hash
+    StaffPosition(BytesN<32>),   // staff hash -> list position, for in-place rotation
+    StaffMigrated,               // instance: legacy staff entries copied so far
+    BatchMigrated,               // instance: legacy batch entries copied so far
+}
+
+fn len_key(list: List) -> ListKey {
+    match list {
+        List::Staff => ListKey::StaffLen,
+        List::Batch => ListKey::BatchLen,
+    }
+}
+
+fn at_key(list: List, index: u32) -> ListKey {
+    match list {
+        List::Staff => ListKey::StaffAt(index),
+        List::Batch => ListKey::BatchAt(index),
+    }
+}
+
+fn migrated_key(list: List) -> ListKey {
+    match list {
+        List::Staff => ListKey::StaffMigrated,
+        List::Batch => ListKey::BatchMigrated,
+    }
+}
+
+fn legacy_key(list: List) -> DataKey {
+    match list {
+        List::Staff => DataKey::AllStaffHashes,
+        List::Batch => DataKey::AllBatchHashes,
+    }
+}
+
+fn legacy(env: &Env, list: List) -> Vec<BytesN<32>> {
+    storage::get(env, DataClass::Indexes, &legacy_key(list))
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn len(env: &Env, list: List) -> u32 {
+    storage::get(env, DataClass::Config, &len_key(list))
+        .unwrap_or_else(|| legacy(env, list).len())
+}
+
+pub(crate) fn get(env: &Env, list: List, index: u32) -> Option<BytesN<32>> {
+    if index >= len(env, list) {
+        return None;
+    }
+    storage::get(env, DataClass::Indexes, &at_key(list, index))
+        .or_else(|| legacy(env, list).get(index))
+}
+
+pub(crate) fn last(env: &Env, list: List) -> Option<BytesN<32>> {
+    len(env, list).checked_sub(1).and_then(|index| get(env, list, index))
+}
+
+// Page through a list; the legacy prefix is loaded at most once per call
+pub(crate) fn page(env: &Env, list: List, start: u32, limit: u32) -> Vec<BytesN<32>> {
+    range(env, list, start, start.saturating_add(limit.min(MAX_PAGE_SIZE)))
+}
+
+fn range(env: &Env, list: List, start: u32, end: u32) -> Vec<BytesN<32>> {
+    let mut page = Vec::new(env);
+    let end = end.min(len(env, list));
+
+    let mut prefix: Option<Vec<BytesN<32>>> = None;
+    for index in start..end {
+        let hash = storage::get(env, DataClass::Indexes, &at_key(list, index))
+            .unwrap_or_else(|| prefix.get_or_insert_with(|| legacy(env, list)).get_unchecked(index));
+        page.push_back(hash);
+    }
+
+    page
+}
+
+pub(crate) fn push(env: &Env, list: List, hash: &BytesN<32>) {
+    let index = len(env, list);
+    let key = at_key(list, index);
+    storage::set(env, DataClass::Indexes, &key, hash);
+    storage::set(env, DataClass::Config, &len_key(list), &(index + 1));
+
+    if let List::Staff = list {
+        set_position(env, hash, index);
+    }
+}
+
+// Position of a staff hash in the registry-wide list
+pub(crate) fn staff_position(env: &Env, staff_hash: &BytesN<32>) -> Option<u32> {
+    storage::get(env, DataClass::Indexes, &ListKey::StaffPosition(staff_hash.clone()))
+        .or_else(|| legacy(env, List::Staff).first_index_of(staff_hash))
+}
+
+fn set_position(env: &Env, staff_hash: &BytesN<32>, index: u32) {
+    let key = ListKey::StaffPosition(staff_hash.clone());
+    storage::set(env, DataClass::Indexes, &key, &index);
+}
+
+// Put a rotated staff hash's replacement in its place in the list
+pub(crate) fn replace_staff(env: &Env, old_hash: &BytesN<32>, new_hash: &BytesN<32>) {
+    let position_key = ListKey::StaffPosition(old_hash.clone());
+    let Some(index) = staff_position(env, old_hash) else {
+        return;
+    };
+
+    let key = ListKey::StaffAt(index);
+    storage::set(env, DataClass::Indexes, &key, new_hash);
+    storage::remove(env, DataClass::Indexes, &position_key);
+    set_position(env, new_hash, index);
+}
+
+// Pin the list lengths so they no longer depend on the legacy Vecs
+pub(crate) fn seal_legacy(env: &Env) {
+    for list in [List::Staff, List::Batch] {
+        let length = len(env, list);
+        storage::set(env, DataClass::Config, &len_key(list), &length);
+    }
+}
+
+// Copy up to `max` legacy entries out of one list's Vec, dropping the Vec once empty
+fn migrate_list(env: &Env, list: List, max: u32) -> u32 {
+    let prefix = legacy(env, list);
+    let mut cursor: u32 = storage::get(env, DataClass::Config, &migrated_key(list)).unwrap_or(0);
+    let end = cursor.saturating_add(max).min(prefix.len());
+
+    while cursor < end {
+        let key = at_key(list, cursor);
+        // An entry already present was rewritten by a rotation and is newer
+        if !storage::has(env, DataClass::Indexes, &key) {
+            let hash = prefix.get_unchecked(cursor);
+            storage::set(env, DataClass::Indexes, &key, &hash);
+            if let List::Staff = list {
+                set_position(env, &hash, cursor);
+            }
+        }
+        cursor += 1;
+    }
+
+    if cursor >= prefix.len() {
+        storage::remove(env, DataClass::Indexes, &legacy_key(list));
+        storage::remove(env, DataClass::Config, &migrated_key(list));
+    } else {
+        storage::set(env, DataClass::Config, &migrated_key(list), &cursor);
+    }
+    prefix.len() - cursor
+}
+
+// Per-subject lists (a day's registrations, a batch's disputes, a department's
+// staff, ...) use the same layout under their own key: one entry per element and
+// the length alongside. A Vec stored under the bare key by an older version is
+// kept as a read-only prefix, as with the registry lists above.
+#[contracttype]
+#[derive(Clone)]
+enum Slot {
+    Len,                   // number of elements
+    At(u32),               // position -> element
+    Position(BytesN<32>),  // hash -> position, for hash lists edited in place
+}
+
+fn slot<K: IntoVal<Env, Val>>(env: &Env, key: &K, slot: Slot) -> (Val, Slot) {
+    (key.into_val(env), slot)
+}
+
+fn keyed_legacy<K, V>(env: &Env, class: DataClass, key: &K) -> Vec<V>
+where
+    K: IntoVal<Env, Val>,
+    V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    storage::get(env, class, key).unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn keyed_len<K>(env: &Env, class: DataClass, key: &K) -> u32
+where
+    K: IntoVal<Env, Val>,
+{
+    storage::get(env, class, &slot(env, key, Slot::Len))
+        .unwrap_or_else(|| keyed_legacy::<K, Val>(env, class, key).len())
+}
+
+pub(crate) fn keyed_get<K, V>(env: &Env, class: DataClass, key: &K, index: u32) -> Option<V>
+where
+    K: IntoVal<Env, Val>,
+    V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    if index >= keyed_len(env, class, key) {
+        return None;
+    }
+    storage::get(env, class, &slot(env, key, Slot::At(index)))
+        .or_else(|| keyed_legacy(env, class, key).get(index))
+}
+
+pub(crate) fn keyed_last<K, V>(env: &Env, class: DataClass, key: &K) -> Option<V>
+where
+    K: IntoVal<Env, Val>,
+    V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    keyed_len(env, class, key).checked_sub(1).and_then(|index| keyed_get(env, class, key, index))
+}
+
+// Elements in [start, end); the legacy prefix is loaded at most once per call
+pub(crate) fn keyed_range<K, V>(env: &Env, class: DataClass, key: &K, start: u32, end: u32) -> Vec<V>
+where
+    K: IntoVal<Env, Val>,
+    V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let mut page = Vec::new(env);
+    let end = end.min(keyed_len(env, class, key));
+
+    let mut prefix: Option<Vec<V>> = None;
+    for index in start..end {
+        let value = storage::get(env, class, &slot(env, key, Slot::At(index)))
+            .unwrap_or_else(|| prefix.get_or_insert_with(|| keyed_legacy(env, class, key)).get_unchecked(index));
+        page.push_back(value);
+    }
+
+    page
+}
+
+pub(crate) fn keyed_page<K, V>(env: &Env, class: DataClass, key: &K, start: u32, limit: u32) -> Vec<V>
+where
+    K: IntoVal<Env, Val>,
+    V: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    keyed_range(env, class, key, start, start.saturating_add(limit.min(MAX_PAGE_SIZE)))
+}
+
+pub(crate) fn keyed_push<K, V>(env: &Env, class: DataClass, key: &K, value: &V)
+where
+    K: IntoVal<Env, Val>,
+    V: IntoVal<Env, Val>,
+{
+    let index = keyed_len(env, class, key);
+    storage::set(env, class, &slot(env, key, Slot::At(index)), value);
+    storage::set(env, class, &slot(env, key, Slot::Len), &(index + 1));
+}
+
+// Append a hash and remember its position so it can later be replaced or removed
+pub(crate) fn keyed_push_hash<K>(env: &Env, class: DataClass, key: &K, hash: &BytesN<32>)
+where
+    K: IntoVal<Env, Val>,
+{
+    let index = keyed_len(env, class, key);
+    keyed_push(env, class, key, hash);
+    storage::set(env, class, &slot(env, key, Slot::Position(hash.clone())), &index);
+}
+
+fn keyed_position<K>(env: &Env, class: DataClass, key: &K, hash: &BytesN<32>) -> Option<u32>
+where
+    K: IntoVal<Env, Val>,
+{
+    storage::get(env, class, &slot(env, key, Slot::Position(hash.clone()))).or_else(|| {
+        // A legacy position only counts while nothing has been written over it
+        keyed_legacy::<K, BytesN<32>>(env, class, key)
+            .first_index_of(hash)
+            .filter(|index| keyed_get::<K, BytesN<32>>(env, class, key, *index).as_ref() == Some(hash))
+    })
+}
+
+// Put `new_hash` in `old_hash`'s place; returns false if `old_hash` isn't listed
+pub(crate) fn keyed_replace_hash<K>(env: &Env, class: DataClass, key: &K, old_hash: &BytesN<32>, new_hash: &BytesN<32>) -> bool
+where
+    K: IntoVal<Env, Val>,
+{
+    let Some(index) = keyed_position(env, class, key, old_hash) else {
+        return false;
+    };
+
+    storage::set(env, class, &slot(env, key, Slot::At(index)), new_hash);
+    storage::remove(env, class, &slot(env, key, Slot::Position(old_hash.clone())));
+    storage::set(env, class, &slot(env, key, Slot::Position(new_hash.clone())), &index);
+    true
+}
+
+// Remove a hash by moving the last element into its place; order is not kept
+pub(crate) fn keyed_remove_hash<K>(env: &Env, class: DataClass, key: &K, hash: &BytesN<32>)
+where
+    K: IntoVal<Env, Val>,
+{
+    let Some(index) = keyed_position(env, class, key, hash) else {
+        return;
+    };
+
+    let last = keyed_len(env, class, key) - 1;
+    if index != last {
+        let moved: BytesN<32> = keyed_get(env, class, key, last).unwrap();
+        storage::set(env, class, &slot(env, key, Slot::At(index)), &moved);
+        storage::set(env, class, &slot(env, key, Slot::Position(moved)), &index);
+    }
+    storage::remove(env, class, &slot(env, key, Slot::At(last)));
+    storage::remove(env, class, &slot(env, key, Slot::Position(hash.clone())));
+    storage::set(env, class, &slot(env, key, Slot::Len), &last);
+}
+
+// Extend the length, any legacy prefix and the elements [start, start + limit),
+// clamped to MAX_PAGE_SIZE; returns how many elements follow the page
+pub(crate) fn keyed_extend<K>(env: &Env, class: DataClass, key: &K, start: u32, limit: u32, ledgers: u32) -> u32
+where
+    K: IntoVal<Env, Val>,
+{
+    storage::extend_to(env, class, &slot(env, key, Slot::Len), ledgers);
+    storage::extend_to(env, class, key, ledgers);
+
+    let len = keyed_len(env, class, key);
+    let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(len);
+    for index in start..end {
+        storage::extend_to(env, class, &slot(env, key, Slot::At(index)), ledgers);
+    }
+    len.saturating_sub(end.max(start))
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Move up to `max` entries of the pre-v7 staff and batch lists into indexed
+    /// storage (owner only). Returns how many entries are left; call until 0.
+    pub fn migrate_lists(env: Env, max: u32) -> u32 {
+        let owner = auth::require_owner(&env);
+        ttl::extend_instance(&env);
+
+        seal_legacy(&env);
+        let staff_left = migrate_list(&env, List::Staff, max);
+        let batch_budget = if staff_left == 0 { max } else { 0 };
+        let remaining = staff_left + migrate_list(&env, List::Batch, batch_budget);
+
+        audit::log_action(&env, symbol_short!("lst_migr"), &owner);
+
+        env.events().publish(
+            (symbol_short!("lst_migr"),),
+            (remaining, env.ledger().timestamp())
+        );
+
+        remaining
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+    #[test]
+    fn test_legacy_lists_migrate_in_chunks() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        // Simulate a pre-v7 deployment: three staff and a batch only in the legacy Vecs
+        let legacy_staff = Vec::from_array(
+            &env,
+            [BytesN::from_array(&env, &[1u8; 32]), BytesN::from_array(&env, &[2u8; 32]), BytesN::from_array(&env, &[3u8; 32])],
+        );
+        env.as_contract(&contract_id, || {
+            env.storage().instance().remove(&ListKey::StaffLen);
+            env.storage().instance().remove(&ListKey::BatchLen);
+            env.storage().persistent().set(&DataKey::AllStaffHashes, &legacy_staff);
+            env.storage().persistent().set(&DataKey::AllBatchHashes, &Vec::from_array(&env, [BytesN::from_array(&env, &[9u8; 32])]));
+        });
+        assert_eq!(client.get_total_staff(), 3);
+
+        // New entries append after the legacy prefix without rewriting it
+        let hired = BytesN::from_array(&env, &[4u8; 32]);
+        env.as_contract(&contract_id, || push(&env, List::Staff, &hired));
+        assert_eq!(client.get_staff_hashes(&1, &10).len(), 3);
+
+        assert_eq!(client.migrate_lists(&2), 2);
+        assert_eq!(client.migrate_lists(&2), 0);
+        env.as_contract(&contract_id, || {
+            assert!(!env.storage().persistent().has(&DataKey::AllStaffHashes));
+            assert!(!env.storage().persistent().has(&DataKey::AllBatchHashes));
+        });
+
+        let mut expected = legacy_staff.clone();
+        expected.push_back(hired);
+        assert_eq!(client.get_staff_hashes(&0, &10), expected);
+        assert_eq!(client.get_total_batches(), 1);
+        assert_eq!(client.get_batch_hashes(&0, &10), Vec::from_array(&env, [BytesN::from_array(&env, &[9u8; 32])]));
+    }
+
+    #[test]
+    fn test_keyed_list_over_legacy_vec() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, StaffRegistry);
+
+        let hash = |seed: u8| BytesN::from_array(&env, &[seed; 32]);
+        let key = DataKey::DepartmentStaff(symbol_short!("finance"));
+        env.as_contract(&contract_id, || {
+            // Written by a version that kept the whole list in one Vec
+            env.storage().persistent().set(&key, &Vec::from_array(&env, [hash(1), hash(2), hash(3)]));
+            assert_eq!(keyed_len(&env, DataClass::Indexes, &key), 3);
+
+            keyed_push_hash(&env, DataClass::Indexes, &key, &hash(4));
+            assert!(keyed_replace_hash(&env, DataClass::Indexes, &key, &hash(2), &hash(5)));
+            keyed_remove_hash(&env, DataClass::Indexes, &key, &hash(1));
+
+            // The last entry fills the removed slot; the legacy Vec is never rewritten
+            let listed: Vec<BytesN<32>> = keyed_page(&env, DataClass::Indexes, &key, 0, 10);
+            assert_eq!(listed, Vec::from_array(&env, [hash(4), hash(5), hash(3)]));
+            assert_eq!(env.storage().persistent().get::<_, Vec<BytesN<32>>>(&key).unwrap().len(), 3);
+
+            // A removed legacy hash can't be found at its old position any more
+            assert!(!keyed_replace_hash(&env, DataClass::Indexes, &key, &hash(1), &hash(6)));
+            keyed_remove_hash(&env, DataClass::Indexes, &key, &hash(3));
+            assert_eq!(keyed_last::<_, BytesN<32>>(&env, DataClass::Indexes, &key), Some(hash(5)));
+        });
+    }
+}
@@ -0,0 +1,25 @@
+use soroban_sdk::{Bytes, BytesN, Env, Vec};
+
+use crate::hashing::{self, HashMode};
+
+// Hash two sibling nodes. Pairs are sorted before hashing (as in OpenZeppelin's
+// MerkleProof), so proofs don't need to carry left/right position bits.
+pub(crate) fn hash_pair(env: &Env, mode: HashMode, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+
+    let mut data = Bytes::from_array(env, &first.to_array());
+    data.extend_from_array(&second.to_array());
+
+    hashing::digest(env, mode, &data)
+}
+
+// Fold a proof from the leaf up and compare against the expected root
+pub(crate) fn verify_proof(env: &Env, mode: HashMode, root: &BytesN<32>, leaf: &BytesN<32>, proof: &Vec<BytesN<32>>) -> bool {
+    let mut computed = leaf.clone();
+
+    for sibling in proof.iter() {
+        computed = hash_pair(env, mode, &computed, &sibling);
+    }
+
+    computed == *root
+}
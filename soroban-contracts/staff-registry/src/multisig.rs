@@ -0,0 +1,230 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env, Vec};
+
+use super::*;
+
+// Upper bound on the owner signer set
+pub const MAX_OWNER_SIGNERS: u32 = 10;
+
+// M-of-N signer set standing in for the single owner key. Any `threshold` of the
+// signers can make themselves the active quorum with select_quorum; until one
+// does, the first `threshold` addresses are the quorum.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OwnerSigners {
+    pub signers: Vec<Address>,
+    pub threshold: u32,
+}
+
+#[contracttype]
+enum MultisigKey {
+    OwnerSigners,
+    ActiveQuorum,  // signers whose auth owner calls currently require
+}
+
+pub(crate) fn owner_signers(env: &Env) -> Option<OwnerSigners> {
+    storage::get(env, DataClass::Config, &MultisigKey::OwnerSigners)
+}
+
+// Drop the signer set, returning control to the single owner key
+pub(crate) fn clear_signers(env: &Env) {
+    storage::remove(env, DataClass::Config, &MultisigKey::OwnerSigners);
+    storage::remove(env, DataClass::Config, &MultisigKey::ActiveQuorum);
+}
+
+// Signers that must authorize owner calls: the selected quorum, else the first `threshold`
+pub(crate) fn active_quorum(env: &Env, signers: &OwnerSigners) -> Vec<Address> {
+    storage::get(env, DataClass::Config, &MultisigKey::ActiveQuorum)
+        .unwrap_or_else(|| signers.signers.slice(0..signers.threshold))
+}
+
+// Approvers must be distinct members of the signer set, at least `threshold` of them
+fn require_valid_quorum(signers: &OwnerSigners, approvers: &Vec<Address>) {
+    for i in 0..approvers.len() {
+        let approver = approvers.get_unchecked(i);
+        if !signers.signers.contains(&approver) {
+            panic!("Not a signer");
+        }
+        if approvers.first_index_of(approver) != Some(i) {
+            panic!("Duplicate signer");
+        }
+    }
+    if approvers.len() < signers.threshold {
+        panic!("Quorum not met");
+    }
+}
+
+// Replace the signer set; shared by set_signers and the timelocked action
+pub(crate) fn apply_set_signers(env: &Env, owner: &Address, signers: Vec<Address>, threshold: u32) {
+    if signers.is_empty() && threshold == 0 {
+        clear_signers(env);
+    } else {
+        if signers.len() > MAX_OWNER_SIGNERS {
+            panic!("Too many signers");
+        }
+        if threshold == 0 || threshold > signers.len() {
+            panic!("Invalid threshold");
+        }
+        for i in 0..signers.len() {
+            if signers.first_index_of(signers.get_unchecked(i)) != Some(i) {
+                panic!("Duplicate signer");
+            }
+        }
+        storage::set(env, DataClass::Config, &MultisigKey::OwnerSigners, &OwnerSigners { signers: signers.clone(), threshold });
+        storage::remove(env, DataClass::Config, &MultisigKey::ActiveQuorum);
+    }
+    ttl::extend_instance(env);
+
+    audit::log_action(env, symbol_short!("signers"), owner);
+
+    env.events().publish(
+        (symbol_short!("signers"),),
+        (signers, threshold, env.ledger().timestamp())
+    );
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Put owner-only calls behind `threshold` of `signers` (owner or current quorum);
+    /// an empty list with threshold 0 returns control to the single owner key.
+    /// Once a timelock is configured this must be queued as AdminAction::SetSigners.
+    pub fn set_signers(env: Env, signers: Vec<Address>, threshold: u32) {
+        let owner = auth::require_owner(&env);
+        timelock::require_not_timelocked(&env);
+
+        apply_set_signers(&env, &owner, signers, threshold);
+    }
+
+    /// Make `approvers` the quorum that authorizes owner calls (every approver signs).
+    /// Any `threshold` distinct signers can do this, so a lost key never locks out the owner.
+    pub fn select_quorum(env: Env, approvers: Vec<Address>) {
+        renounce::require_not_renounced(&env);
+        let signers = owner_signers(&env).unwrap_or_else(|| panic!("No signer set"));
+
+        require_valid_quorum(&signers, &approvers);
+        for approver in approvers.iter() {
+            approver.require_auth();
+        }
+
+        storage::set(&env, DataClass::Config, &MultisigKey::ActiveQuorum, &approvers);
+        ttl::extend_instance(&env);
+
+        audit::log_action(&env, symbol_short!("quorum"), &auth::owner(&env));
+
+        env.events().publish(
+            (symbol_short!("quorum"),),
+            (approvers, env.ledger().timestamp())
+        );
+    }
+
+    /// Get the owner signer set, if one is configured
+    pub fn get_signers(env: Env) -> Option<OwnerSigners> {
+        owner_signers(&env)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_owner_calls_require_quorum() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let a = Address::generate(&env);
+        let b = Address::generate(&env);
+        let c = Address::generate(&env);
+        client.set_signers(&Vec::from_array(&env, [a.clone(), b.clone(), c.clone()]), &2);
+        assert_eq!(client.get_signers().unwrap().threshold, 2);
+
+        client.set_challenge_period(&10);
+        let auths = env.auths();
+        assert_eq!(auths.len(), 2);
+        assert_eq!(auths[0].0, a);
+        assert_eq!(auths[1].0, b);
+
+        // The quorum can hand control back to the single owner key
+        client.set_signers(&Vec::new(&env), &0);
+        assert_eq!(client.get_signers(), None);
+        client.set_challenge_period(&20);
+        let auths = env.auths();
+        assert_eq!(auths.len(), 1);
+        assert_eq!(auths[0].0, owner);
+    }
+
+    #[test]
+    fn test_quorum_of_later_signers() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+
+        let a = Address::generate(&env);
+        let b = Address::generate(&env);
+        let c = Address::generate(&env);
+        client.set_signers(&Vec::from_array(&env, [a.clone(), b.clone(), c.clone()]), &2);
+
+        // a's key is lost; b and c take over without it
+        client.select_quorum(&Vec::from_array(&env, [c.clone(), b.clone()]));
+        client.set_challenge_period(&10);
+        let auths = env.auths();
+        assert_eq!(auths.len(), 2);
+        assert_eq!(auths[0].0, c);
+        assert_eq!(auths[1].0, b);
+    }
+
+    #[test]
+    #[should_panic(expected = "Not a signer")]
+    fn test_quorum_outside_signer_set() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+
+        let a = Address::generate(&env);
+        client.set_signers(&Vec::from_array(&env, [a.clone(), Address::generate(&env)]), &2);
+
+        let approvers = Vec::from_array(&env, [a, Address::generate(&env)]);
+        env.as_contract(&contract_id, || StaffRegistry::select_quorum(env.clone(), approvers));
+    }
+
+    #[test]
+    #[should_panic(expected = "Action is timelocked")]
+    fn test_set_signers_is_timelocked() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+        client.set_timelock_delay(&3_600);
+
+        let signers = Vec::from_array(&env, [Address::generate(&env)]);
+        env.as_contract(&contract_id, || StaffRegistry::set_signers(env.clone(), signers, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid threshold")]
+    fn test_threshold_above_signer_count() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+
+        let signers = Vec::from_array(&env, [Address::generate(&env)]);
+        env.as_contract(&contract_id, || StaffRegistry::set_signers(env.clone(), signers, 2));
+    }
+}
@@ -0,0 +1,224 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env};
+
+use super::*;
+
+// Structured end of employment; the record is revoked once the final settlement is recorded
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Offboarding {
+    pub staff_hash: BytesN<32>,
+    pub notice_ts: u64,   // last day of the notice period
+    pub started_by: Address,
+    pub started_at: u64,
+    pub settled_at: Option<u64>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FinalSettlement {
+    pub settlement_hash: BytesN<32>,
+    pub recorded_by: Address,
+    pub recorded_at: u64,
+}
+
+#[contracttype]
+enum OffboardingKey {
+    Offboarding(BytesN<32>),
+    FinalSettlement(BytesN<32>),
+}
+
+// Carry an in-flight or finished off-boarding over to a rotated hash
+pub(crate) fn rekey(env: &Env, old_hash: &BytesN<32>, new_hash: &BytesN<32>) {
+    if let Some(mut offboarding) = StaffRegistry::get_offboarding(env.clone(), old_hash.clone()) {
+        offboarding.staff_hash = new_hash.clone();
+        let key = OffboardingKey::Offboarding(new_hash.clone());
+        storage::set(env, DataClass::Records, &key, &offboarding);
+        storage::remove(env, DataClass::Records, &OffboardingKey::Offboarding(old_hash.clone()));
+    }
+    rotation::move_entry(
+        env,
+        &OffboardingKey::FinalSettlement(old_hash.clone()),
+        &OffboardingKey::FinalSettlement(new_hash.clone()),
+    );
+}
+
+// Drop the off-boarding trail when a staff member's data is erased
+pub(crate) fn erase(env: &Env, staff_hash: &BytesN<32>) {
+    storage::remove(env, DataClass::Records, &OffboardingKey::Offboarding(staff_hash.clone()));
+    storage::remove(env, DataClass::Records, &OffboardingKey::FinalSettlement(staff_hash.clone()));
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Start terminating a staff member with a notice period ending at `notice_ts` (owner or Registrar)
+    pub fn begin_offboarding(env: Env, caller: Address, staff_hash: BytesN<32>, notice_ts: u64) {
+        // onlyOwner or Registrar
+        auth::require_role(&env, &caller, Role::Registrar);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        rotation::require_current(&env, &staff_hash);
+        erasure::require_not_erased(&env, &staff_hash);
+
+        let record = Self::get_staff_record(env.clone(), staff_hash.clone());
+        if record.status == StaffStatus::Terminated {
+            panic!("Staff already terminated");
+        }
+        if Self::get_offboarding(env.clone(), staff_hash.clone()).is_some_and(|o| o.settled_at.is_none()) {
+            panic!("Offboarding already started");
+        }
+        let now = env.ledger().timestamp();
+        if notice_ts < now {
+            panic!("Notice date in the past");
+        }
+
+        // A rehire that is off-boarded again starts a fresh trail
+        storage::remove(&env, DataClass::Records, &OffboardingKey::FinalSettlement(staff_hash.clone()));
+
+        let offboarding = Offboarding {
+            staff_hash: staff_hash.clone(),
+            notice_ts,
+            started_by: caller.clone(),
+            started_at: now,
+            settled_at: None,
+        };
+        let key = OffboardingKey::Offboarding(staff_hash.clone());
+        storage::set(&env, DataClass::Records, &key, &offboarding);
+
+        audit::log(&env, symbol_short!("off_start"), &caller, &staff_hash);
+
+        env.events().publish(
+            (symbol_short!("off_start"), staff_hash),
+            (caller, notice_ts, now)
+        );
+    }
+
+    /// Anchor the final settlement once the notice period is over; this revokes the
+    /// staff member in the same call (owner or PayrollUploader)
+    pub fn record_final_settlement(env: Env, caller: Address, staff_hash: BytesN<32>, settlement_hash: BytesN<32>) {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        rotation::require_current(&env, &staff_hash);
+        erasure::require_not_erased(&env, &staff_hash);
+
+        let mut offboarding = Self::get_offboarding(env.clone(), staff_hash.clone())
+            .filter(|o| o.settled_at.is_none())
+            .unwrap_or_else(|| panic!("Offboarding not started"));
+        let now = env.ledger().timestamp();
+        if now < offboarding.notice_ts {
+            panic!("Notice period not over");
+        }
+        if settlement_hash == BytesN::from_array(&env, &[0u8; 32]) {
+            panic!("Invalid settlement hash");
+        }
+
+        let settlement = FinalSettlement {
+            settlement_hash: settlement_hash.clone(),
+            recorded_by: caller.clone(),
+            recorded_at: now,
+        };
+        let settlement_key = OffboardingKey::FinalSettlement(staff_hash.clone());
+        storage::set(&env, DataClass::Records, &settlement_key, &settlement);
+
+        offboarding.settled_at = Some(now);
+        let key = OffboardingKey::Offboarding(staff_hash.clone());
+        storage::set(&env, DataClass::Records, &key, &offboarding);
+
+        audit::log(&env, symbol_short!("off_settl"), &caller, &staff_hash);
+
+        env.events().publish(
+            (symbol_short!("off_settl"), staff_hash.clone()),
+            (caller.clone(), settlement_hash, now)
+        );
+
+        // Settlement closes the trail: revoke the record
+        let mut record = Self::get_staff_record(env.clone(), staff_hash.clone());
+        if record.status != StaffStatus::Terminated {
+            staff_status::apply(&env, &Scope::Main, &mut record, StaffStatus::Terminated);
+        }
+        history::save_staff_record(&env, &Scope::Main, &record);
+
+        audit::log(&env, symbol_short!("staff_rev"), &caller, &staff_hash);
+
+        events::staff_event(&env, symbol_short!("staff_rev"), &caller, &record);
+    }
+
+    /// Get a staff member's latest off-boarding, if one was started
+    pub fn get_offboarding(env: Env, staff_hash: BytesN<32>) -> Option<Offboarding> {
+        storage::get(&env, DataClass::Records, &OffboardingKey::Offboarding(rotation::resolve(&env, &staff_hash)))
+    }
+
+    /// Get the final settlement anchored for a staff member's latest off-boarding
+    pub fn get_final_settlement(env: Env, staff_hash: BytesN<32>) -> Option<FinalSettlement> {
+        storage::get(&env, DataClass::Records, &OffboardingKey::FinalSettlement(rotation::resolve(&env, &staff_hash)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Ledger}, Env};
+
+    #[test]
+    fn test_offboarding_flow() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let settlement_hash = BytesN::from_array(&env, &[9u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+
+        client.begin_offboarding(&owner, &staff_hash, &5_000);
+        let offboarding = client.get_offboarding(&staff_hash).unwrap();
+        assert_eq!(offboarding.notice_ts, 5_000);
+        assert_eq!(offboarding.settled_at, None);
+        // Staff keep working through the notice period
+        assert!(client.is_staff_active(&staff_hash));
+
+        env.ledger().with_mut(|li| li.timestamp = 5_000);
+        client.record_final_settlement(&owner, &staff_hash, &settlement_hash);
+
+        assert_eq!(client.get_staff_status(&staff_hash), StaffStatus::Terminated);
+        assert_eq!(client.get_offboarding(&staff_hash).unwrap().settled_at, Some(5_000));
+        let settlement = client.get_final_settlement(&staff_hash).unwrap();
+        assert_eq!(settlement.settlement_hash, settlement_hash);
+        assert_eq!(settlement.recorded_by, owner);
+    }
+
+    #[test]
+    #[should_panic(expected = "Notice period not over")]
+    fn test_settlement_before_notice_ends() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        client.begin_offboarding(&owner, &staff_hash, &5_000);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::record_final_settlement(env.clone(), owner, staff_hash, BytesN::from_array(&env, &[9u8; 32]))
+        });
+    }
+}
@@ -0,0 +1,234 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env};
+
+use super::*;
+
+// How long a proposed staff member has to accept before the proposal lapses (~14 days)
+pub const ONBOARDING_EXPIRY_SECONDS: u64 = 14 * 86_400;
+
+// Registration awaiting the staff member's consent
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingRegistration {
+    pub staff_hash: BytesN<32>,
+    pub staff_address: Address,
+    pub proposed_by: Address,
+    pub proposed_at: u64,
+    pub expires_at: u64,
+}
+
+#[contracttype]
+enum OnboardingKey {
+    OnboardingRequired,               // direct registration disabled
+    PendingRegistration(BytesN<32>),
+    PendingByAddress(Address),        // staff address -> proposed staff hash
+}
+
+fn is_live(env: &Env, pending: &PendingRegistration) -> bool {
+    env.ledger().timestamp() < pending.expires_at
+}
+
+// With onboarding required, staff can only join by accepting a proposal
+pub(crate) fn require_direct_registration(env: &Env) {
+    if StaffRegistry::is_onboarding_required(env.clone()) {
+        panic!("Staff acceptance required");
+    }
+}
+
+// Drop a leftover (lapsed) proposal and its address index when a staff member's data is erased
+pub(crate) fn erase(env: &Env, staff_hash: &BytesN<32>) {
+    let key = OnboardingKey::PendingRegistration(staff_hash.clone());
+    let pending: Option<PendingRegistration> = storage::get(env, DataClass::Records, &key);
+    if let Some(pending) = pending {
+        let address_key = OnboardingKey::PendingByAddress(pending.staff_address);
+        let indexed: Option<BytesN<32>> = storage::get(env, DataClass::Indexes, &address_key);
+        if indexed.as_ref() == Some(staff_hash) {
+            storage::remove(env, DataClass::Indexes, &address_key);
+        }
+        storage::remove(env, DataClass::Records, &key);
+    }
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Require every new main-registry staff member to accept their registration (owner only)
+    pub fn set_onboarding_required(env: Env, required: bool) {
+        let owner = auth::require_owner(&env);
+
+        storage::set(&env, DataClass::Config, &OnboardingKey::OnboardingRequired, &required);
+        ttl::extend_instance(&env);
+
+        audit::log_action(&env, symbol_short!("onboard"), &owner);
+
+        env.events().publish(
+            (symbol_short!("onboard"),),
+            (required, env.ledger().timestamp())
+        );
+    }
+
+    /// Check whether direct registration is disabled in favour of propose/accept
+    pub fn is_onboarding_required(env: Env) -> bool {
+        storage::get(&env, DataClass::Config, &OnboardingKey::OnboardingRequired)
+            .unwrap_or(false)
+    }
+
+    /// Propose a staff member; they join the registry once `staff_address` accepts (owner or Registrar)
+    pub fn propose_staff(env: Env, caller: Address, staff_hash: BytesN<32>, staff_address: Address) {
+        // onlyOwner or Registrar
+        auth::require_role(&env, &caller, Role::Registrar);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        hashing::require_legacy_hash_allowed(&env);
+
+        if Self::is_staff_registered(env.clone(), staff_hash.clone()) {
+            panic!("Staff already registered");
+        }
+        if staff_hash == BytesN::from_array(&env, &[0u8; 32]) {
+            panic!("Invalid staff hash");
+        }
+        if Self::get_pending_registration(env.clone(), staff_hash.clone()).is_some_and(|pending| is_live(&env, &pending)) {
+            panic!("Registration pending");
+        }
+        let address_key = OnboardingKey::PendingByAddress(staff_address.clone());
+        let previous: Option<BytesN<32>> = storage::get(&env, DataClass::Indexes, &address_key);
+        if let Some(previous) = previous.and_then(|hash| Self::get_pending_registration(env.clone(), hash)) {
+            if is_live(&env, &previous) {
+                panic!("Address has a pending registration");
+            }
+        }
+
+        // Proposals count against the proposer, since acceptance only needs the staff member's auth
+        rate_limits::consume_registrations(&env, &caller, 1);
+
+        let now = env.ledger().timestamp();
+        let pending = PendingRegistration {
+            staff_hash: staff_hash.clone(),
+            staff_address: staff_address.clone(),
+            proposed_by: caller.clone(),
+            proposed_at: now,
+            expires_at: now.saturating_add(ONBOARDING_EXPIRY_SECONDS),
+        };
+        let key = OnboardingKey::PendingRegistration(staff_hash.clone());
+        storage::set(&env, DataClass::Records, &key, &pending);
+        storage::set(&env, DataClass::Indexes, &address_key, &staff_hash);
+
+        audit::log(&env, symbol_short!("staff_prp"), &caller, &staff_hash);
+
+        env.events().publish(
+            (symbol_short!("staff_prp"), staff_hash),
+            (staff_address, pending.expires_at)
+        );
+    }
+
+    /// Accept a pending registration as the proposed staff member; binds the address to the record
+    pub fn accept_registration(env: Env, staff_address: Address) {
+        staff_address.require_auth();
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        let address_key = OnboardingKey::PendingByAddress(staff_address.clone());
+        let staff_hash: BytesN<32> = storage::get(&env, DataClass::Indexes, &address_key)
+            .unwrap_or_else(|| panic!("No pending registration"));
+        let key = OnboardingKey::PendingRegistration(staff_hash.clone());
+        let pending = Self::get_pending_registration(env.clone(), staff_hash.clone())
+            .unwrap_or_else(|| panic!("No pending registration"));
+        if !is_live(&env, &pending) {
+            panic!("Registration expired");
+        }
+        if Self::is_staff_registered(env.clone(), staff_hash.clone()) {
+            panic!("Staff already registered");
+        }
+
+        storage::remove(&env, DataClass::Records, &key);
+        storage::remove(&env, DataClass::Indexes, &address_key);
+
+        store_new_staff(&env, &Scope::Main, &pending.proposed_by, &staff_hash);
+        list_staff_hash(&env, &staff_hash);
+
+        acknowledgements::bind(&env, &staff_hash, &staff_address);
+
+        let record = Self::get_staff_record(env.clone(), staff_hash);
+        events::staff_event(&env, symbol_short!("staff_reg"), &staff_address, &record);
+    }
+
+    /// Get the pending registration for a staff hash, including lapsed ones
+    pub fn get_pending_registration(env: Env, staff_hash: BytesN<32>) -> Option<PendingRegistration> {
+        storage::get(&env, DataClass::Records, &OnboardingKey::PendingRegistration(staff_hash))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Ledger}, Env};
+
+    #[test]
+    fn test_propose_and_accept() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let employee = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+        client.set_onboarding_required(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.propose_staff(&owner, &staff_hash, &employee);
+        assert!(!client.is_staff_registered(&staff_hash));
+        assert_eq!(client.get_pending_registration(&staff_hash).unwrap().staff_address, employee);
+
+        client.accept_registration(&employee);
+        assert!(client.is_staff_active(&staff_hash));
+        assert_eq!(client.get_staff_record(&staff_hash).registered_by, owner);
+        assert_eq!(client.get_staff_address(&staff_hash), Some(employee));
+        assert_eq!(client.get_pending_registration(&staff_hash), None);
+        assert_eq!(client.get_total_staff(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Registration expired")]
+    fn test_accept_after_expiry() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let employee = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        client.propose_staff(&owner, &BytesN::from_array(&env, &[1u8; 32]), &employee);
+        env.ledger().with_mut(|li| li.timestamp += ONBOARDING_EXPIRY_SECONDS);
+
+        env.as_contract(&contract_id, || StaffRegistry::accept_registration(env.clone(), employee));
+    }
+
+    #[test]
+    #[should_panic(expected = "Staff acceptance required")]
+    fn test_direct_registration_disabled() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+        client.set_onboarding_required(&true);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::register_staff(env.clone(), owner, BytesN::from_array(&env, &[1u8; 32]))
+        });
+    }
+}
@@ -0,0 +1,317 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env, Symbol, Vec};
+
+use super::*;
+
+// Employer hosted on this deployment. The un-namespaced registry API belongs
+// to the deployment owner; every other employer gets its own org namespace.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Organization {
+    pub id: Symbol,
+    pub admin: Address,
+    pub registered_at: u64,
+}
+
+fn load_org(env: &Env, org_id: &Symbol) -> Organization {
+    storage::get(env, DataClass::Records, &DataKey::Org(org_id.clone()))
+        .unwrap_or_else(|| panic!("Organization not found"))
+}
+
+// Require auth from the org admin; registry writes are also subject to the global pause
+fn require_org_admin(env: &Env, org_id: &Symbol) -> Address {
+    let org = load_org(env, org_id);
+    org.admin.require_auth();
+
+    renounce::require_not_renounced(env);
+    pausable::require_not_paused(env);
+    ttl::extend_instance(env);
+
+    org.admin
+}
+
+// Store a staff record in an org namespace and emit the org_sreg event
+fn org_register_new_staff(env: &Env, admin: &Address, org_id: &Symbol, staff_hash: &BytesN<32>) {
+    let scope = Scope::Org(org_id.clone());
+    if storage::has(env, DataClass::Records, &scope.staff_key(staff_hash)) {
+        panic!("Staff already registered");
+    }
+    if *staff_hash == BytesN::from_array(env, &[0u8; 32]) {
+        panic!("Invalid staff hash");
+    }
+
+    rate_limits::consume_registrations(env, admin, 1);
+
+    store_new_staff(env, &scope, admin, staff_hash);
+
+    env.events().publish(
+        (symbol_short!("org_sreg"), org_id.clone(), staff_hash.clone()),
+        (admin.clone(), env.ledger().timestamp())
+    );
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Register a new employer namespace (deployment owner only)
+    pub fn register_org(env: Env, org_id: Symbol, admin: Address) {
+        let owner = auth::require_owner(&env);
+
+        let key = DataKey::Org(org_id.clone());
+        if storage::has(&env, DataClass::Records, &key) {
+            panic!("Organization already registered");
+        }
+        // Reserved for main-registry staff hashes
+        if org_id == hashing::main_registry_org(&env) {
+            panic!("Invalid org id");
+        }
+
+        let org = Organization {
+            id: org_id.clone(),
+            admin: admin.clone(),
+            registered_at: env.ledger().timestamp(),
+        };
+        storage::set(&env, DataClass::Records, &key, &org);
+
+        audit::log_action(&env, symbol_short!("org_reg"), &owner);
+
+        env.events().publish(
+            (symbol_short!("org_reg"), org_id),
+            (admin, env.ledger().timestamp())
+        );
+    }
+
+    /// Hand an organization over to a new admin (current org admin only)
+    pub fn set_org_admin(env: Env, org_id: Symbol, new_admin: Address) {
+        let mut org = load_org(&env, &org_id);
+        org.admin.require_auth();
+        new_admin.require_auth();
+
+        let previous_admin = org.admin.clone();
+        org.admin = new_admin.clone();
+        let key = DataKey::Org(org_id.clone());
+        storage::set(&env, DataClass::Records, &key, &org);
+
+        audit::log_action(&env, symbol_short!("org_admin"), &previous_admin);
+
+        env.events().publish(
+            (symbol_short!("org_admin"), org_id),
+            (new_admin, env.ledger().timestamp())
+        );
+    }
+
+    /// Get an organization by id
+    pub fn get_org(env: Env, org_id: Symbol) -> Organization {
+        load_org(&env, &org_id)
+    }
+
+    /// Register staff in an organization's namespace
+    pub fn org_register_staff(env: Env, org_id: Symbol, staff_hash: BytesN<32>) {
+        let admin = require_org_admin(&env, &org_id);
+        hashing::require_legacy_hash_allowed(&env);
+
+        org_register_new_staff(&env, &admin, &org_id, &staff_hash);
+    }
+
+    /// Register staff in an organization's namespace under a domain-separated hash
+    /// derived from `preimage`; returns the staff hash
+    pub fn org_register_staff_v2(env: Env, org_id: Symbol, preimage: Bytes) -> BytesN<32> {
+        let admin = require_org_admin(&env, &org_id);
+
+        let staff_hash = hashing::local_staff_hash(&env, &org_id, &preimage);
+        org_register_new_staff(&env, &admin, &org_id, &staff_hash);
+        staff_hash
+    }
+
+    /// Revoke staff in an organization's namespace
+    pub fn org_revoke_staff(env: Env, org_id: Symbol, staff_hash: BytesN<32>) {
+        let admin = require_org_admin(&env, &org_id);
+
+        // Revoking twice is a no-op, as in the main registry
+        let scope = Scope::Org(org_id.clone());
+        let mut record = Self::org_get_staff_record(env.clone(), org_id.clone(), staff_hash.clone());
+        if record.status != StaffStatus::Terminated {
+            staff_status::apply(&env, &scope, &mut record, StaffStatus::Terminated);
+        }
+        history::save_staff_record(&env, &scope, &record);
+
+        audit::log(&env, symbol_short!("org_srev"), &admin, &staff_hash);
+
+        env.events().publish(
+            (symbol_short!("org_srev"), org_id, staff_hash),
+            (admin, env.ledger().timestamp())
+        );
+    }
+
+    /// Record a payroll batch in an organization's namespace
+    pub fn org_record_payroll_batch(env: Env, org_id: Symbol, batch_hash: BytesN<32>, staff_count: u32) {
+        let admin = require_org_admin(&env, &org_id);
+
+        store_scoped_batch(&env, &Scope::Org(org_id), &new_batch(&env, admin, batch_hash, staff_count));
+    }
+
+    /// Get a staff record from an organization's namespace
+    pub fn org_get_staff_record(env: Env, org_id: Symbol, staff_hash: BytesN<32>) -> StaffRecord {
+        upgrade::read_staff_record(&env, &DataKey::OrgStaff(org_id, staff_hash))
+            .unwrap_or_else(|| panic!("Staff not found"))
+    }
+
+    /// Check if staff is active in an organization's namespace
+    pub fn org_is_staff_active(env: Env, org_id: Symbol, staff_hash: BytesN<32>) -> bool {
+        upgrade::read_staff_record(&env, &DataKey::OrgStaff(org_id, staff_hash))
+            .map(|record| expiry::is_active(&env, &record))
+            .unwrap_or(false)
+    }
+
+    /// Get a payroll batch from an organization's namespace
+    pub fn org_get_payroll_batch(env: Env, org_id: Symbol, batch_hash: BytesN<32>) -> PayrollBatch {
+        upgrade::read_payroll_batch(&env, &DataKey::OrgBatch(org_id, batch_hash))
+            .unwrap_or_else(|| panic!("Batch not found"))
+    }
+
+    /// Get total, active and revoked headcounts in an organization's namespace
+    pub fn org_get_staff_stats(env: Env, org_id: Symbol) -> StaffStats {
+        let counts = staff_status::status_counts(&env, &Scope::Org(org_id.clone()));
+        StaffStats {
+            total: lists::keyed_len(&env, DataClass::Indexes, &DataKey::OrgStaffHashes(org_id)),
+            active_count: counts.get(StaffStatus::Active).unwrap_or(0),
+            suspended_count: counts.get(StaffStatus::Suspended).unwrap_or(0),
+            on_leave_count: counts.get(StaffStatus::OnLeave).unwrap_or(0),
+            revoked_count: counts.get(StaffStatus::Terminated).unwrap_or(0),
+        }
+    }
+
+    /// Get a page of staff hashes registered in an organization's namespace
+    pub fn org_get_staff_hashes(env: Env, org_id: Symbol, start: u32, limit: u32) -> Vec<BytesN<32>> {
+        lists::keyed_page(&env, DataClass::Indexes, &DataKey::OrgStaffHashes(org_id), start, limit)
+    }
+
+    /// Get a page of batch hashes recorded in an organization's namespace
+    pub fn org_get_batch_hashes(env: Env, org_id: Symbol, start: u32, limit: u32) -> Vec<BytesN<32>> {
+        lists::keyed_page(&env, DataClass::Indexes, &DataKey::OrgBatchHashes(org_id), start, limit)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Ledger}, Env};
+
+    #[test]
+    fn test_org_namespaces_are_isolated() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let lagos_admin = Address::generate(&env);
+        let kano_admin = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let lagos = symbol_short!("lagos");
+        let kano = symbol_short!("kano");
+        client.register_org(&lagos, &lagos_admin);
+        client.register_org(&kano, &kano_admin);
+
+        // The same hash can exist independently in each namespace
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.org_register_staff(&lagos, &staff_hash);
+        client.org_register_staff(&kano, &staff_hash);
+        client.org_revoke_staff(&kano, &staff_hash);
+
+        assert!(client.org_is_staff_active(&lagos, &staff_hash));
+        assert!(!client.org_is_staff_active(&kano, &staff_hash));
+        assert_eq!(client.org_get_staff_record(&lagos, &staff_hash).registered_by, lagos_admin);
+        assert!(!client.is_staff_registered(&staff_hash));
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.org_record_payroll_batch(&lagos, &batch_hash, &1);
+        assert_eq!(client.org_get_payroll_batch(&lagos, &batch_hash).uploaded_by, lagos_admin);
+        assert_eq!(client.org_get_batch_hashes(&lagos, &0, &10).len(), 1);
+        assert_eq!(client.org_get_batch_hashes(&kano, &0, &10).len(), 0);
+        assert_eq!(client.org_get_staff_hashes(&kano, &0, &10).len(), 1);
+    }
+
+    #[test]
+    fn test_org_writes_keep_history_stats_and_windows() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.sequence_number = 100);
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let admin = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+        client.set_challenge_period(&10);
+
+        let lagos = symbol_short!("lagos");
+        client.register_org(&lagos, &admin);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.org_register_staff(&lagos, &staff_hash);
+        client.org_register_staff(&lagos, &BytesN::from_array(&env, &[2u8; 32]));
+        client.org_revoke_staff(&lagos, &staff_hash);
+
+        let stats = client.org_get_staff_stats(&lagos);
+        assert_eq!((stats.total, stats.active_count, stats.revoked_count), (2, 1, 1));
+        assert_eq!(client.org_get_staff_record_history(&lagos, &staff_hash, &0, &10).len(), 2);
+
+        let first = BytesN::from_array(&env, &[3u8; 32]);
+        let second = BytesN::from_array(&env, &[4u8; 32]);
+        client.org_record_payroll_batch(&lagos, &first, &4);
+        client.org_record_payroll_batch(&lagos, &second, &2);
+        assert_eq!(client.org_get_payroll_batch(&lagos, &second).prev_batch_hash, first);
+        assert_eq!(client.org_get_batch_history(&lagos, &first, &0, &10).len(), 1);
+        assert_eq!(client.org_get_aggregate_stats(&lagos).average_staff_per_batch, 3);
+        assert_eq!(client.get_uploader_batch_count(&admin), 2);
+
+        assert_eq!(client.org_get_batch_status(&lagos, &first), BatchStatus::Pending);
+        env.ledger().with_mut(|li| li.sequence_number += 10);
+        assert_eq!(client.org_get_batch_status(&lagos, &first), BatchStatus::Final);
+
+        // Nothing leaks into the main registry's counts
+        assert_eq!(client.get_staff_stats().total, 0);
+        assert_eq!(client.get_aggregate_stats(&0).cumulative_staff_count, 0);
+        assert_eq!(client.get_total_batches(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Too many staff in batch")]
+    fn test_org_batch_respects_limits() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_config(&Config { max_staff_per_batch: 5, max_batches_per_period: 0, min_batch_interval_ledgers: 0 });
+        client.register_org(&symbol_short!("lagos"), &Address::generate(&env));
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::org_record_payroll_batch(env.clone(), symbol_short!("lagos"), BytesN::from_array(&env, &[1u8; 32]), 6)
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Organization not found")]
+    fn test_unknown_org() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        env.as_contract(&contract_id, || {
+            StaffRegistry::org_register_staff(env.clone(), symbol_short!("ghost"), staff_hash)
+        });
+    }
+}
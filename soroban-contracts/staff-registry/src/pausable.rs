@@ -0,0 +1,144 @@
+use soroban_sdk::{contractimpl, symbol_short, Address, Env};
+
+use super::*;
+
+// Panic if the registry is paused; guards every registry data write, fund
+// movement and keeper or auditor housekeeping call. Governance calls (roles,
+// ownership) stay available so a compromised key can be rotated out while
+// the registry is frozen.
+pub(crate) fn require_not_paused(env: &Env) {
+    if storage::get(env, DataClass::Config, &DataKey::Paused).unwrap_or(false) {
+        panic!("Contract is paused");
+    }
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Pause all registry writes (owner or EmergencyPauser)
+    pub fn pause(env: Env, caller: Address) {
+        auth::require_role(&env, &caller, Role::EmergencyPauser);
+        require_not_paused(&env);
+
+        storage::set(&env, DataClass::Config, &DataKey::Paused, &true);
+
+        audit::log_action(&env, symbol_short!("paused"), &caller);
+
+        env.events().publish(
+            (symbol_short!("paused"),),
+            (caller, env.ledger().timestamp())
+        );
+    }
+
+    /// Resume registry writes (owner only)
+    pub fn unpause(env: Env) {
+        let owner = auth::require_owner(&env);
+
+        if !Self::is_paused(env.clone()) {
+            panic!("Contract is not paused");
+        }
+
+        storage::set(&env, DataClass::Config, &DataKey::Paused, &false);
+
+        audit::log_action(&env, symbol_short!("unpaused"), &owner);
+
+        env.events().publish(
+            (symbol_short!("unpaused"),),
+            (owner, env.ledger().timestamp())
+        );
+    }
+
+    /// Check whether registry writes are paused
+    pub fn is_paused(env: Env) -> bool {
+        storage::get(&env, DataClass::Config, &DataKey::Paused).unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, BytesN, Env};
+
+    #[test]
+    fn test_pause_and_unpause() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let pauser = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+        client.grant_role(&pauser, &Role::EmergencyPauser);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+
+        client.pause(&pauser);
+        assert!(client.is_paused());
+
+        // Views keep working while paused
+        assert!(client.is_staff_active(&staff_hash));
+        assert_eq!(client.get_total_staff(), 1);
+
+        client.unpause();
+        assert!(!client.is_paused());
+        client.revoke_staff(&owner, &staff_hash);
+    }
+
+    #[test]
+    #[should_panic(expected = "Contract is paused")]
+    fn test_writes_blocked_while_paused() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.pause(&owner);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        env.as_contract(&contract_id, || {
+            StaffRegistry::record_payroll_batch(env.clone(), owner, batch_hash, 10)
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Contract is paused")]
+    fn test_tombstone_blocked_while_paused() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        client.pause(&owner);
+
+        env.as_contract(&contract_id, || StaffRegistry::tombstone_staff(env.clone(), staff_hash));
+    }
+
+    #[test]
+    #[should_panic(expected = "Contract is paused")]
+    fn test_poke_blocked_while_paused() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.pause(&owner);
+
+        env.as_contract(&contract_id, || StaffRegistry::poke(env.clone(), Address::generate(&env)));
+    }
+}
@@ -0,0 +1,254 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, token, Address, BytesN, Env, Symbol, Vec};
+
+use super::*;
+
+// Tokens held by the contract for paying out a batch
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchFunding {
+    pub funder: Address,    // receives undisbursed funds on refund
+    pub token: Address,
+    pub funded: i128,
+    pub disbursed: i128,
+    pub refunded: i128,
+    pub funded_at: u64,     // first funding, starts the escrow finalize window
+}
+
+// Outcome of a single disbursement item
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Payout {
+    pub recipient: Address,
+    pub amount: i128,
+    pub paid: bool,
+    pub processed_at: u64,
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Pull tokens from the funder into the contract to pay out a batch
+    pub fn fund_batch(env: Env, funder: Address, batch_hash: BytesN<32>, token: Address, amount: i128) {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &funder, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        if amount <= 0 {
+            panic!("Invalid amount");
+        }
+
+        let batch = Self::get_payroll_batch(env.clone(), batch_hash.clone());
+        if !batch.is_valid {
+            panic!("Batch is invalidated");
+        }
+
+        let key = DataKey::BatchFunding(batch_hash.clone());
+        let mut funding: BatchFunding = storage::get(&env, DataClass::Records, &key).unwrap_or(BatchFunding {
+            funder: funder.clone(),
+            token: token.clone(),
+            funded: 0,
+            disbursed: 0,
+            refunded: 0,
+            funded_at: env.ledger().timestamp(),
+        });
+        if funding.token != token {
+            panic!("Token mismatch");
+        }
+        if funding.funder != funder {
+            panic!("Funder mismatch");
+        }
+
+        token::Client::new(&env, &token).transfer(&funder, &env.current_contract_address(), &amount);
+
+        funding.funded += amount;
+        storage::set(&env, DataClass::Records, &key, &funding);
+
+        audit::log(&env, symbol_short!("batch_fnd"), &funder, &batch_hash);
+
+        env.events().publish(
+            (symbol_short!("batch_fnd"), batch_hash),
+            (funder, token, amount, env.ledger().timestamp())
+        );
+    }
+
+    /// Transfer funded tokens to staff wallets; each item is recorded as paid or failed
+    pub fn disburse(env: Env, caller: Address, batch_hash: BytesN<32>, payments: Vec<(Address, i128)>) -> Vec<bool> {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        if payments.len() > MAX_BULK_SIZE {
+            panic!("Too many payments");
+        }
+
+        // Only pay out batches that auditors have signed off on, once escrow releases
+        escrow::require_released(&env, &batch_hash);
+
+        let key = DataKey::BatchFunding(batch_hash.clone());
+        let mut funding: BatchFunding = storage::get(&env, DataClass::Records, &key)
+            .unwrap_or_else(|| panic!("Batch not funded"));
+        let token_client = token::Client::new(&env, &funding.token);
+
+        // Payouts count against the budget of the pay period (YYYYMM) the batch was recorded for
+        let period = Self::get_batch_period_id(env.clone(), batch_hash.clone());
+        let mut budget = period.and_then(|period_id| budgets::period_budget(&env, period_id));
+
+        let payouts_key = DataKey::BatchPayouts(batch_hash.clone());
+        let mut results = Vec::new(&env);
+
+        for (recipient, amount) in payments.iter() {
+            let remaining = funding.funded - funding.disbursed - funding.refunded;
+
+            let within_budget = budget
+                .as_ref()
+                .is_none_or(|budget| budget.disbursed + amount <= budget.cap);
+            if !within_budget {
+                env.events().publish(
+                    (Symbol::new(&env, "budget_exceeded"), period.unwrap_or(0)),
+                    (batch_hash.clone(), recipient.clone(), amount, env.ledger().timestamp())
+                );
+            }
+
+            // A failed transfer is recorded rather than aborting the whole run
+            let paid = amount > 0
+                && amount <= remaining
+                && within_budget
+                && matches!(
+                    token_client.try_transfer(&env.current_contract_address(), &recipient, &amount),
+                    Ok(Ok(()))
+                );
+            if paid {
+                funding.disbursed += amount;
+                if let Some(budget) = budget.as_mut() {
+                    budget.disbursed += amount;
+                }
+            }
+
+            lists::keyed_push(&env, DataClass::Records, &payouts_key, &Payout {
+                recipient,
+                amount,
+                paid,
+                processed_at: env.ledger().timestamp(),
+            });
+            results.push_back(paid);
+        }
+
+        storage::set(&env, DataClass::Records, &key, &funding);
+        if let (Some(period_id), Some(budget)) = (period, budget) {
+            budgets::set_period_budget_state(&env, period_id, &budget);
+        }
+
+        audit::log(&env, symbol_short!("batch_pay"), &caller, &batch_hash);
+
+        env.events().publish(
+            (symbol_short!("batch_pay"), batch_hash),
+            (caller, funding.disbursed, env.ledger().timestamp())
+        );
+
+        results
+    }
+
+    /// Get the funding held for a batch
+    pub fn get_batch_funding(env: Env, batch_hash: BytesN<32>) -> Option<BatchFunding> {
+        storage::get(&env, DataClass::Records, &DataKey::BatchFunding(batch_hash))
+    }
+
+    /// Get a page of the recorded payouts for a batch, oldest first
+    pub fn get_batch_payouts(env: Env, batch_hash: BytesN<32>, start: u32, limit: u32) -> Vec<Payout> {
+        lists::keyed_page(&env, DataClass::Records, &DataKey::BatchPayouts(batch_hash), start, limit)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, token::StellarAssetClient, Env};
+
+    #[test]
+    fn test_fund_and_disburse() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_challenge_period(&0);
+
+        let token = env.register_stellar_asset_contract(Address::generate(&env));
+        StellarAssetClient::new(&env, &token).mint(&owner, &1_000);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &2);
+
+        let auditor = Address::generate(&env);
+        client.grant_role(&auditor, &Role::Auditor);
+        client.attest_batch(&auditor, &batch_hash);
+
+        client.fund_batch(&owner, &batch_hash, &token, &1_000);
+
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let mut payments = Vec::new(&env);
+        payments.push_back((alice.clone(), 600));
+        payments.push_back((bob.clone(), 600)); // exceeds what is left
+
+        let results = client.disburse(&owner, &batch_hash, &payments);
+        assert_eq!(results, Vec::from_array(&env, [true, false]));
+
+        let token_client = token::Client::new(&env, &token);
+        assert_eq!(token_client.balance(&alice), 600);
+        assert_eq!(token_client.balance(&bob), 0);
+        assert_eq!(client.get_batch_funding(&batch_hash).unwrap().disbursed, 600);
+        assert_eq!(client.get_batch_payouts(&batch_hash, &0, &10).len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Batch not finalized")]
+    fn test_disburse_requires_finalized_batch() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &2);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::disburse(env.clone(), owner, batch_hash, Vec::new(&env))
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Challenge period open")]
+    fn test_disburse_during_challenge_period() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &2);
+        let auditor = Address::generate(&env);
+        client.grant_role(&auditor, &Role::Auditor);
+        client.attest_batch(&auditor, &batch_hash);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::disburse(env.clone(), owner, batch_hash, Vec::new(&env))
+        });
+    }
+}
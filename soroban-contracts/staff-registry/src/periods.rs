@@ -0,0 +1,142 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env, Vec};
+
+use super::*;
+
+#[contracttype]
+enum PeriodKey {
+    BatchPeriodId(BytesN<32>),  // canonical pay period (YYYYMM) of a batch
+    PeriodBatches(u32),         // batches recorded for a pay period, in record order
+}
+
+// period_id is a calendar month encoded as YYYYMM, e.g. 202608 for August 2026
+pub(crate) fn require_valid_period(period_id: u32) {
+    let year = period_id / 100;
+    let month = period_id % 100;
+    if !(1970..=9999).contains(&year) || !(1..=12).contains(&month) {
+        panic!("Invalid period id");
+    }
+}
+
+fn period_batches(env: &Env, period_id: u32) -> Vec<BytesN<32>> {
+    storage::get(env, DataClass::Indexes, &PeriodKey::PeriodBatches(period_id))
+        .unwrap_or(Vec::new(env))
+}
+
+fn assign_period(env: &Env, batch_hash: &BytesN<32>, period_id: u32) {
+    let key = PeriodKey::BatchPeriodId(batch_hash.clone());
+    storage::set(env, DataClass::Records, &key, &period_id);
+
+    let mut batches = period_batches(env, period_id);
+    batches.push_back(batch_hash.clone());
+    let index_key = PeriodKey::PeriodBatches(period_id);
+    storage::set(env, DataClass::Indexes, &index_key, &batches);
+}
+
+// Corrections pay the same period as the batch they replace
+pub(crate) fn inherit_period(env: &Env, old_hash: &BytesN<32>, new_hash: &BytesN<32>) {
+    if let Some(period_id) = StaffRegistry::get_batch_period_id(env.clone(), old_hash.clone()) {
+        assign_period(env, new_hash, period_id);
+
+        let old_batch = StaffRegistry::get_payroll_batch(env.clone(), old_hash.clone());
+        let new_batch = StaffRegistry::get_payroll_batch(env.clone(), new_hash.clone());
+        stats::record_period_batch(env, period_id, new_batch.staff_count, old_batch.staff_count);
+    }
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Record a payroll batch for a canonical pay period (YYYYMM)
+    pub fn record_batch_for_period(env: Env, caller: Address, batch_hash: BytesN<32>, staff_count: u32, period_id: u32) {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        require_valid_period(period_id);
+
+        store_new_batch(&env, &new_batch(&env, caller.clone(), batch_hash.clone(), staff_count));
+        assign_period(&env, &batch_hash, period_id);
+        stats::record_period_batch(&env, period_id, staff_count, 0);
+
+        audit::log(&env, symbol_short!("batch_per"), &caller, &batch_hash);
+
+        env.events().publish(
+            (symbol_short!("batch_per"), period_id),
+            (batch_hash, env.ledger().timestamp())
+        );
+    }
+
+    /// Get the pay period a batch was recorded for
+    pub fn get_batch_period_id(env: Env, batch_hash: BytesN<32>) -> Option<u32> {
+        storage::get(&env, DataClass::Records, &PeriodKey::BatchPeriodId(batch_hash))
+    }
+
+    /// Get the batches recorded for a pay period, including corrected and invalidated ones
+    pub fn get_batches_for_period(env: Env, period_id: u32) -> Vec<BytesN<32>> {
+        paginate(&env, &period_batches(&env, period_id), 0, MAX_PAGE_SIZE)
+    }
+
+    /// Check whether a pay period has a batch that is still valid and not superseded
+    pub fn has_period_been_paid(env: Env, period_id: u32) -> bool {
+        let zero_hash = BytesN::from_array(&env, &[0u8; 32]);
+        period_batches(&env, period_id).iter().any(|batch_hash| {
+            let batch = Self::get_payroll_batch(env.clone(), batch_hash);
+            batch.is_valid && batch.superseded_by == zero_hash
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env, Symbol};
+
+    #[test]
+    fn test_period_index() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let july = BytesN::from_array(&env, &[1u8; 32]);
+        let august = BytesN::from_array(&env, &[2u8; 32]);
+        let august_fix = BytesN::from_array(&env, &[3u8; 32]);
+        client.record_batch_for_period(&owner, &july, &10, &202607);
+        client.record_batch_for_period(&owner, &august, &10, &202608);
+        assert!(client.has_period_been_paid(&202608));
+        assert!(!client.has_period_been_paid(&202609));
+
+        // A correction keeps the period paid; invalidating it leaves the period unpaid
+        client.supersede_batch(&owner, &august, &august_fix, &10, &symbol_short!("typo"));
+        assert_eq!(client.get_batch_period_id(&august_fix), Some(202608));
+        assert_eq!(client.get_batches_for_period(&202608), Vec::from_array(&env, [august, august_fix.clone()]));
+        assert!(client.has_period_been_paid(&202608));
+
+        client.invalidate_batch(&owner, &august_fix, &Symbol::new(&env, "fraud"));
+        assert!(!client.has_period_been_paid(&202608));
+        assert!(client.has_period_been_paid(&202607));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid period id")]
+    fn test_invalid_period_id() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::record_batch_for_period(env.clone(), owner, BytesN::from_array(&env, &[1u8; 32]), 10, 202613)
+        });
+    }
+}
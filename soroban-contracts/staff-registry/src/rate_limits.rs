@@ -0,0 +1,149 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env};
+
+use super::*;
+
+// Per-address write allowances; 0 disables a limit
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RateLimit {
+    pub window_ledgers: u32,
+    pub max_registrations: u32,  // staff hashes registered per address per window
+    pub max_batches: u32,        // batches uploaded per address per window
+}
+
+#[contracttype]
+enum RateKey {
+    RateLimit,
+    RegistrationUsage(Address),  // counter: (window index, registrations in it)
+    BatchUsage(Address),         // counter: (window index, batches in it)
+}
+
+pub(crate) fn rate_limit(env: &Env) -> RateLimit {
+    storage::get(env, DataClass::Config, &RateKey::RateLimit)
+        .unwrap_or(RateLimit { window_ledgers: 0, max_registrations: 0, max_batches: 0 })
+}
+
+// Usage in the current window; with the default policy counters live in
+// temporary storage and simply expire once their window is over
+fn usage(env: &Env, tier: StorageTier, key: &RateKey, window_ledgers: u32) -> (u32, u32) {
+    let window = env.ledger().sequence() / window_ledgers;
+    let (usage_window, used): (u32, u32) = storage::get_in(env, tier, key).unwrap_or((window, 0));
+    (window, if usage_window == window { used } else { 0 })
+}
+
+fn consume(env: &Env, key: RateKey, max: u32, count: u32, message: &str) {
+    let limit = rate_limit(env);
+    if max == 0 || limit.window_ledgers == 0 {
+        return;
+    }
+
+    let tier = storage::tier(env, DataClass::Counters);
+    let (window, used) = usage(env, tier, &key, limit.window_ledgers);
+    if used.saturating_add(count) > max {
+        panic!("{}", message);
+    }
+    storage::set_in(env, tier, &key, &(window, used + count));
+    storage::extend_in(env, tier, &key, limit.window_ledgers);
+}
+
+// Count `count` new staff registrations against `caller`
+pub(crate) fn consume_registrations(env: &Env, caller: &Address, count: u32) {
+    let max = rate_limit(env).max_registrations;
+    consume(env, RateKey::RegistrationUsage(caller.clone()), max, count, "Registration rate limit exceeded");
+}
+
+// Count a batch upload against `uploader`
+pub(crate) fn consume_batch(env: &Env, uploader: &Address) {
+    let max = rate_limit(env).max_batches;
+    consume(env, RateKey::BatchUsage(uploader.clone()), max, 1, "Batch rate limit exceeded");
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Set the per-address write allowances (owner only)
+    pub fn set_rate_limit(env: Env, limit: RateLimit) {
+        let owner = auth::require_owner(&env);
+
+        let enabled = limit.max_registrations > 0 || limit.max_batches > 0;
+        if (enabled && limit.window_ledgers == 0) || limit.window_ledgers > config::CONFIG_PERIOD_LEDGERS {
+            panic!("Invalid rate limit window");
+        }
+
+        storage::set(&env, DataClass::Config, &RateKey::RateLimit, &limit);
+        ttl::extend_instance(&env);
+
+        audit::log_action(&env, symbol_short!("rate_lim"), &owner);
+
+        env.events().publish(
+            (symbol_short!("rate_lim"),),
+            (limit.window_ledgers, limit.max_registrations, limit.max_batches, env.ledger().timestamp())
+        );
+    }
+
+    /// Get the per-address write allowances
+    pub fn get_rate_limit(env: Env) -> RateLimit {
+        rate_limit(&env)
+    }
+
+    /// Get how many staff an address has registered in the current window
+    pub fn get_registration_usage(env: Env, account: Address) -> u32 {
+        let limit = rate_limit(&env);
+        if limit.window_ledgers == 0 {
+            return 0;
+        }
+        usage(&env, storage::tier(&env, DataClass::Counters), &RateKey::RegistrationUsage(account), limit.window_ledgers).1
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Ledger}, BytesN, Env, Vec};
+
+    #[test]
+    fn test_registration_rate_limit_resets() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let registrar = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+        client.grant_role(&registrar, &Role::Registrar);
+        client.set_rate_limit(&RateLimit { window_ledgers: 100, max_registrations: 3, max_batches: 0 });
+
+        let bulk = Vec::from_array(&env, [BytesN::from_array(&env, &[1u8; 32]), BytesN::from_array(&env, &[2u8; 32])]);
+        client.register_staff_batch(&registrar, &bulk, &false);
+        client.register_staff(&registrar, &BytesN::from_array(&env, &[3u8; 32]));
+        assert_eq!(client.get_registration_usage(&registrar), 3);
+        // Allowances are per address
+        client.register_staff(&owner, &BytesN::from_array(&env, &[4u8; 32]));
+
+        env.ledger().with_mut(|li| li.sequence_number += 100);
+        assert_eq!(client.get_registration_usage(&registrar), 0);
+        client.register_staff(&registrar, &BytesN::from_array(&env, &[5u8; 32]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Registration rate limit exceeded")]
+    fn test_registration_rate_limit() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+        client.set_rate_limit(&RateLimit { window_ledgers: 100, max_registrations: 1, max_batches: 0 });
+        client.register_staff(&owner, &BytesN::from_array(&env, &[1u8; 32]));
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::register_staff(env.clone(), owner, BytesN::from_array(&env, &[2u8; 32]))
+        });
+    }
+}
@@ -0,0 +1,154 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env};
+
+use super::*;
+
+// Shortest inactivity window a recovery address may be configured with (~7 days)
+pub const MIN_RECOVERY_INACTIVITY_LEDGERS: u32 = 7 * ttl::DAY_IN_LEDGERS;
+
+// Dead-man switch: who may take over, and after how long without owner activity
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecoveryConfig {
+    pub address: Address,
+    pub inactivity_ledgers: u32,
+}
+
+#[contracttype]
+enum RecoveryKey {
+    Recovery,
+    OwnerLastActive,  // ledger of the owner's most recent authorized call
+}
+
+// Record owner activity; called from every successful owner check
+pub(crate) fn touch_owner(env: &Env) {
+    // Writing dirties the whole instance entry, so skip it within the same ledger
+    let sequence = env.ledger().sequence();
+    if owner_last_active(env) != sequence {
+        storage::set(env, DataClass::Config, &RecoveryKey::OwnerLastActive, &sequence);
+    }
+}
+
+fn owner_last_active(env: &Env) -> u32 {
+    storage::get(env, DataClass::Config, &RecoveryKey::OwnerLastActive).unwrap_or(0)
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Name a recovery address that may claim ownership after `inactivity_ledgers`
+    /// without owner activity (owner only)
+    pub fn set_recovery(env: Env, address: Address, inactivity_ledgers: u32) {
+        let owner = auth::require_owner(&env);
+        ttl::extend_instance(&env);
+
+        if inactivity_ledgers < MIN_RECOVERY_INACTIVITY_LEDGERS {
+            panic!("Inactivity period too short");
+        }
+        if address == owner {
+            panic!("Already owner");
+        }
+        storage::set(&env, DataClass::Config, &RecoveryKey::Recovery, &RecoveryConfig { address: address.clone(), inactivity_ledgers });
+
+        audit::log_action(&env, symbol_short!("recovery"), &owner);
+
+        env.events().publish(
+            (symbol_short!("recovery"),),
+            (address, inactivity_ledgers, env.ledger().timestamp())
+        );
+    }
+
+    /// Get the recovery configuration, if any
+    pub fn get_recovery(env: Env) -> Option<RecoveryConfig> {
+        storage::get(&env, DataClass::Config, &RecoveryKey::Recovery)
+    }
+
+    /// Prove the owner key is alive without changing anything (owner only)
+    pub fn heartbeat(env: Env) {
+        auth::require_owner(&env);
+        ttl::extend_instance(&env);
+    }
+
+    /// Get the ledger of the owner's most recent authorized call
+    pub fn get_owner_last_active(env: Env) -> u32 {
+        owner_last_active(&env)
+    }
+
+    /// Take over ownership after the configured inactivity period (recovery address only).
+    /// Any owner signer set is dropped, since its keys are presumed lost too.
+    pub fn claim_ownership(env: Env) {
+        renounce::require_not_renounced(&env);
+
+        let config = Self::get_recovery(env.clone()).unwrap_or_else(|| panic!("No recovery configured"));
+        config.address.require_auth();
+        ttl::extend_instance(&env);
+
+        if env.ledger().sequence() < owner_last_active(&env).saturating_add(config.inactivity_ledgers) {
+            panic!("Owner still active");
+        }
+
+        let previous_owner = auth::owner(&env);
+        storage::set(&env, DataClass::Config, &DataKey::Owner, &config.address);
+        storage::remove(&env, DataClass::Config, &DataKey::PendingOwner);
+        storage::remove(&env, DataClass::Config, &RecoveryKey::Recovery);
+        multisig::clear_signers(&env);
+        touch_owner(&env);
+
+        audit::log_action(&env, symbol_short!("own_recov"), &config.address);
+
+        env.events().publish(
+            (symbol_short!("own_recov"),),
+            (previous_owner, config.address, env.ledger().timestamp())
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Ledger}, Env};
+
+    #[test]
+    fn test_claim_after_inactivity() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.sequence_number = 100);
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let rescuer = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_recovery(&rescuer, &MIN_RECOVERY_INACTIVITY_LEDGERS);
+        assert_eq!(client.get_owner_last_active(), 100);
+
+        // Keep the contract instance itself alive for the jump below
+        let far = MIN_RECOVERY_INACTIVITY_LEDGERS * 2;
+        env.as_contract(&contract_id, || env.storage().instance().extend_ttl(far, far));
+        env.ledger().with_mut(|li| li.sequence_number += MIN_RECOVERY_INACTIVITY_LEDGERS);
+
+        client.claim_ownership();
+        assert_eq!(client.owner(), rescuer);
+        assert_eq!(client.get_recovery(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Owner still active")]
+    fn test_claim_while_owner_active() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.sequence_number = 100);
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_recovery(&Address::generate(&env), &MIN_RECOVERY_INACTIVITY_LEDGERS);
+
+        env.ledger().with_mut(|li| li.sequence_number += MIN_RECOVERY_INACTIVITY_LEDGERS - 1);
+        client.heartbeat();
+        env.ledger().with_mut(|li| li.sequence_number += 1);
+
+        env.as_contract(&contract_id, || StaffRegistry::claim_ownership(env.clone()));
+    }
+}
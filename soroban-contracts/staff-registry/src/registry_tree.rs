@@ -0,0 +1,195 @@
+use soroban_sdk::{contractimpl, contracttype, BytesN, Env, Vec};
+
+use super::*;
+
+// Fixed depth of the staff registry tree (capacity 2^16 staff)
+pub const REGISTRY_TREE_DEPTH: u32 = 16;
+
+#[contracttype]
+enum TreeKey {
+    TreeLeafCount,              // leaves assigned so far
+    StaffLeaf(BytesN<32>),      // leaf index assigned to a staff hash
+    TreeNode(u32, u32),         // (level, index) node hash; absent means the empty subtree
+}
+
+// Root of an empty subtree at each level: zero leaf, then hash(z, z) upwards
+fn empty_roots(env: &Env) -> Vec<BytesN<32>> {
+    let mode = hashing::hash_mode(env);
+    let mut roots = Vec::new(env);
+    let mut node = BytesN::from_array(env, &[0u8; 32]);
+    for _ in 0..=REGISTRY_TREE_DEPTH {
+        roots.push_back(node.clone());
+        node = merkle::hash_pair(env, mode, &node, &node);
+    }
+    roots
+}
+
+fn node(env: &Env, empty: &Vec<BytesN<32>>, level: u32, index: u32) -> BytesN<32> {
+    storage::get(env, DataClass::Records, &TreeKey::TreeNode(level, index))
+        .unwrap_or_else(|| empty.get_unchecked(level))
+}
+
+// Write a leaf and rehash its path to the root
+fn set_leaf(env: &Env, index: u32, leaf: BytesN<32>) {
+    let mode = hashing::hash_mode(env);
+    let empty = empty_roots(env);
+
+    let mut current = leaf;
+    let mut position = index;
+    for level in 0..REGISTRY_TREE_DEPTH {
+        let key = TreeKey::TreeNode(level, position);
+        storage::set(env, DataClass::Records, &key, &current);
+
+        let sibling = node(env, &empty, level, position ^ 1);
+        current = merkle::hash_pair(env, mode, &current, &sibling);
+        position /= 2;
+    }
+
+    let root_key = TreeKey::TreeNode(REGISTRY_TREE_DEPTH, 0);
+    storage::set(env, DataClass::Records, &root_key, &current);
+}
+
+// Active staff appear as their hash, everyone else as an empty leaf
+fn leaf_for(env: &Env, staff_hash: &BytesN<32>, active: bool) -> BytesN<32> {
+    if active {
+        staff_hash.clone()
+    } else {
+        BytesN::from_array(env, &[0u8; 32])
+    }
+}
+
+fn leaf_index(env: &Env, staff_hash: &BytesN<32>) -> Option<u32> {
+    storage::get(env, DataClass::Records, &TreeKey::StaffLeaf(staff_hash.clone()))
+}
+
+pub(crate) fn contains(env: &Env, staff_hash: &BytesN<32>) -> bool {
+    leaf_index(env, staff_hash).is_some()
+}
+
+// Leaves assigned so far (one per registered staff hash)
+pub(crate) fn leaf_count(env: &Env) -> u32 {
+    storage::get(env, DataClass::Config, &TreeKey::TreeLeafCount).unwrap_or(0)
+}
+
+// Give a newly registered staff hash the next leaf
+pub(crate) fn insert(env: &Env, staff_hash: &BytesN<32>, active: bool) {
+    let index = leaf_count(env);
+    if index >= 1 << REGISTRY_TREE_DEPTH {
+        panic!("Registry tree full");
+    }
+    storage::set(env, DataClass::Config, &TreeKey::TreeLeafCount, &(index + 1));
+
+    let key = TreeKey::StaffLeaf(staff_hash.clone());
+    storage::set(env, DataClass::Records, &key, &index);
+
+    set_leaf(env, index, leaf_for(env, staff_hash, active));
+}
+
+// Update a staff leaf after an Active <-> non-Active status change
+pub(crate) fn set_active(env: &Env, staff_hash: &BytesN<32>, active: bool) {
+    let index = match leaf_index(env, staff_hash) {
+        Some(index) => index,
+        None => return,
+    };
+    set_leaf(env, index, leaf_for(env, staff_hash, active));
+}
+
+// Hand a rotated staff hash's leaf to its replacement
+pub(crate) fn rekey(env: &Env, old_hash: &BytesN<32>, new_hash: &BytesN<32>, active: bool) {
+    let index = match leaf_index(env, old_hash) {
+        Some(index) => index,
+        None => return,
+    };
+    storage::remove(env, DataClass::Records, &TreeKey::StaffLeaf(old_hash.clone()));
+
+    let key = TreeKey::StaffLeaf(new_hash.clone());
+    storage::set(env, DataClass::Records, &key, &index);
+
+    set_leaf(env, index, leaf_for(env, new_hash, active));
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Get the merkle root over all active staff hashes (maintained on every status change)
+    pub fn compute_registry_root(env: Env) -> BytesN<32> {
+        let empty = empty_roots(&env);
+        node(&env, &empty, REGISTRY_TREE_DEPTH, 0)
+    }
+
+    /// Get the sibling path proving a staff hash's leaf against the registry root
+    pub fn get_registry_proof(env: Env, staff_hash: BytesN<32>) -> Vec<BytesN<32>> {
+        let mut position = leaf_index(&env, &staff_hash).unwrap_or_else(|| panic!("Staff not found"));
+        let empty = empty_roots(&env);
+
+        let mut proof = Vec::new(&env);
+        for level in 0..REGISTRY_TREE_DEPTH {
+            proof.push_back(node(&env, &empty, level, position ^ 1));
+            position /= 2;
+        }
+        proof
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+    #[test]
+    fn test_registry_root_tracks_active_staff() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+        let empty_root = client.compute_registry_root();
+
+        let staff = [
+            BytesN::from_array(&env, &[1u8; 32]),
+            BytesN::from_array(&env, &[2u8; 32]),
+            BytesN::from_array(&env, &[3u8; 32]),
+        ];
+        for staff_hash in staff.iter() {
+            client.register_staff(&owner, staff_hash);
+        }
+        client.revoke_staff(&owner, &staff[1]);
+
+        let root = client.compute_registry_root();
+        assert_ne!(root, empty_root);
+
+        let proof = client.get_registry_proof(&staff[2]);
+        assert_eq!(proof.len(), REGISTRY_TREE_DEPTH);
+        assert!(merkle::verify_proof(&env, HashMode::Sha256, &root, &staff[2], &proof));
+
+        // Revoked staff no longer prove membership
+        let revoked_proof = client.get_registry_proof(&staff[1]);
+        assert!(!merkle::verify_proof(&env, HashMode::Sha256, &root, &staff[1], &revoked_proof));
+
+        client.reactivate_staff(&owner, &staff[1]);
+        let root = client.compute_registry_root();
+        assert!(merkle::verify_proof(&env, HashMode::Sha256, &root, &staff[1], &revoked_proof));
+    }
+
+    #[test]
+    #[should_panic(expected = "Hash mode locked")]
+    fn test_hash_mode_locked_once_tree_has_leaves() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+        client.register_staff(&owner, &BytesN::from_array(&env, &[1u8; 32]));
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::set_hash_mode(env.clone(), HashMode::Keccak256)
+        });
+    }
+}
@@ -0,0 +1,154 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Env, Symbol};
+
+use super::*;
+
+// Minimum wait between announcing and completing a renounce (~7 days)
+pub const RENOUNCE_DELAY_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+#[contracttype]
+enum RenounceKey {
+    RenounceAfter,  // earliest timestamp the announced renounce may complete
+    Renounced,      // set once ownership is gone for good
+}
+
+fn require_confirmation(confirmation: &Symbol) {
+    if *confirmation != symbol_short!("renounce") {
+        panic!("Invalid confirmation");
+    }
+}
+
+pub(crate) fn is_renounced(env: &Env) -> bool {
+    storage::get(env, DataClass::Config, &RenounceKey::Renounced).unwrap_or(false)
+}
+
+// Panic once the record has been frozen; guards admin calls and every append path
+pub(crate) fn require_not_renounced(env: &Env) {
+    if is_renounced(env) {
+        panic!("Ownership renounced");
+    }
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Announce that ownership will be renounced (owner only); `confirmation` must be `renounce`.
+    /// The delay is the longer of RENOUNCE_DELAY_SECONDS and the configured timelock.
+    pub fn begin_renounce(env: Env, confirmation: Symbol) -> u64 {
+        let owner = auth::require_owner(&env);
+        ttl::extend_instance(&env);
+        require_confirmation(&confirmation);
+
+        let delay = RENOUNCE_DELAY_SECONDS.max(timelock::timelock_delay(&env));
+        let renounce_after = env.ledger().timestamp() + delay;
+        storage::set(&env, DataClass::Config, &RenounceKey::RenounceAfter, &renounce_after);
+
+        audit::log_action(&env, symbol_short!("ren_begin"), &owner);
+
+        env.events().publish(
+            (symbol_short!("ren_begin"),),
+            (owner, renounce_after, env.ledger().timestamp())
+        );
+
+        renounce_after
+    }
+
+    /// Withdraw an announced renounce (owner only)
+    pub fn cancel_renounce(env: Env) {
+        let owner = auth::require_owner(&env);
+
+        if !storage::has(&env, DataClass::Config, &RenounceKey::RenounceAfter) {
+            panic!("No renounce pending");
+        }
+        storage::remove(&env, DataClass::Config, &RenounceKey::RenounceAfter);
+
+        audit::log_action(&env, symbol_short!("ren_canc"), &owner);
+
+        env.events().publish(
+            (symbol_short!("ren_canc"),),
+            (owner, env.ledger().timestamp())
+        );
+    }
+
+    /// Permanently give up ownership once the announced delay has passed (owner only).
+    /// Admin functions and all registry appends are closed afterwards; reads keep working.
+    pub fn renounce_ownership(env: Env, confirmation: Symbol) {
+        let owner = auth::require_owner(&env);
+        ttl::extend_instance(&env);
+        require_confirmation(&confirmation);
+
+        let renounce_after: u64 = storage::get(&env, DataClass::Config, &RenounceKey::RenounceAfter)
+            .unwrap_or_else(|| panic!("No renounce pending"));
+        if env.ledger().timestamp() < renounce_after {
+            panic!("Renounce delay not over");
+        }
+        // Nobody could unpause afterwards
+        pausable::require_not_paused(&env);
+
+        storage::remove(&env, DataClass::Config, &RenounceKey::RenounceAfter);
+        storage::remove(&env, DataClass::Config, &DataKey::PendingOwner);
+        storage::set(&env, DataClass::Config, &RenounceKey::Renounced, &true);
+
+        audit::log_action(&env, symbol_short!("renounced"), &owner);
+
+        env.events().publish(
+            (symbol_short!("renounced"),),
+            (owner, env.ledger().timestamp())
+        );
+    }
+
+    /// Check whether ownership has been renounced and the record frozen
+    pub fn is_renounced(env: Env) -> bool {
+        is_renounced(&env)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Ledger}, Address, BytesN, Env};
+
+    #[test]
+    #[should_panic(expected = "Ownership renounced")]
+    fn test_renounce_freezes_registry() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+
+        client.begin_renounce(&symbol_short!("renounce"));
+        env.ledger().with_mut(|li| li.timestamp += RENOUNCE_DELAY_SECONDS);
+        client.renounce_ownership(&symbol_short!("renounce"));
+
+        assert!(client.is_renounced());
+        assert!(client.is_staff_active(&staff_hash));
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::register_staff(env.clone(), owner, BytesN::from_array(&env, &[2u8; 32]))
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Renounce delay not over")]
+    fn test_renounce_before_delay() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+
+        client.begin_renounce(&symbol_short!("renounce"));
+        env.ledger().with_mut(|li| li.timestamp += RENOUNCE_DELAY_SECONDS - 1);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::renounce_ownership(env.clone(), symbol_short!("renounce"))
+        });
+    }
+}
@@ -0,0 +1,166 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, BytesN, Env, Vec};
+
+use super::*;
+
+// Anchoring is unauthenticated, so the limit is global per window (~1 hour)
+pub const REPORT_WINDOW_LEDGERS: u32 = 720;
+pub const MAX_REPORTS_PER_WINDOW: u32 = 20;
+
+// Proof-of-existence for a complaint; only the hash of the evidence goes on-chain
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Report {
+    pub id: u32,
+    pub report_hash: BytesN<32>,
+    pub anchored_at: u64,
+    pub ledger: u32,
+    pub last_batch_hash: BytesN<32>,  // payroll head at anchoring time, zero if none
+}
+
+#[contracttype]
+enum ReportKey {
+    ReportCount,
+    ReportUsage,                 // (window index, reports anchored in it)
+    Report(u32),
+    ReportByHash(BytesN<32>),    // report hash -> id
+}
+
+fn report_count(env: &Env) -> u32 {
+    storage::get(env, DataClass::Config, &ReportKey::ReportCount).unwrap_or(0)
+}
+
+fn enforce_report_limit(env: &Env) {
+    let window = env.ledger().sequence() / REPORT_WINDOW_LEDGERS;
+    let (usage_window, used): (u32, u32) = storage::get(env, DataClass::Config, &ReportKey::ReportUsage)
+        .unwrap_or((window, 0));
+    let used = if usage_window == window { used } else { 0 };
+    if used >= MAX_REPORTS_PER_WINDOW {
+        panic!("Report limit reached, try later");
+    }
+    storage::set(env, DataClass::Config, &ReportKey::ReportUsage, &(window, used + 1));
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Timestamp a complaint hash against the payroll record (anyone, no auth).
+    pub fn anchor_report(env: Env, report_hash: BytesN<32>) -> u32 {
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        if report_hash == BytesN::from_array(&env, &[0u8; 32]) {
+            panic!("Invalid report hash");
+        }
+        let index_key = ReportKey::ReportByHash(report_hash.clone());
+        if storage::has(&env, DataClass::Indexes, &index_key) {
+            panic!("Report already anchored");
+        }
+        enforce_report_limit(&env);
+
+        let id = report_count(&env);
+        let report = Report {
+            id,
+            report_hash: report_hash.clone(),
+            anchored_at: env.ledger().timestamp(),
+            ledger: env.ledger().sequence(),
+            last_batch_hash: Self::get_latest_batch(env.clone())
+                .map(|batch| batch.batch_hash)
+                .unwrap_or(BytesN::from_array(&env, &[0u8; 32])),
+        };
+        let key = ReportKey::Report(id);
+        storage::set(&env, DataClass::Records, &key, &report);
+        storage::set(&env, DataClass::Indexes, &index_key, &id);
+        storage::set(&env, DataClass::Config, &ReportKey::ReportCount, &(id + 1));
+
+        env.events().publish(
+            (symbol_short!("report"), report_hash),
+            (id, report.last_batch_hash, env.ledger().timestamp())
+        );
+
+        id
+    }
+
+    /// Get a page of anchored reports, oldest first
+    pub fn get_reports(env: Env, start: u32, limit: u32) -> Vec<Report> {
+        let end = start
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(report_count(&env));
+
+        let mut page = Vec::new(&env);
+        for id in start..end {
+            if let Some(report) = storage::get(&env, DataClass::Records, &ReportKey::Report(id)) {
+                page.push_back(report);
+            }
+        }
+        page
+    }
+
+    /// Look up when a report hash was anchored
+    pub fn get_report_by_hash(env: Env, report_hash: BytesN<32>) -> Option<Report> {
+        let id: u32 = storage::get(&env, DataClass::Indexes, &ReportKey::ReportByHash(report_hash))?;
+        storage::get(&env, DataClass::Records, &ReportKey::Report(id))
+    }
+
+    /// Get the number of anchored reports
+    pub fn get_report_count(env: Env) -> u32 {
+        report_count(&env)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env};
+
+    #[test]
+    fn test_anchor_report() {
+        let env = Env::default();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        env.mock_all_auths();
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &3);
+
+        // No auths needed
+        env.set_auths(&[]);
+        let evidence = BytesN::from_array(&env, &[7u8; 32]);
+        assert_eq!(client.anchor_report(&evidence), 0);
+        assert_eq!(client.anchor_report(&BytesN::from_array(&env, &[8u8; 32])), 1);
+
+        let reports = client.get_reports(&0, &10);
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports.get(0).unwrap().report_hash, evidence);
+        assert_eq!(reports.get(0).unwrap().last_batch_hash, batch_hash);
+        assert_eq!(client.get_reports(&1, &10).len(), 1);
+        assert_eq!(client.get_report_by_hash(&evidence).unwrap().id, 0);
+        assert_eq!(client.get_report_count(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Report limit reached, try later")]
+    fn test_report_rate_limit() {
+        let env = Env::default();
+        env.ledger().with_mut(|li| li.sequence_number = REPORT_WINDOW_LEDGERS);
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        for i in 0..MAX_REPORTS_PER_WINDOW {
+            client.anchor_report(&BytesN::from_array(&env, &[i as u8 + 1; 32]));
+        }
+
+        // The next window starts fresh
+        env.ledger().with_mut(|li| li.sequence_number += REPORT_WINDOW_LEDGERS);
+        client.anchor_report(&BytesN::from_array(&env, &[100u8; 32]));
+
+        env.as_contract(&contract_id, || {
+            for i in 0..MAX_REPORTS_PER_WINDOW {
+                StaffRegistry::anchor_report(env.clone(), BytesN::from_array(&env, &[i as u8 + 101; 32]));
+            }
+        });
+    }
+}
@@ -0,0 +1,205 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env, Vec};
+
+use super::*;
+
+// Operational roles the owner can delegate without handing over the master key
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    Registrar,        // may register and revoke staff
+    PayrollUploader,  // may record payroll batches
+    Auditor,          // may review and flag batches
+    EmergencyPauser,  // may pause registry writes during an incident
+    Regulator,        // may publish compliance findings on batches
+}
+
+// Roles explicitly granted to an address
+pub(crate) fn roles_of(env: &Env, account: &Address) -> Vec<Role> {
+    storage::get(env, DataClass::Records, &DataKey::Role(account.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+// All addresses currently holding a role, in grant order
+pub(crate) fn role_members(env: &Env, role: Role) -> Vec<Address> {
+    storage::get(env, DataClass::Indexes, &DataKey::RoleMembers(role))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_role_members(env: &Env, role: Role, members: &Vec<Address>) {
+    storage::set(env, DataClass::Indexes, &DataKey::RoleMembers(role), members);
+}
+
+pub(crate) fn grant(env: &Env, owner: &Address, account: Address, role: Role) {
+    let mut roles = roles_of(env, &account);
+    if roles.contains(role) {
+        panic!("Role already granted");
+    }
+    roles.push_back(role);
+
+    storage::set(env, DataClass::Records, &DataKey::Role(account.clone()), &roles);
+
+    let mut members = role_members(env, role);
+    members.push_back(account.clone());
+    set_role_members(env, role, &members);
+
+    audit::log_action(env, symbol_short!("role_grnt"), owner);
+
+    env.events().publish(
+        (symbol_short!("role_grnt"), account),
+        (role, env.ledger().timestamp())
+    );
+}
+
+pub(crate) fn revoke(env: &Env, owner: &Address, account: Address, role: Role) {
+    let mut roles = roles_of(env, &account);
+    let index = roles
+        .first_index_of(role)
+        .unwrap_or_else(|| panic!("Role not granted"));
+    roles.remove(index);
+
+    if roles.is_empty() {
+        storage::remove(env, DataClass::Records, &DataKey::Role(account.clone()));
+    } else {
+        storage::set(env, DataClass::Records, &DataKey::Role(account.clone()), &roles);
+    }
+
+    let mut members = role_members(env, role);
+    if let Some(member_index) = members.first_index_of(&account) {
+        members.remove(member_index);
+    }
+    set_role_members(env, role, &members);
+
+    // A revoked auditor's credential must not outlive the role
+    if role == Role::Auditor {
+        auditors::clear_credential(env, &account);
+    }
+
+    audit::log_action(env, symbol_short!("role_rev"), owner);
+
+    env.events().publish(
+        (symbol_short!("role_rev"), account),
+        (role, env.ledger().timestamp())
+    );
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Grant a role to an address (owner only)
+    pub fn grant_role(env: Env, account: Address, role: Role) {
+        let owner = auth::require_owner(&env);
+
+        grant(&env, &owner, account, role);
+    }
+
+    /// Revoke a role from an address (owner only)
+    pub fn revoke_role(env: Env, account: Address, role: Role) {
+        let owner = auth::require_owner(&env);
+
+        revoke(&env, &owner, account, role);
+    }
+
+    /// Allowlist an operational key for batch uploads (owner only)
+    pub fn add_uploader(env: Env, account: Address) {
+        Self::grant_role(env, account, Role::PayrollUploader);
+    }
+
+    /// Remove an operational key from the upload allowlist (owner only)
+    pub fn remove_uploader(env: Env, account: Address) {
+        Self::revoke_role(env, account, Role::PayrollUploader);
+    }
+
+    /// Get all allowlisted uploaders
+    pub fn get_uploaders(env: Env) -> Vec<Address> {
+        role_members(&env, Role::PayrollUploader)
+    }
+
+    /// Check whether an address has been granted a role
+    pub fn has_role(env: Env, account: Address, role: Role) -> bool {
+        roles_of(&env, &account).contains(role)
+    }
+
+    /// Get all roles granted to an address
+    pub fn get_roles(env: Env, account: Address) -> Vec<Role> {
+        roles_of(&env, &account)
+    }
+
+    /// Get all addresses holding a role
+    pub fn get_role_members(env: Env, role: Role) -> Vec<Address> {
+        role_members(&env, role)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, BytesN, Env};
+
+    #[test]
+    fn test_registrar_can_register_staff() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let registrar = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        client.grant_role(&registrar, &Role::Registrar);
+        assert!(client.has_role(&registrar, &Role::Registrar));
+        assert!(!client.has_role(&registrar, &Role::Auditor));
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_staff(&registrar, &staff_hash);
+        assert_eq!(client.get_staff_record(&staff_hash).registered_by, registrar);
+
+        assert_eq!(client.get_role_members(&Role::Registrar), Vec::from_array(&env, [registrar.clone()]));
+
+        client.revoke_role(&registrar, &Role::Registrar);
+        assert_eq!(client.get_roles(&registrar).len(), 0);
+        assert_eq!(client.get_role_members(&Role::Registrar).len(), 0);
+    }
+
+    #[test]
+    fn test_allowlisted_uploader_records_batch() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let uploader = Address::generate(&env);
+        client.initialize(&owner);
+
+        client.add_uploader(&uploader);
+        assert_eq!(client.get_uploaders(), Vec::from_array(&env, [uploader.clone()]));
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&uploader, &batch_hash, &1);
+        assert_eq!(client.get_payroll_batch(&batch_hash).uploaded_by, uploader);
+
+        client.remove_uploader(&uploader);
+        assert!(!client.has_role(&uploader, &Role::PayrollUploader));
+    }
+
+    #[test]
+    #[should_panic(expected = "Missing role")]
+    fn test_register_without_role() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+        client.set_legacy_hashes_allowed(&true);
+
+        let outsider = Address::generate(&env);
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        env.as_contract(&contract_id, || {
+            StaffRegistry::register_staff(env.clone(), outsider, staff_hash)
+        });
+    }
+}
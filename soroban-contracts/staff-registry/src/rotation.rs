@@ -0,0 +1,168 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env, IntoVal, Val, Vec};
+
+use super::*;
+
+#[contracttype]
+enum RotationKey {
+    RotatedTo(BytesN<32>),       // retired staff hash -> current hash
+    PreviousHashes(BytesN<32>),  // current hash -> retired hashes, oldest first
+}
+
+// Current hash for a staff member, following a rotation if there was one
+pub(crate) fn resolve(env: &Env, staff_hash: &BytesN<32>) -> BytesN<32> {
+    storage::get(env, DataClass::Records, &RotationKey::RotatedTo(staff_hash.clone()))
+        .unwrap_or(staff_hash.clone())
+}
+
+// Whether a hash was rotated away from; its record now lives under the new hash
+pub(crate) fn is_retired(env: &Env, staff_hash: &BytesN<32>) -> bool {
+    storage::has(env, DataClass::Records, &RotationKey::RotatedTo(staff_hash.clone()))
+}
+
+// Writes must use the current hash so nothing is stored under a retired one
+pub(crate) fn require_current(env: &Env, staff_hash: &BytesN<32>) {
+    if is_retired(env, staff_hash) {
+        panic!("Staff hash rotated");
+    }
+}
+
+// Move a persistent entry to a new key, if it exists
+pub(crate) fn move_entry<K: IntoVal<Env, Val>>(env: &Env, from: &K, to: &K) {
+    if let Some(value) = storage::get::<K, Val>(env, DataClass::Records, from) {
+        storage::set(env, DataClass::Records, to, &value);
+        storage::remove(env, DataClass::Records, from);
+    }
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Re-key a staff member after their identity data changed (owner or Registrar).
+    /// The record and its live attachments move to the new hash; the old hash keeps
+    /// resolving to it. Batch membership and acknowledgements stay under the old hash.
+    pub fn rotate_staff_hash(env: Env, caller: Address, old_hash: BytesN<32>, new_hash: BytesN<32>) {
+        // onlyOwner or Registrar
+        auth::require_role(&env, &caller, Role::Registrar);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        rotation::require_current(&env, &old_hash);
+        erasure::require_not_erased(&env, &old_hash);
+        if new_hash == BytesN::from_array(&env, &[0u8; 32]) {
+            panic!("Invalid staff hash");
+        }
+        if Self::is_staff_registered(env.clone(), new_hash.clone()) {
+            panic!("Staff already registered");
+        }
+
+        let mut record = Self::get_staff_record(env.clone(), old_hash.clone());
+        record.staff_hash = new_hash.clone();
+        storage::remove(&env, DataClass::Records, &DataKey::StaffRecord(old_hash.clone()));
+        history::save_staff_record(&env, &Scope::Main, &record);
+
+        // The new hash takes the old one's place in the registry list, indexes and tree
+        lists::replace_staff(&env, &old_hash, &new_hash);
+        indexes::rekey_staff(&env, &record, &old_hash);
+        registry_tree::rekey(&env, &old_hash, &new_hash, record.status == StaffStatus::Active);
+
+        tags::rekey_staff_tags(&env, &old_hash, &new_hash);
+        departments::rekey(&env, &old_hash, &new_hash);
+        documents::move_employment_contracts(&env, &old_hash, &new_hash);
+        sealed::rekey(&env, &old_hash, &new_hash);
+        offboarding::rekey(&env, &old_hash, &new_hash);
+        external_ids::rekey(&env, &old_hash, &new_hash);
+        salts::tag_staff(&env, &new_hash);
+        if let Some(address) = acknowledgements::bound_address(&env, &old_hash) {
+            acknowledgements::unbind(&env, &old_hash);
+            acknowledgements::bind(&env, &new_hash, &address);
+        }
+        move_entry(&env, &DataKey::SalaryCommitment(old_hash.clone()), &DataKey::SalaryCommitment(new_hash.clone()));
+
+        // Every retired hash points straight at the current one
+        let mut previous = Self::get_previous_hashes(env.clone(), old_hash.clone());
+        previous.push_back(old_hash.clone());
+        for retired in previous.iter() {
+            let key = RotationKey::RotatedTo(retired);
+            storage::set(&env, DataClass::Records, &key, &new_hash);
+        }
+        storage::remove(&env, DataClass::Records, &RotationKey::PreviousHashes(old_hash.clone()));
+        let previous_key = RotationKey::PreviousHashes(new_hash.clone());
+        storage::set(&env, DataClass::Records, &previous_key, &previous);
+
+        audit::log(&env, symbol_short!("staff_rot"), &caller, &old_hash);
+
+        events::staff_event(&env, symbol_short!("staff_rot"), &caller, &record);
+    }
+
+    /// Get the hashes a staff member was registered under before, oldest first
+    pub fn get_previous_hashes(env: Env, staff_hash: BytesN<32>) -> Vec<BytesN<32>> {
+        storage::get(&env, DataClass::Records, &RotationKey::PreviousHashes(resolve(&env, &staff_hash)))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the current hash for a staff member, given any hash they were registered under
+    pub fn get_current_staff_hash(env: Env, staff_hash: BytesN<32>) -> BytesN<32> {
+        resolve(&env, &staff_hash)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env, Symbol};
+
+    #[test]
+    fn test_rotate_staff_hash() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let first = BytesN::from_array(&env, &[1u8; 32]);
+        let second = BytesN::from_array(&env, &[2u8; 32]);
+        let third = BytesN::from_array(&env, &[3u8; 32]);
+        let tag = symbol_short!("remote");
+        client.register_staff(&owner, &first);
+        client.set_staff_tags(&owner, &first, &Vec::from_array(&env, [tag.clone()]));
+        let root = client.compute_registry_root();
+
+        client.rotate_staff_hash(&owner, &first, &second);
+        client.rotate_staff_hash(&owner, &second, &third);
+
+        assert_eq!(client.get_staff_record(&first).staff_hash, third);
+        assert!(client.is_staff_active(&first));
+        assert_eq!(client.get_current_staff_hash(&second), third);
+        assert_eq!(client.get_previous_hashes(&first), Vec::from_array(&env, [first.clone(), second.clone()]));
+        assert_eq!(client.get_staff_hashes(&0, &10), Vec::from_array(&env, [third.clone()]));
+        assert_eq!(client.get_total_staff(), 1);
+        assert_eq!(client.get_staff_tags(&third), Vec::<Symbol>::from_array(&env, [tag.clone()]));
+        assert_eq!(client.get_staff_by_tag(&tag, &0, &10), Vec::from_array(&env, [third.clone()]));
+        assert_ne!(client.compute_registry_root(), root);
+    }
+
+    #[test]
+    #[should_panic(expected = "Staff hash rotated")]
+    fn test_retired_hash_is_read_only() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let old_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_staff(&owner, &old_hash);
+        client.rotate_staff_hash(&owner, &old_hash, &BytesN::from_array(&env, &[2u8; 32]));
+
+        env.as_contract(&contract_id, || StaffRegistry::revoke_staff(env.clone(), owner, old_hash));
+    }
+}
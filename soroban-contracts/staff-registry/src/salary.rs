@@ -0,0 +1,178 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env};
+
+use super::*;
+
+// Hidden salary figure for one staff member, revealed after a disclosure deadline
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SalaryCommitment {
+    pub commitment: BytesN<32>,     // sha256(salary as 16-byte big-endian i128 || salt)
+    pub committed_by: Address,
+    pub committed_at: u64,
+    pub disclose_after: u64,
+    pub revealed_salary: Option<i128>,
+    pub revealed_at: Option<u64>,
+}
+
+// Commitment preimage layout shared with off-chain tooling
+pub(crate) fn salary_commitment(env: &Env, salary: i128, salt: &BytesN<32>) -> BytesN<32> {
+    let mut preimage = Bytes::from_array(env, &salary.to_be_bytes());
+    preimage.extend_from_array(&salt.to_array());
+    env.crypto().sha256(&preimage)
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Commit to a staff member's salary without disclosing it until `disclose_after`
+    pub fn commit_salary(env: Env, caller: Address, staff_hash: BytesN<32>, commitment: BytesN<32>, disclose_after: u64) {
+        // onlyOwner or Registrar
+        auth::require_role(&env, &caller, Role::Registrar);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        rotation::require_current(&env, &staff_hash);
+        erasure::require_not_erased(&env, &staff_hash);
+
+        if !Self::is_staff_registered(env.clone(), staff_hash.clone()) {
+            panic!("Staff not found");
+        }
+        if commitment == BytesN::from_array(&env, &[0u8; 32]) {
+            panic!("Invalid commitment");
+        }
+        if disclose_after <= env.ledger().timestamp() {
+            panic!("Disclosure deadline must be in the future");
+        }
+
+        // A pending commitment can't be silently replaced before it is revealed
+        let key = DataKey::SalaryCommitment(staff_hash.clone());
+        if let Some(existing) = storage::get::<DataKey, SalaryCommitment>(&env, DataClass::Records, &key) {
+            if existing.revealed_salary.is_none() {
+                panic!("Salary commitment pending");
+            }
+        }
+
+        let entry = SalaryCommitment {
+            commitment: commitment.clone(),
+            committed_by: caller.clone(),
+            committed_at: env.ledger().timestamp(),
+            disclose_after,
+            revealed_salary: None,
+            revealed_at: None,
+        };
+        storage::set(&env, DataClass::Records, &key, &entry);
+
+        audit::log(&env, symbol_short!("sal_com"), &caller, &staff_hash);
+
+        env.events().publish(
+            (symbol_short!("sal_com"), staff_hash),
+            (commitment, caller, disclose_after)
+        );
+    }
+
+    /// Reveal a committed salary once the deadline has passed; anyone holding the opening may call
+    pub fn reveal_salary(env: Env, staff_hash: BytesN<32>, salary: i128, salt: BytesN<32>) {
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+
+        let key = DataKey::SalaryCommitment(staff_hash.clone());
+        let mut entry = Self::get_salary_commitment(env.clone(), staff_hash.clone());
+
+        if entry.revealed_salary.is_some() {
+            panic!("Salary already revealed");
+        }
+        if env.ledger().timestamp() < entry.disclose_after {
+            panic!("Disclosure deadline not reached");
+        }
+        if salary < 0 {
+            panic!("Invalid salary");
+        }
+        if salary_commitment(&env, salary, &salt) != entry.commitment {
+            panic!("Commitment mismatch");
+        }
+
+        entry.revealed_salary = Some(salary);
+        entry.revealed_at = Some(env.ledger().timestamp());
+        storage::set(&env, DataClass::Records, &key, &entry);
+
+        env.events().publish(
+            (symbol_short!("sal_rev"), staff_hash),
+            (salary, env.ledger().timestamp())
+        );
+    }
+
+    /// Get the salary commitment of a staff member
+    pub fn get_salary_commitment(env: Env, staff_hash: BytesN<32>) -> SalaryCommitment {
+        storage::get(&env, DataClass::Records, &DataKey::SalaryCommitment(staff_hash))
+            .unwrap_or_else(|| panic!("Salary commitment not found"))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Ledger}, Env};
+
+    fn setup(env: &Env) -> (StaffRegistryClient<'_>, Address, Address, BytesN<32>) {
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(env, &contract_id);
+
+        let owner = Address::generate(env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(env, &[1u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+
+        (client, contract_id, owner, staff_hash)
+    }
+
+    #[test]
+    fn test_commit_and_reveal_salary() {
+        let env = Env::default();
+        let (client, _, owner, staff_hash) = setup(&env);
+
+        let salt = BytesN::from_array(&env, &[9u8; 32]);
+        let commitment = salary_commitment(&env, 450_000, &salt);
+        client.commit_salary(&owner, &staff_hash, &commitment, &1_000);
+
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+        client.reveal_salary(&staff_hash, &450_000, &salt);
+
+        let entry = client.get_salary_commitment(&staff_hash);
+        assert_eq!(entry.revealed_salary, Some(450_000));
+        assert_eq!(entry.revealed_at, Some(1_000));
+    }
+
+    #[test]
+    #[should_panic(expected = "Disclosure deadline not reached")]
+    fn test_reveal_before_deadline() {
+        let env = Env::default();
+        let (client, contract_id, owner, staff_hash) = setup(&env);
+
+        let salt = BytesN::from_array(&env, &[9u8; 32]);
+        client.commit_salary(&owner, &staff_hash, &salary_commitment(&env, 450_000, &salt), &1_000);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::reveal_salary(env.clone(), staff_hash, 450_000, salt)
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Commitment mismatch")]
+    fn test_reveal_wrong_salary() {
+        let env = Env::default();
+        let (client, contract_id, owner, staff_hash) = setup(&env);
+
+        let salt = BytesN::from_array(&env, &[9u8; 32]);
+        client.commit_salary(&owner, &staff_hash, &salary_commitment(&env, 450_000, &salt), &1_000);
+        env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::reveal_salary(env.clone(), staff_hash, 900_000, salt)
+        });
+    }
+}
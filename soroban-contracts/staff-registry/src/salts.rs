@@ -0,0 +1,163 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env, Symbol};
+
+use super::*;
+
+// Period during which staff hashes are derived with one salt. Epoch 0 covers
+// everything registered before the first rotation and has no commitment.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SaltEpoch {
+    pub epoch: u32,
+    pub salt_commitment: BytesN<32>,  // digest of the salt in the active hash mode
+    pub started_by: Address,
+    pub started_at: u64,
+}
+
+#[contracttype]
+enum SaltKey {
+    CurrentSaltEpoch,
+    SaltCommitment(u32),                    // epoch -> SaltEpoch
+    StaffSaltEpoch(BytesN<32>),
+    OrgStaffSaltEpoch(Symbol, BytesN<32>),
+}
+
+pub(crate) fn current_epoch(env: &Env) -> u32 {
+    storage::get(env, DataClass::Config, &SaltKey::CurrentSaltEpoch).unwrap_or(0)
+}
+
+// Stamp a newly derived main-registry hash with the salt epoch it was made under
+pub(crate) fn tag_staff(env: &Env, staff_hash: &BytesN<32>) {
+    let epoch = current_epoch(env);
+    if epoch > 0 {
+        let key = SaltKey::StaffSaltEpoch(staff_hash.clone());
+        storage::set(env, DataClass::Records, &key, &epoch);
+    }
+}
+
+pub(crate) fn tag_org_staff(env: &Env, org_id: &Symbol, staff_hash: &BytesN<32>) {
+    let epoch = current_epoch(env);
+    if epoch > 0 {
+        let key = SaltKey::OrgStaffSaltEpoch(org_id.clone(), staff_hash.clone());
+        storage::set(env, DataClass::Records, &key, &epoch);
+    }
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Start a new salt epoch; staff registered from now on are tagged with it (owner only).
+    /// Returns the new epoch number.
+    pub fn rotate_salt_epoch(env: Env, new_salt_commitment: BytesN<32>) -> u32 {
+        let owner = auth::require_owner(&env);
+        ttl::extend_instance(&env);
+
+        if new_salt_commitment == BytesN::from_array(&env, &[0u8; 32]) {
+            panic!("Invalid salt commitment");
+        }
+        let previous = current_epoch(&env);
+        if Self::get_salt_epoch(env.clone(), previous).is_some_and(|e| e.salt_commitment == new_salt_commitment) {
+            panic!("Salt not rotated");
+        }
+
+        let epoch = previous + 1;
+        let salt_epoch = SaltEpoch {
+            epoch,
+            salt_commitment: new_salt_commitment.clone(),
+            started_by: owner.clone(),
+            started_at: env.ledger().timestamp(),
+        };
+        let key = SaltKey::SaltCommitment(epoch);
+        storage::set(&env, DataClass::Records, &key, &salt_epoch);
+        storage::set(&env, DataClass::Config, &SaltKey::CurrentSaltEpoch, &epoch);
+
+        audit::log_action(&env, symbol_short!("salt_rot"), &owner);
+
+        env.events().publish(
+            (symbol_short!("salt_rot"), epoch),
+            (new_salt_commitment, salt_epoch.started_at)
+        );
+
+        epoch
+    }
+
+    /// Get the current salt epoch (0 until the first rotation)
+    pub fn get_current_salt_epoch(env: Env) -> u32 {
+        current_epoch(&env)
+    }
+
+    /// Get a salt epoch's commitment and start time
+    pub fn get_salt_epoch(env: Env, epoch: u32) -> Option<SaltEpoch> {
+        storage::get(&env, DataClass::Records, &SaltKey::SaltCommitment(epoch))
+    }
+
+    /// Check a disclosed salt against an epoch's commitment
+    pub fn verify_salt(env: Env, epoch: u32, salt: Bytes) -> bool {
+        Self::get_salt_epoch(env.clone(), epoch)
+            .is_some_and(|e| e.salt_commitment == hashing::digest(&env, hashing::hash_mode(&env), &salt))
+    }
+
+    /// Get the salt epoch a staff hash was derived under
+    pub fn get_staff_salt_epoch(env: Env, staff_hash: BytesN<32>) -> u32 {
+        storage::get(&env, DataClass::Records, &SaltKey::StaffSaltEpoch(rotation::resolve(&env, &staff_hash)))
+            .unwrap_or(0)
+    }
+
+    /// Get the salt epoch a staff hash in an organization's namespace was derived under
+    pub fn org_get_staff_salt_epoch(env: Env, org_id: Symbol, staff_hash: BytesN<32>) -> u32 {
+        storage::get(&env, DataClass::Records, &SaltKey::OrgStaffSaltEpoch(org_id, staff_hash))
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_salt_epochs() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let legacy = BytesN::from_array(&env, &[1u8; 32]);
+        let hired = BytesN::from_array(&env, &[2u8; 32]);
+        let rekeyed = BytesN::from_array(&env, &[3u8; 32]);
+        client.register_staff(&owner, &legacy);
+
+        let salt = Bytes::from_slice(&env, b"2026-q3-salt");
+        assert_eq!(client.rotate_salt_epoch(&env.crypto().sha256(&salt)), 1);
+        client.register_staff(&owner, &hired);
+
+        assert_eq!(client.get_current_salt_epoch(), 1);
+        assert_eq!(client.get_staff_salt_epoch(&legacy), 0);
+        assert_eq!(client.get_staff_salt_epoch(&hired), 1);
+        assert!(client.verify_salt(&1, &salt));
+        assert!(!client.verify_salt(&1, &Bytes::from_slice(&env, b"guess")));
+
+        // Re-deriving a legacy hash under the current salt moves it into the current epoch
+        client.rotate_staff_hash(&owner, &legacy, &rekeyed);
+        assert_eq!(client.get_staff_salt_epoch(&legacy), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Salt not rotated")]
+    fn test_salt_must_change() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+
+        let commitment = BytesN::from_array(&env, &[5u8; 32]);
+        client.rotate_salt_epoch(&commitment);
+
+        env.as_contract(&contract_id, || StaffRegistry::rotate_salt_epoch(env.clone(), commitment));
+    }
+}
@@ -0,0 +1,211 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env, Symbol};
+
+use super::*;
+
+// Recurring payroll cadence; period n covers ledgers [start + n * len, start + (n + 1) * len)
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Schedule {
+    pub period_length: u32,         // in ledgers
+    pub expected_staff_count: u32,
+    pub start_ledger: u32,
+    pub next_period: u32,           // earliest period still awaiting a batch
+    pub missed_periods: u32,        // periods that closed with no batch
+}
+
+fn load_schedule(env: &Env) -> Schedule {
+    storage::get(env, DataClass::Config, &DataKey::Schedule)
+        .unwrap_or_else(|| panic!("No active schedule"))
+}
+
+fn current_period(env: &Env, schedule: &Schedule) -> u32 {
+    (env.ledger().sequence() - schedule.start_ledger) / schedule.period_length
+}
+
+// Mark every closed period before the current one as missed and emit a single event for the range
+fn close_missed_periods(env: &Env, schedule: &mut Schedule) -> u32 {
+    let current = current_period(env, schedule);
+    if current <= schedule.next_period {
+        return 0;
+    }
+
+    let missed = current - schedule.next_period;
+    env.events().publish(
+        (Symbol::new(env, "schedule_missed"),),
+        (schedule.next_period, current - 1, env.ledger().timestamp())
+    );
+
+    schedule.missed_periods += missed;
+    schedule.next_period = current;
+    missed
+}
+
+// Keeper variant of check_schedule: a no-op when no schedule exists
+pub(crate) fn close_due_periods(env: &Env) -> u32 {
+    let mut schedule: Schedule = match storage::get(env, DataClass::Config, &DataKey::Schedule) {
+        Some(schedule) => schedule,
+        None => return 0,
+    };
+    let missed = close_missed_periods(env, &mut schedule);
+    if missed > 0 {
+        storage::set(env, DataClass::Config, &DataKey::Schedule, &schedule);
+    }
+    missed
+}
+
+// Start a schedule from the current ledger, replacing any previous one
+pub(crate) fn apply_schedule(env: &Env, owner: &Address, period_length_ledgers: u32, expected_staff_count: u32) {
+    if period_length_ledgers == 0 || expected_staff_count == 0 {
+        panic!("Invalid schedule");
+    }
+
+    storage::set(env, DataClass::Config, &DataKey::Schedule, &Schedule {
+        period_length: period_length_ledgers,
+        expected_staff_count,
+        start_ledger: env.ledger().sequence(),
+        next_period: 0,
+        missed_periods: 0,
+    });
+
+    audit::log_action(env, symbol_short!("sched_new"), owner);
+
+    env.events().publish(
+        (symbol_short!("sched_new"),),
+        (period_length_ledgers, expected_staff_count, env.ledger().timestamp())
+    );
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Start a recurring payroll schedule from the current ledger, replacing any previous one (owner only)
+    pub fn create_schedule(env: Env, period_length_ledgers: u32, expected_staff_count: u32) {
+        let owner = auth::require_owner(&env);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        apply_schedule(&env, &owner, period_length_ledgers, expected_staff_count);
+    }
+
+    /// Get the active payroll schedule
+    pub fn get_schedule(env: Env) -> Schedule {
+        load_schedule(&env)
+    }
+
+    /// Check whether the current period still needs its batch
+    pub fn is_batch_due(env: Env) -> bool {
+        let schedule = load_schedule(&env);
+        current_period(&env, &schedule) >= schedule.next_period
+    }
+
+    /// Record the batch for the current schedule period
+    pub fn record_scheduled_batch(env: Env, caller: Address, batch_hash: BytesN<32>, staff_count: u32, period: u32) {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        let mut schedule = load_schedule(&env);
+        close_missed_periods(&env, &mut schedule);
+
+        if period < schedule.next_period {
+            panic!("Period already recorded");
+        }
+        if period != current_period(&env, &schedule) {
+            panic!("Wrong period");
+        }
+        if staff_count != schedule.expected_staff_count {
+            panic!("Unexpected staff count");
+        }
+
+        store_new_batch(&env, &new_batch(&env, caller.clone(), batch_hash.clone(), staff_count));
+
+        let key = DataKey::ScheduledBatch(period);
+        storage::set(&env, DataClass::Records, &key, &batch_hash);
+
+        schedule.next_period = period + 1;
+        storage::set(&env, DataClass::Config, &DataKey::Schedule, &schedule);
+
+        env.events().publish(
+            (symbol_short!("sched_rec"), batch_hash),
+            (caller, period, env.ledger().timestamp())
+        );
+    }
+
+    /// Flag periods that closed without a batch; callable by anyone (e.g. a keeper)
+    pub fn check_schedule(env: Env) -> u32 {
+        ttl::extend_instance(&env);
+
+        let mut schedule = load_schedule(&env);
+        let missed = close_missed_periods(&env, &mut schedule);
+        if missed > 0 {
+            storage::set(&env, DataClass::Config, &DataKey::Schedule, &schedule);
+        }
+        missed
+    }
+
+    /// Get the batch recorded for a schedule period
+    pub fn get_scheduled_batch(env: Env, period: u32) -> Option<BytesN<32>> {
+        storage::get(&env, DataClass::Records, &DataKey::ScheduledBatch(period))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Ledger}, Env};
+
+    #[test]
+    fn test_schedule_detects_missed_period() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.sequence_number = 1_000);
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.create_schedule(&100, &5);
+        assert!(client.is_batch_due());
+
+        let first = BytesN::from_array(&env, &[1u8; 32]);
+        client.record_scheduled_batch(&owner, &first, &5, &0);
+        assert!(!client.is_batch_due());
+        assert_eq!(client.get_scheduled_batch(&0), Some(first));
+
+        // Period 1 passes with nothing recorded
+        env.ledger().with_mut(|li| li.sequence_number = 1_250);
+        assert_eq!(client.check_schedule(), 1);
+
+        let third = BytesN::from_array(&env, &[3u8; 32]);
+        client.record_scheduled_batch(&owner, &third, &5, &2);
+
+        let schedule = client.get_schedule();
+        assert_eq!(schedule.next_period, 3);
+        assert_eq!(schedule.missed_periods, 1);
+        assert_eq!(client.get_scheduled_batch(&1), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Wrong period")]
+    fn test_scheduled_batch_too_early() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.create_schedule(&100, &5);
+
+        let batch_hash = BytesN::from_array(&env, &[1u8; 32]);
+        env.as_contract(&contract_id, || {
+            StaffRegistry::record_scheduled_batch(env.clone(), owner, batch_hash, 5, 1)
+        });
+    }
+}
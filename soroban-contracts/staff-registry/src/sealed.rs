@@ -0,0 +1,168 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env, Vec};
+
+use super::*;
+
+// Ciphertext size cap per payload; keeps a single entry well inside ledger limits
+pub const MAX_SEALED_PAYLOAD_BYTES: u32 = 4096;
+
+// Most viewer keys a staff member's payloads can be sealed to
+pub const MAX_SEALED_VIEWERS: u32 = 10;
+
+// Encrypted blob (e.g. a payslip) readable only with the viewer's decryption key.
+// Everything on-chain is public; confidentiality rests entirely on the encryption.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SealedPayload {
+    pub ciphertext: Bytes,
+    pub viewer_key_id: BytesN<32>,  // identifies the key the blob is sealed to (e.g. hash of its public key)
+    pub stored_by: Address,
+    pub stored_at: u64,
+}
+
+#[contracttype]
+enum SealedKey {
+    SealedPayload(BytesN<32>, BytesN<32>),  // (staff, viewer key id) latest payload
+    SealedViewers(BytesN<32>),              // viewer key ids holding a payload for a staff member
+}
+
+// Drop every payload sealed for a staff member
+pub(crate) fn erase(env: &Env, staff_hash: &BytesN<32>) {
+    let viewers_key = SealedKey::SealedViewers(staff_hash.clone());
+    let viewers: Vec<BytesN<32>> = storage::get(env, DataClass::Records, &viewers_key).unwrap_or(Vec::new(env));
+    for viewer_key_id in viewers.iter() {
+        storage::remove(env, DataClass::Records, &SealedKey::SealedPayload(staff_hash.clone(), viewer_key_id));
+    }
+    storage::remove(env, DataClass::Records, &viewers_key);
+}
+
+// Carry a rotated staff hash's sealed payloads over to its replacement
+pub(crate) fn rekey(env: &Env, old_hash: &BytesN<32>, new_hash: &BytesN<32>) {
+    let viewers_key = SealedKey::SealedViewers(old_hash.clone());
+    let viewers: Vec<BytesN<32>> = storage::get(env, DataClass::Records, &viewers_key).unwrap_or(Vec::new(env));
+    for viewer_key_id in viewers.iter() {
+        rotation::move_entry(
+            env,
+            &SealedKey::SealedPayload(old_hash.clone(), viewer_key_id.clone()),
+            &SealedKey::SealedPayload(new_hash.clone(), viewer_key_id),
+        );
+    }
+    rotation::move_entry(env, &viewers_key, &SealedKey::SealedViewers(new_hash.clone()));
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Store (or replace) the encrypted payload for a staff member sealed to one viewer key
+    pub fn store_sealed_payload(env: Env, caller: Address, staff_hash: BytesN<32>, ciphertext: Bytes, viewer_key_id: BytesN<32>) {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        rotation::require_current(&env, &staff_hash);
+        erasure::require_not_erased(&env, &staff_hash);
+
+        if !Self::is_staff_registered(env.clone(), staff_hash.clone()) {
+            panic!("Staff not found");
+        }
+        if ciphertext.is_empty() || ciphertext.len() > MAX_SEALED_PAYLOAD_BYTES {
+            panic!("Invalid payload size");
+        }
+        if viewer_key_id == BytesN::from_array(&env, &[0u8; 32]) {
+            panic!("Invalid viewer key");
+        }
+
+        let mut viewers = Self::get_sealed_viewers(env.clone(), staff_hash.clone());
+        if !viewers.contains(&viewer_key_id) {
+            if viewers.len() >= MAX_SEALED_VIEWERS {
+                panic!("Too many viewers");
+            }
+            viewers.push_back(viewer_key_id.clone());
+            let viewers_key = SealedKey::SealedViewers(staff_hash.clone());
+            storage::set(&env, DataClass::Records, &viewers_key, &viewers);
+        }
+
+        let key = SealedKey::SealedPayload(staff_hash.clone(), viewer_key_id.clone());
+        let payload = SealedPayload {
+            ciphertext,
+            viewer_key_id: viewer_key_id.clone(),
+            stored_by: caller.clone(),
+            stored_at: env.ledger().timestamp(),
+        };
+        storage::set(&env, DataClass::Records, &key, &payload);
+
+        audit::log(&env, symbol_short!("sealed"), &caller, &staff_hash);
+
+        env.events().publish(
+            (symbol_short!("sealed"), staff_hash),
+            (viewer_key_id, payload.ciphertext.len(), env.ledger().timestamp())
+        );
+    }
+
+    /// Get the payload for a staff member sealed to a viewer key
+    pub fn get_sealed_payload(env: Env, staff_hash: BytesN<32>, viewer_key_id: BytesN<32>) -> Option<SealedPayload> {
+        storage::get(&env, DataClass::Records, &SealedKey::SealedPayload(staff_hash, viewer_key_id))
+    }
+
+    /// Get the viewer keys a staff member's payloads are sealed to
+    pub fn get_sealed_viewers(env: Env, staff_hash: BytesN<32>) -> Vec<BytesN<32>> {
+        storage::get(&env, DataClass::Records, &SealedKey::SealedViewers(staff_hash))
+            .unwrap_or(Vec::new(&env))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_sealed_payloads_per_viewer() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+
+        let auditor_key = BytesN::from_array(&env, &[7u8; 32]);
+        let employee_key = BytesN::from_array(&env, &[8u8; 32]);
+        client.store_sealed_payload(&owner, &staff_hash, &Bytes::from_slice(&env, b"sealed-for-auditor"), &auditor_key);
+        client.store_sealed_payload(&owner, &staff_hash, &Bytes::from_slice(&env, b"sealed-for-employee"), &employee_key);
+        client.store_sealed_payload(&owner, &staff_hash, &Bytes::from_slice(&env, b"reissued-for-auditor"), &auditor_key);
+
+        assert_eq!(client.get_sealed_viewers(&staff_hash), Vec::from_array(&env, [auditor_key.clone(), employee_key]));
+        let payload = client.get_sealed_payload(&staff_hash, &auditor_key).unwrap();
+        assert_eq!(payload.ciphertext, Bytes::from_slice(&env, b"reissued-for-auditor"));
+        assert_eq!(client.get_sealed_payload(&staff_hash, &BytesN::from_array(&env, &[9u8; 32])), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid payload size")]
+    fn test_oversized_payload() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+
+        let ciphertext = Bytes::from_array(&env, &[0u8; (MAX_SEALED_PAYLOAD_BYTES + 1) as usize]);
+        env.as_contract(&contract_id, || {
+            StaffRegistry::store_sealed_payload(env.clone(), owner, staff_hash, ciphertext, BytesN::from_array(&env, &[7u8; 32]))
+        });
+    }
+}
@@ -0,0 +1,103 @@
+use soroban_sdk::{contractimpl, Address, BytesN, Env, Vec};
+
+use super::*;
+
+// Staff hash for an authenticated employee; the salted hash never has to leave the registry
+fn require_own_hash(env: &Env, address: &Address) -> BytesN<32> {
+    address.require_auth();
+
+    acknowledgements::bound_staff(env, address).unwrap_or_else(|| panic!("Staff address not bound"))
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Get the staff record bound to the calling address (bound staff address only)
+    pub fn get_my_record(env: Env, address: Address) -> StaffRecord {
+        let staff_hash = require_own_hash(&env, &address);
+
+        Self::get_staff_record(env, staff_hash)
+    }
+
+    /// Get a page of the batches the calling staff member was paid in, oldest first,
+    /// including batches paid under hashes they were registered under before (bound staff address only)
+    pub fn get_my_payment_history(env: Env, address: Address, start: u32, limit: u32) -> Vec<PayrollBatch> {
+        let staff_hash = require_own_hash(&env, &address);
+
+        let mut hashes = Self::get_previous_hashes(env.clone(), staff_hash.clone());
+        hashes.push_back(staff_hash);
+
+        // Page through the hashes' batch lists as if they were one list, oldest hash first
+        let limit = limit.min(MAX_PAGE_SIZE);
+        let mut skip = start;
+        let mut history = Vec::new(&env);
+        for hash in hashes.iter() {
+            let key = DataKey::StaffBatches(hash);
+            let len = lists::keyed_len(&env, DataClass::Indexes, &key);
+            let offset = skip.min(len);
+            skip -= offset;
+
+            let wanted = limit - history.len();
+            let batches: Vec<BytesN<32>> = lists::keyed_range(&env, DataClass::Indexes, &key, offset, offset.saturating_add(wanted));
+            for batch_hash in batches.iter() {
+                history.push_back(Self::get_payroll_batch(env.clone(), batch_hash));
+            }
+            if history.len() >= limit {
+                break;
+            }
+        }
+
+        history
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_my_record_and_payment_history() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let employee = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let rotated = BytesN::from_array(&env, &[2u8; 32]);
+        let january = BytesN::from_array(&env, &[3u8; 32]);
+        let february = BytesN::from_array(&env, &[4u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        client.bind_staff_address(&owner, &staff_hash, &employee);
+        client.record_batch_with_members(&owner, &january, &Vec::from_array(&env, [staff_hash.clone()]));
+
+        // The binding follows a rotation, and pay under the old hash stays visible
+        client.rotate_staff_hash(&owner, &staff_hash, &rotated);
+        client.record_batch_with_members(&owner, &february, &Vec::from_array(&env, [rotated.clone()]));
+
+        assert_eq!(client.get_my_record(&employee).staff_hash, rotated);
+        let history = client.get_my_payment_history(&employee, &0, &10);
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.get(0).unwrap().batch_hash, january);
+        assert_eq!(history.get(1).unwrap().batch_hash, february);
+        assert_eq!(client.get_my_payment_history(&employee, &1, &10).len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Staff address not bound")]
+    fn test_unbound_address() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+
+        env.as_contract(&contract_id, || StaffRegistry::get_my_record(env.clone(), Address::generate(&env)));
+    }
+}
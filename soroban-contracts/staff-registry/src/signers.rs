@@ -0,0 +1,140 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, xdr::ToXdr, Bytes, BytesN, Env};
+
+use super::*;
+
+// Domain tag prefixed to every signed batch payload
+const BATCH_PAYLOAD_TAG: &[u8] = b"payroll-batch-v1";
+
+#[contracttype]
+enum SignerKey {
+    BatchSigner(BytesN<32>), // ed25519 key trusted to pre-sign batches
+}
+
+// Canonical payload: tag || contract address (XDR) || batch_hash || staff_count (big-endian).
+// Binding the contract address stops a signature being replayed on another deployment.
+pub(crate) fn batch_payload(env: &Env, batch_hash: &BytesN<32>, staff_count: u32) -> Bytes {
+    let mut payload = Bytes::from_slice(env, BATCH_PAYLOAD_TAG);
+    payload.append(&env.current_contract_address().to_xdr(env));
+    payload.extend_from_array(&batch_hash.to_array());
+    payload.extend_from_array(&staff_count.to_be_bytes());
+    payload
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Trust an ed25519 key to pre-sign payroll batches (owner only)
+    pub fn add_batch_signer(env: Env, pubkey: BytesN<32>) {
+        let owner = auth::require_owner(&env);
+
+        let key = SignerKey::BatchSigner(pubkey.clone());
+        storage::set(&env, DataClass::Records, &key, &true);
+
+        audit::log(&env, symbol_short!("signr_add"), &owner, &pubkey);
+
+        env.events().publish(
+            (symbol_short!("signr_add"), pubkey),
+            (owner, env.ledger().timestamp())
+        );
+    }
+
+    /// Stop trusting a batch signing key (owner only)
+    pub fn remove_batch_signer(env: Env, pubkey: BytesN<32>) {
+        let owner = auth::require_owner(&env);
+
+        let key = SignerKey::BatchSigner(pubkey.clone());
+        if !storage::has(&env, DataClass::Records, &key) {
+            panic!("Unknown signer");
+        }
+        storage::remove(&env, DataClass::Records, &key);
+
+        audit::log(&env, symbol_short!("signr_rem"), &owner, &pubkey);
+
+        env.events().publish(
+            (symbol_short!("signr_rem"), pubkey),
+            (owner, env.ledger().timestamp())
+        );
+    }
+
+    /// Check whether a key may sign batches
+    pub fn is_batch_signer(env: Env, pubkey: BytesN<32>) -> bool {
+        storage::has(&env, DataClass::Records, &SignerKey::BatchSigner(pubkey))
+    }
+
+    /// Record a batch pre-signed by a trusted ed25519 key; anyone may relay it
+    pub fn record_batch_with_signature(env: Env, batch_hash: BytesN<32>, staff_count: u32, pubkey: BytesN<32>, sig: BytesN<64>) {
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        if !Self::is_batch_signer(env.clone(), pubkey.clone()) {
+            panic!("Unknown signer");
+        }
+
+        // Traps on a bad signature
+        env.crypto().ed25519_verify(&pubkey, &batch_payload(&env, &batch_hash, staff_count), &sig);
+
+        store_new_batch(&env, &new_batch(&env, env.current_contract_address(), batch_hash.clone(), staff_count));
+
+        env.events().publish(
+            (symbol_short!("batch_sig"), batch_hash),
+            (pubkey, env.ledger().timestamp())
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+    fn sign(env: &Env, contract_id: &Address, key: &SigningKey, batch_hash: &BytesN<32>, staff_count: u32) -> BytesN<64> {
+        let payload = env.as_contract(contract_id, || batch_payload(env, batch_hash, staff_count));
+        let mut message = [0u8; 128];
+        let len = payload.len() as usize;
+        payload.copy_into_slice(&mut message[..len]);
+        BytesN::from_array(env, &key.sign(&message[..len]).to_bytes())
+    }
+
+    #[test]
+    fn test_record_batch_with_signature() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+
+        let key = SigningKey::from_bytes(&[5u8; 32]);
+        let pubkey = BytesN::from_array(&env, &key.verifying_key().to_bytes());
+        client.add_batch_signer(&pubkey);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        let sig = sign(&env, &contract_id, &key, &batch_hash, 8);
+
+        // Submitted by an unprivileged relayer: no auth is required
+        env.set_auths(&[]);
+        client.record_batch_with_signature(&batch_hash, &8, &pubkey, &sig);
+        assert_eq!(client.get_payroll_batch(&batch_hash).staff_count, 8);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown signer")]
+    fn test_record_batch_with_untrusted_key() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+
+        let key = SigningKey::from_bytes(&[5u8; 32]);
+        let pubkey = BytesN::from_array(&env, &key.verifying_key().to_bytes());
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        let sig = sign(&env, &contract_id, &key, &batch_hash, 8);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::record_batch_with_signature(env.clone(), batch_hash, 8, pubkey, sig)
+        });
+    }
+}
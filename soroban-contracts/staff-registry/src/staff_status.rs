@@ -0,0 +1,196 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env, Map, Symbol};
+
+use super::*;
+
+// Employment lifecycle of a staff member
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StaffStatus {
+    Active,
+    Suspended,
+    OnLeave,
+    Terminated,
+}
+
+#[contracttype]
+enum StatusKey {
+    StatusCounts,             // Map<StaffStatus, u32> headcount per status
+    OrgStatusCounts(Symbol),  // the same headcounts for an org namespace
+}
+
+fn counts_key(scope: &Scope) -> StatusKey {
+    match scope {
+        Scope::Main => StatusKey::StatusCounts,
+        Scope::Org(org_id) => StatusKey::OrgStatusCounts(org_id.clone()),
+    }
+}
+
+// Allowed lifecycle moves; Terminated staff can only come back as Active (a rehire)
+fn can_transition(from: StaffStatus, to: StaffStatus) -> bool {
+    matches!(
+        (from, to),
+        (StaffStatus::Active, StaffStatus::Suspended)
+            | (StaffStatus::Active, StaffStatus::OnLeave)
+            | (StaffStatus::Active, StaffStatus::Terminated)
+            | (StaffStatus::Suspended, StaffStatus::Active)
+            | (StaffStatus::Suspended, StaffStatus::Terminated)
+            | (StaffStatus::OnLeave, StaffStatus::Active)
+            | (StaffStatus::OnLeave, StaffStatus::Terminated)
+            | (StaffStatus::Terminated, StaffStatus::Active)
+    )
+}
+
+fn status_event(status: StaffStatus) -> Symbol {
+    match status {
+        StaffStatus::Active => symbol_short!("staff_act"),
+        StaffStatus::Suspended => symbol_short!("staff_sus"),
+        StaffStatus::OnLeave => symbol_short!("staff_lve"),
+        StaffStatus::Terminated => symbol_short!("staff_trm"),
+    }
+}
+
+// Main-registry headcounts sit with the config; each org's are a record of their own
+fn counts_class(scope: &Scope) -> DataClass {
+    match scope {
+        Scope::Main => DataClass::Config,
+        Scope::Org(_) => DataClass::Records,
+    }
+}
+
+pub(crate) fn status_counts(env: &Env, scope: &Scope) -> Map<StaffStatus, u32> {
+    storage::get(env, counts_class(scope), &counts_key(scope))
+        .unwrap_or(Map::new(env))
+}
+
+pub(crate) fn set_status_counts(env: &Env, scope: &Scope, counts: &Map<StaffStatus, u32>) {
+    storage::set(env, counts_class(scope), &counts_key(scope), counts);
+}
+
+// Count a newly registered record under its status
+pub(crate) fn count_new(env: &Env, scope: &Scope, status: StaffStatus) {
+    if let Scope::Main = scope {
+        if upgrade::recount_pending(env) {
+            return;
+        }
+    }
+
+    let mut counts = status_counts(env, scope);
+    counts.set(status, counts.get(status).unwrap_or(0) + 1);
+    set_status_counts(env, scope, &counts);
+}
+
+// Move a record to a new status, keeping its namespace's headcounts in step
+pub(crate) fn apply(env: &Env, scope: &Scope, record: &mut StaffRecord, to: StaffStatus) {
+    if !can_transition(record.status, to) {
+        panic!("Invalid status transition");
+    }
+
+    let counted = match scope {
+        Scope::Main => !upgrade::awaits_recount(env, &record.staff_hash),
+        Scope::Org(_) => true,
+    };
+    if counted {
+        let mut counts = status_counts(env, scope);
+        counts.set(record.status, counts.get(record.status).unwrap_or(0).saturating_sub(1));
+        counts.set(to, counts.get(to).unwrap_or(0) + 1);
+        set_status_counts(env, scope, &counts);
+    }
+
+    // Only the main registry has a registry tree
+    if let Scope::Main = scope {
+        if (record.status == StaffStatus::Active) != (to == StaffStatus::Active) {
+            registry_tree::set_active(env, &record.staff_hash, to == StaffStatus::Active);
+        }
+    }
+    record.status = to;
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Move a staff member through the employment lifecycle
+    pub fn set_staff_status(env: Env, caller: Address, staff_hash: BytesN<32>, status: StaffStatus) {
+        // onlyOwner or Registrar
+        auth::require_role(&env, &caller, Role::Registrar);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        rotation::require_current(&env, &staff_hash);
+        erasure::require_not_erased(&env, &staff_hash);
+
+        let mut record = Self::get_staff_record(env.clone(), staff_hash.clone());
+        let from = record.status;
+        apply(&env, &Scope::Main, &mut record, status);
+        if from == StaffStatus::Terminated {
+            record.reactivated_at = Some(env.ledger().timestamp());
+        }
+
+        history::save_staff_record(&env, &Scope::Main, &record);
+
+        audit::log(&env, status_event(status), &caller, &staff_hash);
+
+        events::staff_event(&env, status_event(status), &caller, &record);
+    }
+
+    /// Get a staff member's lifecycle status
+    pub fn get_staff_status(env: Env, staff_hash: BytesN<32>) -> StaffStatus {
+        Self::get_staff_record(env, staff_hash).status
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_staff_status_lifecycle() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        assert_eq!(client.get_staff_status(&staff_hash), StaffStatus::Active);
+
+        client.set_staff_status(&owner, &staff_hash, &StaffStatus::OnLeave);
+        assert!(!client.is_staff_active(&staff_hash));
+        assert_eq!(client.get_staff_stats().on_leave_count, 1);
+
+        client.set_staff_status(&owner, &staff_hash, &StaffStatus::Active);
+        client.set_staff_status(&owner, &staff_hash, &StaffStatus::Suspended);
+        client.set_staff_status(&owner, &staff_hash, &StaffStatus::Terminated);
+
+        let stats = client.get_staff_stats();
+        assert_eq!((stats.active_count, stats.suspended_count, stats.revoked_count), (0, 0, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid status transition")]
+    fn test_terminated_staff_cannot_go_on_leave() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        client.revoke_staff(&owner, &staff_hash);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::set_staff_status(env.clone(), owner, staff_hash, StaffStatus::OnLeave)
+        });
+    }
+}
@@ -0,0 +1,174 @@
+use soroban_sdk::{contractimpl, contracttype, Address, Env, Symbol};
+
+use super::*;
+
+// Dashboard totals, kept up to date as batches are recorded
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AggregateStats {
+    pub period_id: u32,
+    pub period_batch_count: u32,      // batches recorded for the period, corrections included
+    pub period_staff_paid: u32,       // staff paid in the period; a correction replaces its batch's count
+    pub total_batch_count: u32,
+    pub cumulative_staff_count: u64,  // sum of staff_count over every recorded batch
+    pub average_staff_per_batch: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Default, PartialEq)]
+struct PeriodTotals {
+    batch_count: u32,
+    staff_paid: u32,
+}
+
+#[contracttype]
+enum StatsKey {
+    CumulativeStaffCount,        // u64 running sum of batch staff counts
+    CountedBatches,              // batches recorded since aggregates were introduced
+    UploaderBatchCount(Address),
+    PeriodTotals(u32),
+    OrgCumulativeStaffCount(Symbol), // the running sums for an org namespace
+    OrgCountedBatches(Symbol),
+}
+
+fn cumulative_key(scope: &Scope) -> StatsKey {
+    match scope {
+        Scope::Main => StatsKey::CumulativeStaffCount,
+        Scope::Org(org_id) => StatsKey::OrgCumulativeStaffCount(org_id.clone()),
+    }
+}
+
+fn counted_key(scope: &Scope) -> StatsKey {
+    match scope {
+        Scope::Main => StatsKey::CountedBatches,
+        Scope::Org(org_id) => StatsKey::OrgCountedBatches(org_id.clone()),
+    }
+}
+
+// Main-registry sums sit with the config; each org's are a record of their own
+fn totals_class(scope: &Scope) -> DataClass {
+    match scope {
+        Scope::Main => DataClass::Config,
+        Scope::Org(_) => DataClass::Records,
+    }
+}
+
+fn running_totals(env: &Env, scope: &Scope) -> (u64, u32) {
+    (
+        storage::get(env, totals_class(scope), &cumulative_key(scope)).unwrap_or(0),
+        storage::get(env, totals_class(scope), &counted_key(scope)).unwrap_or(0),
+    )
+}
+
+fn average(cumulative: u64, counted: u32) -> u32 {
+    if counted == 0 { 0 } else { (cumulative / counted as u64) as u32 }
+}
+
+fn period_totals(env: &Env, period_id: u32) -> PeriodTotals {
+    storage::get(env, DataClass::Records, &StatsKey::PeriodTotals(period_id))
+        .unwrap_or_default()
+}
+
+fn set_period_totals(env: &Env, period_id: u32, totals: &PeriodTotals) {
+    let key = StatsKey::PeriodTotals(period_id);
+    storage::set(env, DataClass::Records, &key, totals);
+}
+
+// Fold a newly stored batch into its namespace's running totals and its uploader's count
+pub(crate) fn record_batch(env: &Env, scope: &Scope, batch: &PayrollBatch) {
+    let (cumulative, counted) = running_totals(env, scope);
+    storage::set(env, totals_class(scope), &cumulative_key(scope), &(cumulative + batch.staff_count as u64));
+    storage::set(env, totals_class(scope), &counted_key(scope), &(counted + 1));
+
+    let key = StatsKey::UploaderBatchCount(batch.uploaded_by.clone());
+    let uploaded: u32 = storage::get(env, DataClass::Records, &key).unwrap_or(0);
+    storage::set(env, DataClass::Records, &key, &(uploaded + 1));
+}
+
+// Count a batch towards its pay period; `replaced_staff` is the staff count of a
+// corrected batch, which no longer counts as paid
+pub(crate) fn record_period_batch(env: &Env, period_id: u32, staff_count: u32, replaced_staff: u32) {
+    let mut totals = period_totals(env, period_id);
+    totals.batch_count += 1;
+    totals.staff_paid = totals.staff_paid.saturating_sub(replaced_staff) + staff_count;
+    set_period_totals(env, period_id, &totals);
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Get running payroll aggregates, with the totals for one pay period (YYYYMM).
+    /// Batches recorded before aggregates were introduced are only in `total_batch_count`.
+    pub fn get_aggregate_stats(env: Env, period_id: u32) -> AggregateStats {
+        let totals = period_totals(&env, period_id);
+        let (cumulative, counted) = running_totals(&env, &Scope::Main);
+
+        AggregateStats {
+            period_id,
+            period_batch_count: totals.batch_count,
+            period_staff_paid: totals.staff_paid,
+            total_batch_count: Self::get_total_batches(env),
+            cumulative_staff_count: cumulative,
+            average_staff_per_batch: average(cumulative, counted),
+        }
+    }
+
+    /// Get running payroll aggregates for an organization's namespace. Org batches
+    /// carry no pay period, so the period fields are always zero.
+    pub fn org_get_aggregate_stats(env: Env, org_id: Symbol) -> AggregateStats {
+        let (cumulative, counted) = running_totals(&env, &Scope::Org(org_id.clone()));
+
+        AggregateStats {
+            period_id: 0,
+            period_batch_count: 0,
+            period_staff_paid: 0,
+            total_batch_count: lists::keyed_len(&env, DataClass::Indexes, &DataKey::OrgBatchHashes(org_id)),
+            cumulative_staff_count: cumulative,
+            average_staff_per_batch: average(cumulative, counted),
+        }
+    }
+
+    /// Get how many batches an address has uploaded
+    pub fn get_uploader_batch_count(env: Env, uploader: Address) -> u32 {
+        storage::get(&env, DataClass::Records, &StatsKey::UploaderBatchCount(uploader))
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{symbol_short, testutils::Address as _, Env};
+
+    #[test]
+    fn test_aggregate_stats() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let uploader = Address::generate(&env);
+        client.initialize(&owner);
+        client.add_uploader(&uploader);
+
+        let july = BytesN::from_array(&env, &[1u8; 32]);
+        let august = BytesN::from_array(&env, &[2u8; 32]);
+        let august_fix = BytesN::from_array(&env, &[3u8; 32]);
+        client.record_batch_for_period(&owner, &july, &10, &202607);
+        client.record_batch_for_period(&uploader, &august, &20, &202608);
+        client.supersede_batch(&uploader, &august, &august_fix, &24, &symbol_short!("arrears"));
+
+        let stats = client.get_aggregate_stats(&202608);
+        assert_eq!(stats.period_batch_count, 2);
+        assert_eq!(stats.period_staff_paid, 24);
+        assert_eq!(stats.total_batch_count, 3);
+        assert_eq!(stats.cumulative_staff_count, 54);
+        assert_eq!(stats.average_staff_per_batch, 18);
+        assert_eq!(client.get_aggregate_stats(&202607).period_staff_paid, 10);
+        assert_eq!(client.get_aggregate_stats(&202609).period_batch_count, 0);
+
+        assert_eq!(client.get_uploader_batch_count(&owner), 1);
+        assert_eq!(client.get_uploader_batch_count(&uploader), 2);
+    }
+}
@@ -0,0 +1,276 @@
+use soroban_sdk::{contractimpl, contracttype, Env, IntoVal, TryFromVal, Val};
+
+use super::*;
+
+// Where a class of data is kept. Instance entries load with every call and share
+// one size-limited entry; persistent entries are loaded on demand and archived
+// when their TTL lapses; temporary entries are cheapest but deleted for good on expiry.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StorageTier {
+    Instance,
+    Persistent,
+    Temporary,
+}
+
+// Every contract key belongs to one class; all reads and writes go through this
+// module so the class decides the tier
+#[derive(Clone, Copy)]
+pub(crate) enum DataClass {
+    Config,    // owner, settings and small fixed-size state; always Instance
+    Records,   // per-staff and per-batch records that grow with the registry
+    Indexes,   // batch membership, per-staff batch lists and the registry-wide lists
+    Counters,  // short-lived per-address rate-limit windows
+}
+
+// Deployment-time storage layout. Records and indexes grow without bound and must
+// survive, so they can only be Persistent; counters are keyed per address, so they
+// may be Persistent or Temporary but never share the instance entry.
+// Only the counters tier is ever looked up, since the other two are fixed.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct StoragePolicy {
+    pub records: StorageTier,
+    pub indexes: StorageTier,
+    pub counters: StorageTier,
+}
+
+#[contracttype]
+enum StorageKey {
+    StoragePolicy,
+}
+
+pub(crate) fn default_policy() -> StoragePolicy {
+    StoragePolicy {
+        records: StorageTier::Persistent,
+        indexes: StorageTier::Persistent,
+        counters: StorageTier::Temporary,
+    }
+}
+
+pub(crate) fn policy(env: &Env) -> StoragePolicy {
+    env.storage()
+        .instance()
+        .get(&StorageKey::StoragePolicy)
+        .unwrap_or_else(default_policy)
+}
+
+// Fix the policy at initialization; it can't change once data has been written
+pub(crate) fn apply_policy(env: &Env, policy: &StoragePolicy) {
+    if policy.records != StorageTier::Persistent
+        || policy.indexes != StorageTier::Persistent
+        || policy.counters == StorageTier::Instance
+    {
+        panic!("Invalid storage policy");
+    }
+    if *policy != default_policy() {
+        env.storage().instance().set(&StorageKey::StoragePolicy, policy);
+    }
+}
+
+// Records and indexes are fixed to Persistent by apply_policy, so only counters need
+// a policy lookup; callers touching several counters resolve the tier once and use
+// the `_in` accessors
+pub(crate) fn tier(env: &Env, class: DataClass) -> StorageTier {
+    match class {
+        DataClass::Config => StorageTier::Instance,
+        DataClass::Records | DataClass::Indexes => StorageTier::Persistent,
+        DataClass::Counters => policy(env).counters,
+    }
+}
+
+pub(crate) fn get<K, V>(env: &Env, class: DataClass, key: &K) -> Option<V>
+where
+    K: IntoVal<Env, Val>,
+    V: TryFromVal<Env, Val>,
+{
+    get_in(env, tier(env, class), key)
+}
+
+pub(crate) fn get_in<K, V>(env: &Env, tier: StorageTier, key: &K) -> Option<V>
+where
+    K: IntoVal<Env, Val>,
+    V: TryFromVal<Env, Val>,
+{
+    match tier {
+        StorageTier::Instance => env.storage().instance().get(key),
+        StorageTier::Persistent => env.storage().persistent().get(key),
+        StorageTier::Temporary => env.storage().temporary().get(key),
+    }
+}
+
+pub(crate) fn has<K: IntoVal<Env, Val>>(env: &Env, class: DataClass, key: &K) -> bool {
+    match tier(env, class) {
+        StorageTier::Instance => env.storage().instance().has(key),
+        StorageTier::Persistent => env.storage().persistent().has(key),
+        StorageTier::Temporary => env.storage().temporary().has(key),
+    }
+}
+
+// Write an entry and keep it alive for the tier's standard lifetime;
+// temporary entries keep the TTL they were given with `extend_to`
+pub(crate) fn set<K, V>(env: &Env, class: DataClass, key: &K, value: &V)
+where
+    K: IntoVal<Env, Val>,
+    V: IntoVal<Env, Val>,
+{
+    set_in(env, tier(env, class), key, value)
+}
+
+pub(crate) fn set_in<K, V>(env: &Env, tier: StorageTier, key: &K, value: &V)
+where
+    K: IntoVal<Env, Val>,
+    V: IntoVal<Env, Val>,
+{
+    match tier {
+        StorageTier::Instance => {
+            env.storage().instance().set(key, value);
+            ttl::extend_instance(env);
+        }
+        StorageTier::Persistent => {
+            env.storage().persistent().set(key, value);
+            ttl::extend_persistent(env, key);
+        }
+        StorageTier::Temporary => env.storage().temporary().set(key, value),
+    }
+}
+
+pub(crate) fn remove<K: IntoVal<Env, Val>>(env: &Env, class: DataClass, key: &K) {
+    match tier(env, class) {
+        StorageTier::Instance => env.storage().instance().remove(key),
+        StorageTier::Persistent => env.storage().persistent().remove(key),
+        StorageTier::Temporary => env.storage().temporary().remove(key),
+    }
+}
+
+// Extend an entry to live at least `ledgers` more, if it exists
+pub(crate) fn extend_to<K: IntoVal<Env, Val>>(env: &Env, class: DataClass, key: &K, ledgers: u32) {
+    extend_in(env, tier(env, class), key, ledgers)
+}
+
+pub(crate) fn extend_in<K: IntoVal<Env, Val>>(env: &Env, tier: StorageTier, key: &K, ledgers: u32) {
+    match tier {
+        StorageTier::Instance => env.storage().instance().extend_ttl(ledgers, ledgers),
+        StorageTier::Persistent => {
+            if env.storage().persistent().has(key) {
+                env.storage().persistent().extend_ttl(key, ledgers, ledgers);
+            }
+        }
+        StorageTier::Temporary => {
+            if env.storage().temporary().has(key) {
+                env.storage().temporary().extend_ttl(key, ledgers, ledgers);
+            }
+        }
+    }
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Get the storage tiers this deployment was initialized with
+    pub fn get_storage_policy(env: Env) -> StoragePolicy {
+        policy(&env)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, Vec};
+
+    fn init_config(env: &Env, storage_policy: StoragePolicy) -> InitConfig {
+        InitConfig {
+            limits: Config { max_staff_per_batch: 0, max_batches_per_period: 0, min_batch_interval_ledgers: 0 },
+            role_grants: Vec::new(env),
+            schedule_period_ledgers: 0,
+            schedule_expected_staff: 0,
+            hash_mode: HashMode::Sha256,
+            storage_policy,
+        }
+    }
+
+    #[test]
+    fn test_counters_in_persistent_storage() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let policy = StoragePolicy {
+            records: StorageTier::Persistent,
+            indexes: StorageTier::Persistent,
+            counters: StorageTier::Persistent,
+        };
+        client.initialize_with_config(&owner, &init_config(&env, policy.clone()));
+        client.set_legacy_hashes_allowed(&true);
+        assert_eq!(client.get_storage_policy(), policy);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        assert!(client.is_staff_active(&staff_hash));
+
+        env.as_contract(&contract_id, || {
+            let key = DataKey::StaffRecord(staff_hash.clone());
+            assert!(env.storage().persistent().has(&key));
+            assert!(!env.storage().instance().has(&key));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid storage policy")]
+    fn test_records_cannot_be_instance() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let owner = Address::generate(&env);
+        let policy = StoragePolicy {
+            records: StorageTier::Instance,
+            indexes: StorageTier::Persistent,
+            counters: StorageTier::Temporary,
+        };
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::initialize_with_config(env.clone(), owner, init_config(&env, policy))
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid storage policy")]
+    fn test_counters_cannot_be_instance() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let owner = Address::generate(&env);
+        let policy = StoragePolicy {
+            records: StorageTier::Persistent,
+            indexes: StorageTier::Persistent,
+            counters: StorageTier::Instance,
+        };
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::initialize_with_config(env.clone(), owner, init_config(&env, policy))
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid storage policy")]
+    fn test_records_cannot_be_temporary() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let owner = Address::generate(&env);
+        let policy = StoragePolicy {
+            records: StorageTier::Temporary,
+            indexes: StorageTier::Persistent,
+            counters: StorageTier::Temporary,
+        };
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::initialize_with_config(env.clone(), owner, init_config(&env, policy))
+        });
+    }
+}
@@ -0,0 +1,234 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, token, Address, BytesN, Env};
+
+use super::*;
+
+// Salary that accrues every ledger between start and end; the recipient pulls at will
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stream {
+    pub id: u64,
+    pub staff_hash: BytesN<32>,
+    pub recipient: Address,
+    pub token: Address,
+    pub funded_by: Address,
+    pub rate_per_ledger: i128,
+    pub start: u32,      // ledger sequence accrual starts at
+    pub end: u32,        // ledger sequence accrual stops at (moved forward on cancel)
+    pub withdrawn: i128,
+}
+
+// Amount accrued up to the current ledger
+fn accrued(env: &Env, stream: &Stream) -> i128 {
+    let now = env.ledger().sequence().min(stream.end);
+    if now <= stream.start {
+        return 0;
+    }
+    stream.rate_per_ledger * i128::from(now - stream.start)
+}
+
+fn save_stream(env: &Env, stream: &Stream) {
+    let key = DataKey::Stream(stream.id);
+    storage::set(env, DataClass::Records, &key, stream);
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Lock tokens for a salary stream to a staff member's wallet
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_stream(
+        env: Env,
+        funder: Address,
+        staff_hash: BytesN<32>,
+        recipient: Address,
+        token: Address,
+        rate_per_ledger: i128,
+        start: u32,
+        end: u32,
+    ) -> u64 {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &funder, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        if !Self::is_staff_active(env.clone(), staff_hash.clone()) {
+            panic!("Staff not active");
+        }
+        if rate_per_ledger <= 0 || end <= start {
+            panic!("Invalid stream");
+        }
+
+        let deposit = rate_per_ledger * i128::from(end - start);
+        token::Client::new(&env, &token).transfer(&funder, &env.current_contract_address(), &deposit);
+
+        let id: u64 = storage::get(&env, DataClass::Config, &DataKey::StreamCount).unwrap_or(0) + 1;
+        storage::set(&env, DataClass::Config, &DataKey::StreamCount, &id);
+
+        save_stream(&env, &Stream {
+            id,
+            staff_hash: staff_hash.clone(),
+            recipient: recipient.clone(),
+            token,
+            funded_by: funder.clone(),
+            rate_per_ledger,
+            start,
+            end,
+            withdrawn: 0,
+        });
+
+        audit::log(&env, symbol_short!("strm_new"), &funder, &staff_hash);
+
+        env.events().publish(
+            (symbol_short!("strm_new"), staff_hash),
+            (id, recipient, deposit, env.ledger().timestamp())
+        );
+
+        id
+    }
+
+    /// Withdraw everything accrued so far to the stream recipient
+    pub fn withdraw_stream(env: Env, stream_id: u64) -> i128 {
+        let mut stream = Self::get_stream(env.clone(), stream_id);
+        stream.recipient.require_auth();
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        let amount = accrued(&env, &stream) - stream.withdrawn;
+        if amount <= 0 {
+            panic!("Nothing to withdraw");
+        }
+
+        stream.withdrawn += amount;
+        save_stream(&env, &stream);
+
+        token::Client::new(&env, &stream.token).transfer(&env.current_contract_address(), &stream.recipient, &amount);
+
+        audit::log(&env, symbol_short!("strm_wdr"), &stream.recipient, &stream.staff_hash);
+
+        env.events().publish(
+            (symbol_short!("strm_wdr"), stream_id),
+            (stream.recipient, amount, env.ledger().timestamp())
+        );
+
+        amount
+    }
+
+    /// Stop a stream: accrued pay goes to the recipient, the rest back to the funder
+    pub fn cancel_stream(env: Env, caller: Address, stream_id: u64) {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        let mut stream = Self::get_stream(env.clone(), stream_id);
+        let now = env.ledger().sequence();
+        if now >= stream.end {
+            panic!("Stream already ended");
+        }
+
+        let total = stream.rate_per_ledger * i128::from(stream.end - stream.start);
+        let owed = accrued(&env, &stream) - stream.withdrawn;
+        let unearned = total - accrued(&env, &stream);
+
+        stream.end = now.max(stream.start);
+        stream.withdrawn += owed;
+        save_stream(&env, &stream);
+
+        let token_client = token::Client::new(&env, &stream.token);
+        if owed > 0 {
+            token_client.transfer(&env.current_contract_address(), &stream.recipient, &owed);
+        }
+        if unearned > 0 {
+            token_client.transfer(&env.current_contract_address(), &stream.funded_by, &unearned);
+        }
+
+        audit::log(&env, symbol_short!("strm_cncl"), &caller, &stream.staff_hash);
+
+        env.events().publish(
+            (symbol_short!("strm_cncl"), stream_id),
+            (caller, owed, unearned, env.ledger().timestamp())
+        );
+    }
+
+    /// Get a stream by id
+    pub fn get_stream(env: Env, stream_id: u64) -> Stream {
+        storage::get(&env, DataClass::Records, &DataKey::Stream(stream_id))
+            .unwrap_or_else(|| panic!("Stream not found"))
+    }
+
+    /// Get the amount currently withdrawable from a stream
+    pub fn get_stream_balance(env: Env, stream_id: u64) -> i128 {
+        let stream = Self::get_stream(env.clone(), stream_id);
+        accrued(&env, &stream) - stream.withdrawn
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Ledger}, token::StellarAssetClient, Env};
+
+    #[test]
+    fn test_stream_withdraw_and_cancel() {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().with_mut(|li| li.sequence_number = 100);
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let token = env.register_stellar_asset_contract(Address::generate(&env));
+        StellarAssetClient::new(&env, &token).mint(&owner, &1_000);
+        let token_client = token::Client::new(&env, &token);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let wallet = Address::generate(&env);
+        client.register_staff(&owner, &staff_hash);
+
+        let id = client.create_stream(&owner, &staff_hash, &wallet, &token, &10, &100, &200);
+        assert_eq!(token_client.balance(&owner), 0);
+
+        env.ledger().with_mut(|li| li.sequence_number = 130);
+        assert_eq!(client.get_stream_balance(&id), 300);
+        assert_eq!(client.withdraw_stream(&id), 300);
+        assert_eq!(token_client.balance(&wallet), 300);
+
+        env.ledger().with_mut(|li| li.sequence_number = 150);
+        client.cancel_stream(&owner, &id);
+        assert_eq!(token_client.balance(&wallet), 500);
+        assert_eq!(token_client.balance(&owner), 500);
+        assert_eq!(client.get_stream(&id).end, 150);
+    }
+
+    #[test]
+    #[should_panic(expected = "Nothing to withdraw")]
+    fn test_withdraw_before_start() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let token = env.register_stellar_asset_contract(Address::generate(&env));
+        StellarAssetClient::new(&env, &token).mint(&owner, &1_000);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        let id = client.create_stream(&owner, &staff_hash, &Address::generate(&env), &token, &10, &500, &600);
+
+        env.as_contract(&contract_id, || StaffRegistry::withdraw_stream(env.clone(), id));
+    }
+}
@@ -0,0 +1,128 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, vec, Address, BytesN, Env, IntoVal, Symbol, Vec};
+
+use super::*;
+
+// Upper bound on callback targets, since every upload pays for each call
+pub const MAX_SUBSCRIBERS: u32 = 5;
+
+#[contracttype]
+enum SubscriberKey {
+    Subscribers,
+}
+
+fn subscribers(env: &Env) -> Vec<Address> {
+    storage::get(env, DataClass::Config, &SubscriberKey::Subscribers)
+        .unwrap_or(Vec::new(env))
+}
+
+// Invoke on_batch_recorded(batch_hash, staff_count) on every subscriber.
+// Failures are reported in an event and never revert the upload.
+pub(crate) fn notify_batch_recorded(env: &Env, batch_hash: &BytesN<32>, staff_count: u32) {
+    let func = Symbol::new(env, "on_batch_recorded");
+    for subscriber in subscribers(env).iter() {
+        let args = vec![env, batch_hash.into_val(env), staff_count.into_val(env)];
+        let result = env.try_invoke_contract::<(), soroban_sdk::Error>(&subscriber, &func, args);
+        if !matches!(result, Ok(Ok(()))) {
+            env.events().publish(
+                (symbol_short!("sub_fail"), subscriber),
+                (batch_hash.clone(), env.ledger().timestamp())
+            );
+        }
+    }
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Register a contract to be called with on_batch_recorded(batch_hash, staff_count) (owner only)
+    pub fn subscribe(env: Env, contract: Address) {
+        let owner = auth::require_owner(&env);
+        ttl::extend_instance(&env);
+
+        let mut list = subscribers(&env);
+        if list.contains(&contract) {
+            panic!("Already subscribed");
+        }
+        if list.len() >= MAX_SUBSCRIBERS {
+            panic!("Too many subscribers");
+        }
+        list.push_back(contract.clone());
+        storage::set(&env, DataClass::Config, &SubscriberKey::Subscribers, &list);
+
+        audit::log_action(&env, symbol_short!("subscribe"), &owner);
+
+        env.events().publish(
+            (symbol_short!("subscribe"), contract),
+            env.ledger().timestamp()
+        );
+    }
+
+    /// Stop calling a subscriber (owner only)
+    pub fn unsubscribe(env: Env, contract: Address) {
+        let owner = auth::require_owner(&env);
+        ttl::extend_instance(&env);
+
+        let mut list = subscribers(&env);
+        let index = list
+            .first_index_of(&contract)
+            .unwrap_or_else(|| panic!("Not subscribed"));
+        list.remove(index);
+        storage::set(&env, DataClass::Config, &SubscriberKey::Subscribers, &list);
+
+        audit::log_action(&env, symbol_short!("unsub"), &owner);
+
+        env.events().publish(
+            (symbol_short!("unsub"), contract),
+            env.ledger().timestamp()
+        );
+    }
+
+    /// Get the contracts notified of new batches
+    pub fn get_subscribers(env: Env) -> Vec<Address> {
+        subscribers(&env)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{contract, testutils::Address as _, Env};
+
+    #[contract]
+    struct CountingSubscriber;
+
+    #[contractimpl]
+    impl CountingSubscriber {
+        pub fn on_batch_recorded(env: Env, _batch_hash: BytesN<32>, staff_count: u32) {
+            let total: u32 = env.storage().instance().get(&symbol_short!("total")).unwrap_or(0);
+            env.storage().instance().set(&symbol_short!("total"), &(total + staff_count));
+        }
+    }
+
+    #[test]
+    fn test_subscribers_notified_with_failure_isolation() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        // An address with no contract behind it always fails the callback
+        let broken = Address::generate(&env);
+        let counter = env.register_contract(None, CountingSubscriber);
+        client.subscribe(&broken);
+        client.subscribe(&counter);
+
+        client.record_payroll_batch(&owner, &BytesN::from_array(&env, &[1u8; 32]), &3);
+        client.record_payroll_batch(&owner, &BytesN::from_array(&env, &[2u8; 32]), &4);
+
+        let total: u32 = env.as_contract(&counter, || env.storage().instance().get(&symbol_short!("total")).unwrap());
+        assert_eq!(total, 7);
+        assert_eq!(client.get_total_batches(), 2);
+
+        client.unsubscribe(&broken);
+        assert_eq!(client.get_subscribers(), Vec::from_array(&env, [counter]));
+    }
+}
@@ -0,0 +1,272 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env, Symbol, Vec};
+
+use super::*;
+
+// Maximum number of category tags a batch can carry
+pub const MAX_BATCH_TAGS: u32 = 5;
+
+// Maximum number of classification tags (grade, employment type, ...) per staff member
+pub const MAX_STAFF_TAGS: u32 = 5;
+
+#[contracttype]
+enum TagKey {
+    BatchTags(BytesN<32>),  // tags set on a batch at record time
+    TaggedBatches(Symbol),  // batch hashes carrying a tag, in record order
+    StaffTags(BytesN<32>),  // classification tags currently set on a staff member
+    TaggedStaff(Symbol),    // staff hashes currently carrying a tag
+}
+
+fn validate_tags(tags: &Vec<Symbol>, max: u32) {
+    if tags.len() > max {
+        panic!("Too many tags");
+    }
+    for i in 0..tags.len() {
+        if tags.first_index_of(tags.get_unchecked(i)) != Some(i) {
+            panic!("Duplicate tag");
+        }
+    }
+}
+
+fn tagged_staff(env: &Env, tag: &Symbol) -> Vec<BytesN<32>> {
+    storage::get(env, DataClass::Indexes, &TagKey::TaggedStaff(tag.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_tagged_staff(env: &Env, tag: Symbol, staff: &Vec<BytesN<32>>) {
+    let key = TagKey::TaggedStaff(tag);
+    if staff.is_empty() {
+        storage::remove(env, DataClass::Indexes, &key);
+    } else {
+        storage::set(env, DataClass::Indexes, &key, staff);
+    }
+}
+
+fn tagged_batches(env: &Env, tag: &Symbol) -> Vec<BytesN<32>> {
+    storage::get(env, DataClass::Indexes, &TagKey::TaggedBatches(tag.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+// Remove a staff member's tags and their entries in the tag indexes
+pub(crate) fn clear_staff_tags(env: &Env, staff_hash: &BytesN<32>) {
+    let key = TagKey::StaffTags(staff_hash.clone());
+    let tags: Vec<Symbol> = storage::get(env, DataClass::Records, &key).unwrap_or(Vec::new(env));
+    for tag in tags.iter() {
+        let mut staff = tagged_staff(env, &tag);
+        if let Some(index) = staff.first_index_of(staff_hash) {
+            staff.remove(index);
+        }
+        set_tagged_staff(env, tag, &staff);
+    }
+    storage::remove(env, DataClass::Records, &key);
+}
+
+// Carry a rotated staff hash's tags over to its replacement
+pub(crate) fn rekey_staff_tags(env: &Env, old_hash: &BytesN<32>, new_hash: &BytesN<32>) {
+    let old_key = TagKey::StaffTags(old_hash.clone());
+    let tags: Vec<Symbol> = storage::get(env, DataClass::Records, &old_key).unwrap_or(Vec::new(env));
+    for tag in tags.iter() {
+        let mut staff = tagged_staff(env, &tag);
+        if let Some(index) = staff.first_index_of(old_hash) {
+            staff.set(index, new_hash.clone());
+        }
+        set_tagged_staff(env, tag, &staff);
+    }
+    rotation::move_entry(env, &old_key, &TagKey::StaffTags(new_hash.clone()));
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Record a payroll batch carrying category tags such as `monthly`, `bonus` or `severance`
+    pub fn record_payroll_batch_tagged(env: Env, caller: Address, batch_hash: BytesN<32>, staff_count: u32, tags: Vec<Symbol>) {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        validate_tags(&tags, MAX_BATCH_TAGS);
+
+        store_new_batch(&env, &new_batch(&env, caller.clone(), batch_hash.clone(), staff_count));
+
+        let key = TagKey::BatchTags(batch_hash.clone());
+        storage::set(&env, DataClass::Records, &key, &tags);
+
+        for tag in tags.iter() {
+            let mut batches = tagged_batches(&env, &tag);
+            batches.push_back(batch_hash.clone());
+            let index_key = TagKey::TaggedBatches(tag);
+            storage::set(&env, DataClass::Indexes, &index_key, &batches);
+        }
+
+        audit::log(&env, symbol_short!("batch_tag"), &caller, &batch_hash);
+
+        env.events().publish(
+            (symbol_short!("batch_tag"), batch_hash),
+            (tags, env.ledger().timestamp())
+        );
+    }
+
+    /// Get the tags set on a batch
+    pub fn get_batch_tags(env: Env, batch_hash: BytesN<32>) -> Vec<Symbol> {
+        storage::get(&env, DataClass::Records, &TagKey::BatchTags(batch_hash))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get a page of batch hashes carrying a tag, oldest first
+    pub fn get_batches_by_tag(env: Env, tag: Symbol, start: u32, limit: u32) -> Vec<BytesN<32>> {
+        paginate(&env, &tagged_batches(&env, &tag), start, limit)
+    }
+
+    /// Replace a staff member's classification tags (owner or Registrar)
+    pub fn set_staff_tags(env: Env, caller: Address, staff_hash: BytesN<32>, tags: Vec<Symbol>) {
+        // onlyOwner or Registrar
+        auth::require_role(&env, &caller, Role::Registrar);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        rotation::require_current(&env, &staff_hash);
+        erasure::require_not_erased(&env, &staff_hash);
+
+        if !Self::is_staff_registered(env.clone(), staff_hash.clone()) {
+            panic!("Staff not found");
+        }
+        validate_tags(&tags, MAX_STAFF_TAGS);
+
+        // Drop the staff member from indexes of tags no longer carried
+        let previous = Self::get_staff_tags(env.clone(), staff_hash.clone());
+        for tag in previous.iter() {
+            if tags.contains(&tag) {
+                continue;
+            }
+            let mut staff = tagged_staff(&env, &tag);
+            if let Some(index) = staff.first_index_of(&staff_hash) {
+                staff.remove(index);
+            }
+            set_tagged_staff(&env, tag, &staff);
+        }
+        for tag in tags.iter() {
+            if previous.contains(&tag) {
+                continue;
+            }
+            let mut staff = tagged_staff(&env, &tag);
+            staff.push_back(staff_hash.clone());
+            set_tagged_staff(&env, tag, &staff);
+        }
+
+        let key = TagKey::StaffTags(staff_hash.clone());
+        if tags.is_empty() {
+            storage::remove(&env, DataClass::Records, &key);
+        } else {
+            storage::set(&env, DataClass::Records, &key, &tags);
+        }
+
+        audit::log(&env, symbol_short!("staff_tag"), &caller, &staff_hash);
+
+        env.events().publish(
+            (symbol_short!("staff_tag"), staff_hash),
+            (tags, env.ledger().timestamp())
+        );
+    }
+
+    /// Get the classification tags set on a staff member
+    pub fn get_staff_tags(env: Env, staff_hash: BytesN<32>) -> Vec<Symbol> {
+        storage::get(&env, DataClass::Records, &TagKey::StaffTags(staff_hash))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get a page of staff hashes currently carrying a tag
+    pub fn get_staff_by_tag(env: Env, tag: Symbol, start: u32, limit: u32) -> Vec<BytesN<32>> {
+        paginate(&env, &tagged_staff(&env, &tag), start, limit)
+    }
+
+    /// Get how many staff currently carry a tag
+    pub fn get_staff_tag_count(env: Env, tag: Symbol) -> u32 {
+        tagged_staff(&env, &tag).len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_batches_by_tag() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let monthly = symbol_short!("monthly");
+        let bonus = symbol_short!("bonus");
+        let january = BytesN::from_array(&env, &[1u8; 32]);
+        let q1_bonus = BytesN::from_array(&env, &[2u8; 32]);
+        let february = BytesN::from_array(&env, &[3u8; 32]);
+
+        client.record_payroll_batch_tagged(&owner, &january, &10, &Vec::from_array(&env, [monthly.clone()]));
+        client.record_payroll_batch_tagged(&owner, &q1_bonus, &4, &Vec::from_array(&env, [bonus.clone(), monthly.clone()]));
+        client.record_payroll_batch_tagged(&owner, &february, &10, &Vec::from_array(&env, [monthly.clone()]));
+
+        assert_eq!(client.get_batch_tags(&q1_bonus), Vec::from_array(&env, [bonus.clone(), monthly.clone()]));
+        assert_eq!(client.get_batches_by_tag(&bonus, &0, &10), Vec::from_array(&env, [q1_bonus.clone()]));
+        assert_eq!(client.get_batches_by_tag(&monthly, &1, &10), Vec::from_array(&env, [q1_bonus, february]));
+        assert_eq!(client.get_batches_by_tag(&symbol_short!("severance"), &0, &10).len(), 0);
+    }
+
+    #[test]
+    fn test_staff_by_tag() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let grade_7 = symbol_short!("grade_7");
+        let grade_8 = symbol_short!("grade_8");
+        let contract = symbol_short!("contract");
+        let alice = BytesN::from_array(&env, &[1u8; 32]);
+        let bob = BytesN::from_array(&env, &[2u8; 32]);
+        client.register_staff(&owner, &alice);
+        client.register_staff(&owner, &bob);
+
+        client.set_staff_tags(&owner, &alice, &Vec::from_array(&env, [grade_7.clone(), contract.clone()]));
+        client.set_staff_tags(&owner, &bob, &Vec::from_array(&env, [grade_7.clone()]));
+        assert_eq!(client.get_staff_tag_count(&grade_7), 2);
+
+        // Promotion moves alice between grade indexes
+        client.set_staff_tags(&owner, &alice, &Vec::from_array(&env, [grade_8.clone(), contract.clone()]));
+        assert_eq!(client.get_staff_by_tag(&grade_7, &0, &10), Vec::from_array(&env, [bob]));
+        assert_eq!(client.get_staff_by_tag(&grade_8, &0, &10), Vec::from_array(&env, [alice.clone()]));
+        assert_eq!(client.get_staff_by_tag(&contract, &0, &10), Vec::from_array(&env, [alice.clone()]));
+        assert_eq!(client.get_staff_tags(&alice), Vec::from_array(&env, [grade_8, contract]));
+    }
+
+    #[test]
+    #[should_panic(expected = "Duplicate tag")]
+    fn test_duplicate_tag() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let tags = Vec::from_array(&env, [symbol_short!("bonus"), symbol_short!("bonus")]);
+        env.as_contract(&contract_id, || {
+            StaffRegistry::record_payroll_batch_tagged(env.clone(), owner, BytesN::from_array(&env, &[1u8; 32]), 1, tags)
+        });
+    }
+}
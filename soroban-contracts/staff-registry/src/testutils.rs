@@ -0,0 +1,126 @@
+//! Setup helpers for contracts and services that integrate with the registry.
+//! Enable with the `testutils` feature:
+//!
+//! ```ignore
+//! let env = Env::default();
+//! let registry = TestRegistry::with_owner(&env, &Address::generate(&env));
+//! let staff = registry.seeded_staff(3);
+//! let batches = registry.seeded_batches(2);
+//! advance_time(&env, 30 * 24 * 60 * 60);
+//! ```
+
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Bytes, BytesN, Env, Vec};
+
+use crate::{StaffRegistry, StaffRegistryClient};
+
+// Ledger close time the helpers assume when moving time and sequence together
+pub const SECONDS_PER_LEDGER: u64 = 5;
+
+/// Deterministic, non-zero staff hash for a seed
+pub fn fake_staff_hash(env: &Env, seed: u32) -> BytesN<32> {
+    fake_hash(env, b"staff", seed)
+}
+
+/// Deterministic, non-zero batch hash for a seed, distinct from any staff hash
+pub fn fake_batch_hash(env: &Env, seed: u32) -> BytesN<32> {
+    fake_hash(env, b"batch", seed)
+}
+
+fn fake_hash(env: &Env, tag: &[u8], seed: u32) -> BytesN<32> {
+    let mut data = Bytes::from_slice(env, tag);
+    data.extend_from_array(&seed.to_be_bytes());
+    env.crypto().sha256(&data)
+}
+
+/// Move the ledger forward by `ledgers`, advancing the timestamp to match
+pub fn advance_ledgers(env: &Env, ledgers: u32) {
+    env.ledger().with_mut(|li| {
+        li.sequence_number += ledgers;
+        li.timestamp += ledgers as u64 * SECONDS_PER_LEDGER;
+    });
+}
+
+/// Move the ledger timestamp forward by `seconds`, advancing the sequence to match
+pub fn advance_time(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|li| {
+        li.timestamp += seconds;
+        li.sequence_number += (seconds / SECONDS_PER_LEDGER) as u32;
+    });
+}
+
+/// A registered, initialized registry. All auths in `env` are mocked, so the
+/// owner (and any caller) can invoke gated functions without signatures, and
+/// caller-supplied staff hashes are allowed so fixtures can use `fake_staff_hash`.
+pub struct TestRegistry<'a> {
+    pub env: Env,
+    pub contract_id: Address,
+    pub owner: Address,
+    pub client: StaffRegistryClient<'a>,
+}
+
+impl<'a> TestRegistry<'a> {
+    /// Registry owned by a freshly generated address
+    pub fn new(env: &Env) -> Self {
+        Self::with_owner(env, &Address::generate(env))
+    }
+
+    pub fn with_owner(env: &Env, owner: &Address) -> Self {
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(env, &contract_id);
+        client.initialize(owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        TestRegistry { env: env.clone(), contract_id, owner: owner.clone(), client }
+    }
+
+    /// Register `fake_staff_hash(0..n)`, returning the hashes in order
+    pub fn seeded_staff(&self, n: u32) -> Vec<BytesN<32>> {
+        let mut hashes = Vec::new(&self.env);
+        for seed in 0..n {
+            let staff_hash = fake_staff_hash(&self.env, seed);
+            self.client.register_staff(&self.owner, &staff_hash);
+            hashes.push_back(staff_hash);
+        }
+        hashes
+    }
+
+    /// Record `fake_batch_hash(0..n)` as the owner, one staff member each,
+    /// returning the hashes in order
+    pub fn seeded_batches(&self, n: u32) -> Vec<BytesN<32>> {
+        let mut hashes = Vec::new(&self.env);
+        for seed in 0..n {
+            let batch_hash = fake_batch_hash(&self.env, seed);
+            self.client.record_payroll_batch(&self.owner, &batch_hash, &1);
+            hashes.push_back(batch_hash);
+        }
+        hashes
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_registry_builder() {
+        let env = Env::default();
+        let owner = Address::generate(&env);
+        let registry = TestRegistry::with_owner(&env, &owner);
+        assert_eq!(registry.client.owner(), owner);
+
+        let staff = registry.seeded_staff(3);
+        let batches = registry.seeded_batches(2);
+        assert_eq!(registry.client.get_total_staff(), 3);
+        assert_eq!(registry.client.get_latest_batch().unwrap().batch_hash, batches.get_unchecked(1));
+        assert!(registry.client.is_staff_active(&staff.get_unchecked(0)));
+        assert_ne!(fake_staff_hash(&env, 0), fake_batch_hash(&env, 0));
+
+        let (sequence, timestamp) = (env.ledger().sequence(), env.ledger().timestamp());
+        advance_time(&env, 60);
+        advance_ledgers(&env, 10);
+        assert_eq!((env.ledger().sequence(), env.ledger().timestamp()), (sequence + 22, timestamp + 110));
+    }
+}
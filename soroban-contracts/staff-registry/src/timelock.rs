@@ -0,0 +1,210 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, BytesN, Env, Vec};
+
+use super::*;
+
+// Sensitive owner operations that must wait out the timelock once one is configured
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum AdminAction {
+    ProposeOwner(Address),
+    Upgrade(BytesN<32>),
+    SetConfig(Config),
+    SetEscrowConfig(EscrowConfig),
+    SetTimelockDelay(u64),
+    SetSigners(Vec<Address>, u32),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct QueuedAction {
+    pub id: u64,
+    pub action: AdminAction,
+    pub queued_at: u64,
+    pub eta: u64,  // earliest timestamp execute_action may run it
+}
+
+#[contracttype]
+enum TimelockKey {
+    TimelockDelay,      // seconds between queueing and execution; 0 disables the timelock
+    ActionCount,        // last issued action id
+    QueuedAction(u64),  // pending action by id
+}
+
+pub(crate) fn timelock_delay(env: &Env) -> u64 {
+    storage::get(env, DataClass::Config, &TimelockKey::TimelockDelay).unwrap_or(0)
+}
+
+// Direct calls to sensitive operations are only allowed while no timelock is configured
+pub(crate) fn require_not_timelocked(env: &Env) {
+    if timelock_delay(env) > 0 {
+        panic!("Action is timelocked");
+    }
+}
+
+fn apply_timelock_delay(env: &Env, owner: &Address, delay: u64) {
+    storage::set(env, DataClass::Config, &TimelockKey::TimelockDelay, &delay);
+    ttl::extend_instance(env);
+
+    audit::log_action(env, symbol_short!("tl_delay"), owner);
+
+    env.events().publish(
+        (symbol_short!("tl_delay"),),
+        (delay, env.ledger().timestamp())
+    );
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Enable the timelock (owner only); once set, changing the delay must itself be queued
+    pub fn set_timelock_delay(env: Env, delay: u64) {
+        let owner = auth::require_owner(&env);
+        require_not_timelocked(&env);
+
+        apply_timelock_delay(&env, &owner, delay);
+    }
+
+    /// Get the timelock delay in seconds (0 when disabled)
+    pub fn get_timelock_delay(env: Env) -> u64 {
+        timelock_delay(&env)
+    }
+
+    /// Queue a sensitive action to run after the timelock delay (owner only); returns its id
+    pub fn queue_action(env: Env, action: AdminAction) -> u64 {
+        let owner = auth::require_owner(&env);
+        ttl::extend_instance(&env);
+
+        let id: u64 = storage::get(&env, DataClass::Config, &TimelockKey::ActionCount).unwrap_or(0) + 1;
+        storage::set(&env, DataClass::Config, &TimelockKey::ActionCount, &id);
+
+        let queued = QueuedAction {
+            id,
+            action: action.clone(),
+            queued_at: env.ledger().timestamp(),
+            eta: env.ledger().timestamp() + timelock_delay(&env),
+        };
+        let key = TimelockKey::QueuedAction(id);
+        storage::set(&env, DataClass::Records, &key, &queued);
+
+        audit::log_action(&env, symbol_short!("tl_queue"), &owner);
+
+        env.events().publish(
+            (symbol_short!("tl_queue"), id),
+            (action, queued.eta, env.ledger().timestamp())
+        );
+
+        id
+    }
+
+    /// Run a queued action once its delay has passed (callable by anyone)
+    pub fn execute_action(env: Env, action_id: u64) {
+        let queued = Self::get_queued_action(env.clone(), action_id)
+            .unwrap_or_else(|| panic!("Action not found"));
+        if env.ledger().timestamp() < queued.eta {
+            panic!("Timelock not expired");
+        }
+
+        storage::remove(&env, DataClass::Records, &TimelockKey::QueuedAction(action_id));
+        ttl::extend_instance(&env);
+
+        let owner = auth::owner(&env);
+        match queued.action {
+            AdminAction::ProposeOwner(new_owner) => apply_propose_owner(&env, &owner, new_owner),
+            AdminAction::Upgrade(new_wasm_hash) => upgrade::apply_upgrade(&env, &owner, new_wasm_hash),
+            AdminAction::SetConfig(config) => config::apply_config(&env, &owner, config),
+            AdminAction::SetEscrowConfig(config) => escrow::apply_escrow_config(&env, &owner, config),
+            AdminAction::SetTimelockDelay(delay) => apply_timelock_delay(&env, &owner, delay),
+            AdminAction::SetSigners(signers, threshold) => multisig::apply_set_signers(&env, &owner, signers, threshold),
+        }
+
+        env.events().publish(
+            (symbol_short!("tl_exec"), action_id),
+            env.ledger().timestamp()
+        );
+    }
+
+    /// Drop a queued action before it runs (owner only)
+    pub fn cancel_action(env: Env, action_id: u64) {
+        let owner = auth::require_owner(&env);
+
+        let key = TimelockKey::QueuedAction(action_id);
+        if !storage::has(&env, DataClass::Records, &key) {
+            panic!("Action not found");
+        }
+        storage::remove(&env, DataClass::Records, &key);
+
+        audit::log_action(&env, symbol_short!("tl_cancel"), &owner);
+
+        env.events().publish(
+            (symbol_short!("tl_cancel"), action_id),
+            env.ledger().timestamp()
+        );
+    }
+
+    /// Get a queued action by id, if still pending
+    pub fn get_queued_action(env: Env, action_id: u64) -> Option<QueuedAction> {
+        storage::get(&env, DataClass::Records, &TimelockKey::QueuedAction(action_id))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Ledger}, Env};
+
+    #[test]
+    fn test_queued_config_change() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_timelock_delay(&3_600);
+
+        let config = Config { max_staff_per_batch: 500, max_batches_per_period: 0, min_batch_interval_ledgers: 0 };
+        let id = client.queue_action(&AdminAction::SetConfig(config.clone()));
+        assert_eq!(client.get_queued_action(&id).unwrap().eta, env.ledger().timestamp() + 3_600);
+
+        let new_owner = Address::generate(&env);
+        let cancelled = client.queue_action(&AdminAction::ProposeOwner(new_owner));
+        client.cancel_action(&cancelled);
+        assert_eq!(client.get_queued_action(&cancelled), None);
+
+        env.ledger().with_mut(|li| li.timestamp += 3_600);
+        client.execute_action(&id);
+        assert_eq!(client.get_config(), config);
+        assert_eq!(client.get_queued_action(&id), None);
+        assert_eq!(client.pending_owner(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Timelock not expired")]
+    fn test_execute_before_eta() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+        client.set_timelock_delay(&3_600);
+
+        let id = client.queue_action(&AdminAction::ProposeOwner(Address::generate(&env)));
+        env.as_contract(&contract_id, || StaffRegistry::execute_action(env.clone(), id));
+    }
+
+    #[test]
+    #[should_panic(expected = "Action is timelocked")]
+    fn test_direct_call_blocked() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+        client.set_timelock_delay(&3_600);
+
+        env.as_contract(&contract_id, || StaffRegistry::propose_owner(env.clone(), Address::generate(&env)));
+    }
+}
@@ -0,0 +1,189 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env, Vec};
+
+use super::*;
+
+// Most installments a single batch can be paid across
+pub const MAX_TRANCHES: u32 = 24;
+
+// One installment of a batch's payout
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tranche {
+    pub tranche_id: u32,
+    pub amount_commitment: BytesN<32>,
+    pub tx_ref: BytesN<32>,     // payment transaction (on-chain hash or bank reference digest)
+    pub recorded_by: Address,
+    pub recorded_at: u64,
+}
+
+// Paid vs outstanding installments for a batch
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DisbursementProgress {
+    pub planned: u32,           // 0 when no plan was set
+    pub paid: u32,
+    pub outstanding: Vec<u32>,  // planned tranche ids not yet recorded
+}
+
+#[contracttype]
+enum TrancheKey {
+    TranchePlan(BytesN<32>),  // number of installments a batch is paid in
+    Tranches(BytesN<32>),     // recorded installments, in recording order
+}
+
+fn tranche_plan(env: &Env, batch_hash: &BytesN<32>) -> u32 {
+    storage::get(env, DataClass::Records, &TrancheKey::TranchePlan(batch_hash.clone()))
+        .unwrap_or(0)
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Declare how many installments a batch will be paid in; tranche ids run 0..count (owner or PayrollUploader)
+    pub fn set_tranche_plan(env: Env, caller: Address, batch_hash: BytesN<32>, tranche_count: u32) {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        if !Self::is_batch_recorded(env.clone(), batch_hash.clone()) {
+            panic!("Batch not found");
+        }
+        if tranche_count == 0 || tranche_count > MAX_TRANCHES {
+            panic!("Invalid tranche count");
+        }
+        for tranche in Self::get_disbursements(env.clone(), batch_hash.clone()).iter() {
+            if tranche.tranche_id >= tranche_count {
+                panic!("Plan excludes recorded tranche");
+            }
+        }
+
+        let key = TrancheKey::TranchePlan(batch_hash.clone());
+        storage::set(&env, DataClass::Records, &key, &tranche_count);
+
+        audit::log(&env, symbol_short!("trn_plan"), &caller, &batch_hash);
+
+        env.events().publish(
+            (symbol_short!("trn_plan"), batch_hash),
+            (tranche_count, env.ledger().timestamp())
+        );
+    }
+
+    /// Mark one installment of a batch as paid (owner or PayrollUploader)
+    pub fn record_disbursement(env: Env, caller: Address, batch_hash: BytesN<32>, tranche_id: u32, amount_commitment: BytesN<32>, tx_ref: BytesN<32>) {
+        // onlyOwner or PayrollUploader
+        auth::require_role(&env, &caller, Role::PayrollUploader);
+
+        // whenNotPaused
+        pausable::require_not_paused(&env);
+        ttl::extend_instance(&env);
+
+        let batch = Self::get_payroll_batch(env.clone(), batch_hash.clone());
+        if !batch.is_valid {
+            panic!("Batch is invalidated");
+        }
+
+        let planned = tranche_plan(&env, &batch_hash);
+        let mut tranches = Self::get_disbursements(env.clone(), batch_hash.clone());
+        if (planned > 0 && tranche_id >= planned) || tranche_id >= MAX_TRANCHES {
+            panic!("Unknown tranche");
+        }
+        if tranches.iter().any(|tranche| tranche.tranche_id == tranche_id) {
+            panic!("Tranche already recorded");
+        }
+
+        tranches.push_back(Tranche {
+            tranche_id,
+            amount_commitment,
+            tx_ref: tx_ref.clone(),
+            recorded_by: caller.clone(),
+            recorded_at: env.ledger().timestamp(),
+        });
+        let key = TrancheKey::Tranches(batch_hash.clone());
+        storage::set(&env, DataClass::Records, &key, &tranches);
+
+        audit::log(&env, symbol_short!("trn_paid"), &caller, &batch_hash);
+
+        env.events().publish(
+            (symbol_short!("trn_paid"), batch_hash),
+            (tranche_id, tx_ref, env.ledger().timestamp())
+        );
+    }
+
+    /// Get the installments recorded for a batch, in recording order
+    pub fn get_disbursements(env: Env, batch_hash: BytesN<32>) -> Vec<Tranche> {
+        storage::get(&env, DataClass::Records, &TrancheKey::Tranches(batch_hash))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Report how many installments of a batch are paid and which planned ones are outstanding
+    pub fn get_disbursement_progress(env: Env, batch_hash: BytesN<32>) -> DisbursementProgress {
+        let planned = tranche_plan(&env, &batch_hash);
+        let tranches = Self::get_disbursements(env.clone(), batch_hash);
+
+        let mut outstanding = Vec::new(&env);
+        for tranche_id in 0..planned {
+            if !tranches.iter().any(|tranche| tranche.tranche_id == tranche_id) {
+                outstanding.push_back(tranche_id);
+            }
+        }
+
+        DisbursementProgress { planned, paid: tranches.len(), outstanding }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_disbursement_progress() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &10);
+        client.set_tranche_plan(&owner, &batch_hash, &3);
+
+        let commitment = BytesN::from_array(&env, &[5u8; 32]);
+        client.record_disbursement(&owner, &batch_hash, &0, &commitment, &BytesN::from_array(&env, &[6u8; 32]));
+        client.record_disbursement(&owner, &batch_hash, &2, &commitment, &BytesN::from_array(&env, &[7u8; 32]));
+
+        let progress = client.get_disbursement_progress(&batch_hash);
+        assert_eq!(progress.planned, 3);
+        assert_eq!(progress.paid, 2);
+        assert_eq!(progress.outstanding, Vec::from_array(&env, [1u32]));
+        assert_eq!(client.get_disbursements(&batch_hash).get(1).unwrap().tranche_id, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Tranche already recorded")]
+    fn test_tranche_recorded_twice() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        let commitment = BytesN::from_array(&env, &[5u8; 32]);
+        let tx_ref = BytesN::from_array(&env, &[6u8; 32]);
+        client.record_payroll_batch(&owner, &batch_hash, &10);
+        client.record_disbursement(&owner, &batch_hash, &0, &commitment, &tx_ref);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::record_disbursement(env.clone(), owner, batch_hash, 0, commitment, tx_ref)
+        });
+    }
+}
@@ -0,0 +1,202 @@
+use soroban_sdk::{contractimpl, symbol_short, BytesN, Env, IntoVal, Val};
+
+use super::*;
+
+// Ledger-count constants (~5s per ledger)
+pub const DAY_IN_LEDGERS: u32 = 17280;
+
+// Instance storage (owner, config) is kept alive for a week past any write
+pub const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+pub const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+// Persistent records are kept alive for a month past any write
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+// Extend the contract instance (and its instance storage)
+pub(crate) fn extend_instance(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+// Extend a persistent entry that was just written
+pub(crate) fn extend_persistent<K: IntoVal<Env, Val>>(env: &Env, key: &K) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+// Caller-chosen extensions can't go past what the network allows
+fn clamp_ledgers(env: &Env, ledgers: u32) -> u32 {
+    ledgers.min(env.storage().max_ttl().saturating_sub(1))
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Extend the TTL of a staff record and one page of its batch list so they are not
+    /// archived (callable by anyone). `ledgers` is capped at the network maximum.
+    /// Returns how many batch-list entries follow the page.
+    pub fn bump_staff(env: Env, staff_hash: BytesN<32>, ledgers: u32, start: u32, limit: u32) -> u32 {
+        if !Self::is_staff_registered(env.clone(), staff_hash.clone()) {
+            panic!("Staff not found");
+        }
+        let ledgers = clamp_ledgers(&env, ledgers);
+
+        storage::extend_to(&env, DataClass::Records, &DataKey::StaffRecord(staff_hash.clone()), ledgers);
+        lists::keyed_extend(&env, DataClass::Indexes, &DataKey::StaffBatches(staff_hash), start, limit, ledgers)
+    }
+
+    /// Extend the TTL of a batch record so it is not archived (callable by anyone).
+    /// `ledgers` is capped at the network maximum.
+    pub fn bump_batch(env: Env, batch_hash: BytesN<32>, ledgers: u32) {
+        if !Self::is_batch_recorded(env.clone(), batch_hash.clone()) {
+            panic!("Batch not found");
+        }
+        let ledgers = clamp_ledgers(&env, ledgers);
+
+        storage::extend_to(&env, DataClass::Records, &DataKey::PayrollBatch(batch_hash.clone()), ledgers);
+        storage::extend_to(&env, DataClass::Records, &DataKey::BatchMetadata(batch_hash.clone()), ledgers);
+        storage::extend_to(&env, DataClass::Records, &DataKey::BatchDocuments(batch_hash.clone()), ledgers);
+        storage::extend_to(&env, DataClass::Indexes, &DataKey::BatchHasMembers(batch_hash), ledgers);
+    }
+
+    /// Extend the TTLs of a staff record brought back from state archival, along with
+    /// one page of its batch list (callable by anyone). Registration is read from the
+    /// record itself, so nothing else needs rebuilding. Returns how many batch-list
+    /// entries follow the page.
+    pub fn restore_staff(env: Env, staff_hash: BytesN<32>, start: u32, limit: u32) -> u32 {
+        ttl::extend_instance(&env);
+
+        let record_key = DataKey::StaffRecord(staff_hash.clone());
+        if !storage::has(&env, DataClass::Records, &record_key) {
+            panic!("Staff not found");
+        }
+
+        storage::extend_to(&env, DataClass::Records, &record_key, PERSISTENT_BUMP_AMOUNT);
+        let remaining = lists::keyed_extend(&env, DataClass::Indexes, &DataKey::StaffBatches(staff_hash.clone()), start, limit, PERSISTENT_BUMP_AMOUNT);
+
+        env.events().publish(
+            (symbol_short!("staff_rst"), staff_hash),
+            env.ledger().timestamp()
+        );
+
+        remaining
+    }
+
+    /// Extend the TTLs of a payroll batch brought back from state archival, along with
+    /// its metadata and documents (callable by anyone)
+    pub fn restore_batch(env: Env, batch_hash: BytesN<32>) {
+        ttl::extend_instance(&env);
+
+        let batch_key = DataKey::PayrollBatch(batch_hash.clone());
+        if !storage::has(&env, DataClass::Records, &batch_key) {
+            panic!("Batch not found");
+        }
+
+        storage::extend_to(&env, DataClass::Records, &batch_key, PERSISTENT_BUMP_AMOUNT);
+        storage::extend_to(&env, DataClass::Records, &DataKey::BatchMetadata(batch_hash.clone()), PERSISTENT_BUMP_AMOUNT);
+        storage::extend_to(&env, DataClass::Records, &DataKey::BatchDocuments(batch_hash.clone()), PERSISTENT_BUMP_AMOUNT);
+        storage::extend_to(&env, DataClass::Indexes, &DataKey::BatchHasMembers(batch_hash.clone()), PERSISTENT_BUMP_AMOUNT);
+
+        env.events().publish(
+            (symbol_short!("batch_rst"), batch_hash),
+            env.ledger().timestamp()
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::{Address as _, Ledger}, Address, Env, Vec};
+
+    #[test]
+    fn test_records_survive_past_minimum_ttl() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        client.record_payroll_batch(&owner, &batch_hash, &1);
+        assert_eq!(client.bump_staff(&staff_hash, &(PERSISTENT_BUMP_AMOUNT * 2), &0, &10), 0);
+        client.bump_batch(&batch_hash, &(PERSISTENT_BUMP_AMOUNT * 2));
+        // Keep the contract instance itself alive for the jump below
+        let far = PERSISTENT_BUMP_AMOUNT * 2;
+        env.as_contract(&contract_id, || env.storage().instance().extend_ttl(far, far));
+
+        // Past the automatic write extension, but within the explicit bump
+        env.ledger().with_mut(|li| li.sequence_number += PERSISTENT_BUMP_AMOUNT + DAY_IN_LEDGERS);
+
+        assert!(client.is_staff_active(&staff_hash));
+        assert_eq!(client.get_payroll_batch(&batch_hash).staff_count, 1);
+    }
+
+    #[test]
+    fn test_bump_is_clamped_and_paged() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        for seed in 10..13u8 {
+            let batch_hash = BytesN::from_array(&env, &[seed; 32]);
+            client.record_batch_with_members(&owner, &batch_hash, &Vec::from_array(&env, [staff_hash.clone()]));
+        }
+
+        // An oversized extension is capped rather than rejected by the host
+        assert_eq!(client.bump_staff(&staff_hash, &u32::MAX, &0, &2), 1);
+        assert_eq!(client.bump_staff(&staff_hash, &u32::MAX, &2, &2), 0);
+        client.bump_batch(&BytesN::from_array(&env, &[10u8; 32]), &u32::MAX);
+    }
+
+    #[test]
+    fn test_restore_without_registration_flags() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+        client.record_payroll_batch(&owner, &batch_hash, &1);
+
+        // Restored records are found on their own; no flag entries need restoring
+        assert_eq!(client.restore_staff(&staff_hash, &0, &10), 0);
+        client.restore_batch(&batch_hash);
+        assert!(client.is_staff_active(&staff_hash));
+        assert_eq!(client.get_payroll_batch(&batch_hash).staff_count, 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Staff not found")]
+    fn test_restore_unknown_staff() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, StaffRegistry);
+
+        env.as_contract(&contract_id, || {
+            StaffRegistry::restore_staff(env.clone(), BytesN::from_array(&env, &[1u8; 32]), 0, 10)
+        });
+    }
+}
@@ -0,0 +1,728 @@
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env, IntoVal, Map, String, Symbol, TryFromVal, Val};
+
+use super::*;
+
+// Storage layout version this build expects; bump together with a migration step
+pub const CONTRACT_VERSION: u32 = 8;
+
+// Storage layout version currently on ledger (0 for deployments that predate versioning)
+pub(crate) fn stored_version(env: &Env) -> u32 {
+    storage::get(env, DataClass::Config, &DataKey::Version).unwrap_or(0)
+}
+
+// Staff and batch layouts that shipped without a version bump (rehires, metadata, merkle
+// roots, corrections, invalidation) share a version number with their predecessors, so
+// every layout keeps a decoder and the one to use is told apart by the fields present.
+
+// Staff record layout as first deployed, before rehires and metadata were tracked
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct LegacyStaffRecordV0 {
+    pub staff_hash: BytesN<32>,
+    pub registered_by: Address,
+    pub registered_at: u64,
+    pub is_active: bool,
+}
+
+impl LegacyStaffRecordV0 {
+    fn upgrade(self) -> LegacyStaffRecordNoMetadata {
+        LegacyStaffRecordNoMetadata {
+            staff_hash: self.staff_hash,
+            registered_by: self.registered_by,
+            registered_at: self.registered_at,
+            is_active: self.is_active,
+            reactivated_at: None,
+        }
+    }
+}
+
+// Staff record layout once rehires were tracked, before the metadata pointer
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct LegacyStaffRecordNoMetadata {
+    pub staff_hash: BytesN<32>,
+    pub registered_by: Address,
+    pub registered_at: u64,
+    pub is_active: bool,
+    pub reactivated_at: Option<u64>,
+}
+
+impl LegacyStaffRecordNoMetadata {
+    fn upgrade(self, env: &Env) -> LegacyStaffRecord {
+        LegacyStaffRecord {
+            staff_hash: self.staff_hash,
+            registered_by: self.registered_by,
+            registered_at: self.registered_at,
+            is_active: self.is_active,
+            reactivated_at: self.reactivated_at,
+            metadata_uri: String::from_str(env, ""),
+        }
+    }
+}
+
+// Staff record layout before v3, when status was a single is_active flag
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct LegacyStaffRecord {
+    pub staff_hash: BytesN<32>,
+    pub registered_by: Address,
+    pub registered_at: u64,
+    pub is_active: bool,
+    pub reactivated_at: Option<u64>,
+    pub metadata_uri: String,
+}
+
+impl LegacyStaffRecord {
+    fn upgrade(self) -> StaffRecord {
+        StaffRecord {
+            staff_hash: self.staff_hash,
+            registered_by: self.registered_by,
+            registered_at: self.registered_at,
+            status: if self.is_active { StaffStatus::Active } else { StaffStatus::Terminated },
+            reactivated_at: self.reactivated_at,
+            metadata_uri: self.metadata_uri,
+            employment_end_ts: None,
+        }
+    }
+}
+
+// Staff record layout before v6, without a contract end date
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct LegacyStaffRecordV5 {
+    pub staff_hash: BytesN<32>,
+    pub registered_by: Address,
+    pub registered_at: u64,
+    pub status: StaffStatus,
+    pub reactivated_at: Option<u64>,
+    pub metadata_uri: String,
+}
+
+impl LegacyStaffRecordV5 {
+    fn upgrade(self) -> StaffRecord {
+        StaffRecord {
+            staff_hash: self.staff_hash,
+            registered_by: self.registered_by,
+            registered_at: self.registered_at,
+            status: self.status,
+            reactivated_at: self.reactivated_at,
+            metadata_uri: self.metadata_uri,
+            employment_end_ts: None,
+        }
+    }
+}
+
+// Read a staff record under any key, upgrading older layouts on the fly. Org
+// namespaces can't be enumerated, so old records are converted lazily rather than in migrate.
+pub(crate) fn read_staff_record<K: IntoVal<Env, Val>>(env: &Env, key: &K) -> Option<StaffRecord> {
+    let raw: Val = storage::get(env, DataClass::Records, key)?;
+    let fields: Map<Symbol, Val> = Map::try_from_val(env, &raw).unwrap();
+    let has = |name: &str| fields.contains_key(Symbol::new(env, name));
+
+    let record = if has("employment_end_ts") {
+        StaffRecord::try_from_val(env, &raw).unwrap()
+    } else if has("status") {
+        LegacyStaffRecordV5::try_from_val(env, &raw).unwrap().upgrade()
+    } else if has("metadata_uri") {
+        LegacyStaffRecord::try_from_val(env, &raw).unwrap().upgrade()
+    } else if has("reactivated_at") {
+        LegacyStaffRecordNoMetadata::try_from_val(env, &raw).unwrap().upgrade(env).upgrade()
+    } else {
+        LegacyStaffRecordV0::try_from_val(env, &raw).unwrap().upgrade().upgrade(env).upgrade()
+    };
+    Some(record)
+}
+
+// Payroll batch layout as first deployed, before merkle roots, corrections and invalidation
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct LegacyPayrollBatchV0 {
+    pub batch_hash: BytesN<32>,
+    pub uploaded_by: Address,
+    pub timestamp: u64,
+    pub staff_count: u32,
+}
+
+impl LegacyPayrollBatchV0 {
+    fn upgrade(self, env: &Env) -> LegacyPayrollBatchNoCorrections {
+        LegacyPayrollBatchNoCorrections {
+            batch_hash: self.batch_hash,
+            uploaded_by: self.uploaded_by,
+            timestamp: self.timestamp,
+            staff_count: self.staff_count,
+            merkle_root: BytesN::from_array(env, &[0u8; 32]),
+        }
+    }
+}
+
+// Payroll batch layout once merkle roots were added, before corrections
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct LegacyPayrollBatchNoCorrections {
+    pub batch_hash: BytesN<32>,
+    pub uploaded_by: Address,
+    pub timestamp: u64,
+    pub staff_count: u32,
+    pub merkle_root: BytesN<32>,
+}
+
+impl LegacyPayrollBatchNoCorrections {
+    fn upgrade(self, env: &Env) -> LegacyPayrollBatchNoInvalidation {
+        LegacyPayrollBatchNoInvalidation {
+            batch_hash: self.batch_hash,
+            uploaded_by: self.uploaded_by,
+            timestamp: self.timestamp,
+            staff_count: self.staff_count,
+            merkle_root: self.merkle_root,
+            supersedes: BytesN::from_array(env, &[0u8; 32]),
+            superseded_by: BytesN::from_array(env, &[0u8; 32]),
+            correction_reason: Symbol::new(env, ""),
+        }
+    }
+}
+
+// Payroll batch layout once corrections were linked, before invalidation
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct LegacyPayrollBatchNoInvalidation {
+    pub batch_hash: BytesN<32>,
+    pub uploaded_by: Address,
+    pub timestamp: u64,
+    pub staff_count: u32,
+    pub merkle_root: BytesN<32>,
+    pub supersedes: BytesN<32>,
+    pub superseded_by: BytesN<32>,
+    pub correction_reason: Symbol,
+}
+
+impl LegacyPayrollBatchNoInvalidation {
+    fn upgrade(self, env: &Env) -> LegacyPayrollBatch {
+        LegacyPayrollBatch {
+            batch_hash: self.batch_hash,
+            uploaded_by: self.uploaded_by,
+            timestamp: self.timestamp,
+            staff_count: self.staff_count,
+            merkle_root: self.merkle_root,
+            supersedes: self.supersedes,
+            superseded_by: self.superseded_by,
+            correction_reason: self.correction_reason,
+            is_valid: true,
+            invalidation_reason: Symbol::new(env, ""),
+        }
+    }
+}
+
+// Payroll batch layout before v4, when batches were not hash-linked
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct LegacyPayrollBatch {
+    pub batch_hash: BytesN<32>,
+    pub uploaded_by: Address,
+    pub timestamp: u64,
+    pub staff_count: u32,
+    pub merkle_root: BytesN<32>,
+    pub supersedes: BytesN<32>,
+    pub superseded_by: BytesN<32>,
+    pub correction_reason: Symbol,
+    pub is_valid: bool,
+    pub invalidation_reason: Symbol,
+}
+
+impl LegacyPayrollBatch {
+    fn upgrade(self, env: &Env) -> PayrollBatch {
+        PayrollBatch {
+            batch_hash: self.batch_hash,
+            uploaded_by: self.uploaded_by,
+            timestamp: self.timestamp,
+            staff_count: self.staff_count,
+            merkle_root: self.merkle_root,
+            supersedes: self.supersedes,
+            superseded_by: self.superseded_by,
+            correction_reason: self.correction_reason,
+            is_valid: self.is_valid,
+            invalidation_reason: self.invalidation_reason,
+            prev_batch_hash: BytesN::from_array(env, &[0u8; 32]),
+        }
+    }
+}
+
+// Read a payroll batch under any key, upgrading older layouts on the fly; pre-v4
+// batches come back unlinked (zero prev hash)
+pub(crate) fn read_payroll_batch<K: IntoVal<Env, Val>>(env: &Env, key: &K) -> Option<PayrollBatch> {
+    let raw: Val = storage::get(env, DataClass::Records, key)?;
+    let fields: Map<Symbol, Val> = Map::try_from_val(env, &raw).unwrap();
+    let has = |name: &str| fields.contains_key(Symbol::new(env, name));
+
+    let batch = if has("prev_batch_hash") {
+        PayrollBatch::try_from_val(env, &raw).unwrap()
+    } else if has("is_valid") {
+        LegacyPayrollBatch::try_from_val(env, &raw).unwrap().upgrade(env)
+    } else if has("supersedes") {
+        LegacyPayrollBatchNoInvalidation::try_from_val(env, &raw).unwrap().upgrade(env).upgrade(env)
+    } else if has("merkle_root") {
+        LegacyPayrollBatchNoCorrections::try_from_val(env, &raw).unwrap().upgrade(env).upgrade(env).upgrade(env)
+    } else {
+        LegacyPayrollBatchV0::try_from_val(env, &raw).unwrap().upgrade(env).upgrade(env).upgrade(env).upgrade(env)
+    };
+    Some(batch)
+}
+
+// Per-staff backfills started by migrate and worked through by migrate_staff
+#[contracttype]
+#[derive(Clone, Copy)]
+enum UpgradeKey {
+    TreeBackfill,   // instance: next staff list position to add to the registry tree
+    StatusRecount,  // instance: next staff list position to fold into the status counts
+}
+
+fn cursor(env: &Env, key: UpgradeKey) -> Option<u32> {
+    storage::get(env, DataClass::Config, &key)
+}
+
+// Status counts cover exactly the list positions the recount has passed, so records
+// further on are left for the recount to count once, under their status at that time
+pub(crate) fn awaits_recount(env: &Env, staff_hash: &BytesN<32>) -> bool {
+    let Some(cursor) = cursor(env, UpgradeKey::StatusRecount) else {
+        return false;
+    };
+    lists::staff_position(env, staff_hash).is_none_or(|position| position >= cursor)
+}
+
+// New registrations are appended past any recount cursor
+pub(crate) fn recount_pending(env: &Env) -> bool {
+    cursor(env, UpgradeKey::StatusRecount).is_some()
+}
+
+// Run one backfill over up to `max` staff list positions; returns the positions left
+fn backfill(env: &Env, key: UpgradeKey, max: u32, visit: impl Fn(&BytesN<32>)) -> u32 {
+    let Some(start) = cursor(env, key) else {
+        return 0;
+    };
+
+    let staff = lists::page(env, lists::List::Staff, start, max);
+    for staff_hash in staff.iter() {
+        visit(&staff_hash);
+    }
+
+    let next = start + staff.len();
+    let total = lists::len(env, lists::List::Staff);
+    if next >= total {
+        storage::remove(env, DataClass::Config, &key);
+    } else {
+        storage::set(env, DataClass::Config, &key, &next);
+    }
+    total.saturating_sub(next)
+}
+
+pub(crate) fn apply_upgrade(env: &Env, owner: &Address, new_wasm_hash: BytesN<32>) {
+    env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+
+    audit::log_action(env, symbol_short!("upgraded"), owner);
+
+    env.events().publish(
+        (symbol_short!("upgraded"),),
+        (new_wasm_hash, stored_version(env), env.ledger().timestamp())
+    );
+}
+
+#[contractimpl]
+impl StaffRegistry {
+    /// Replace the contract code, keeping storage (owner only)
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let owner = auth::require_owner(&env);
+        timelock::require_not_timelocked(&env);
+
+        apply_upgrade(&env, &owner, new_wasm_hash);
+    }
+
+    /// Bring storage up to the layout expected by the current code (owner only)
+    pub fn migrate(env: Env) {
+        let owner = auth::require_owner(&env);
+
+        let from_version = stored_version(&env);
+        if from_version >= CONTRACT_VERSION {
+            panic!("Already migrated");
+        }
+
+        // Per-version migration steps go here, applied in order:
+        // 0 -> 1: start tracking the version; version 0 (and 1) records and batches may be
+        //         in any of the unversioned layouts and are upgraded on read by their fields
+        // 1 -> 2: seed the active/revoked staff counters from existing records
+        // 2 -> 3: is_active becomes a lifecycle status; records upgrade on read,
+        //         counters are rebuilt per status
+        // 3 -> 4: batches gain prev_batch_hash; older batches read back with a zero link
+        // 4 -> 5: add existing staff to the registry merkle tree
+        // 5 -> 6: staff records gain employment_end_ts; older records read back with none
+        // 6 -> 7: staff and batch lists move to indexed entries; the old Vecs stay as a
+        //         read-only prefix until migrate_lists has copied them out
+        // 7 -> 8: registration is implied by the record; the old flags stay readable
+        //         until migrate_flags has deleted them
+        // The per-staff steps (4 -> 5 and 2 -> 3) are started here and carried out
+        // in chunks by migrate_staff
+        if from_version < 5 {
+            storage::set(&env, DataClass::Config, &UpgradeKey::TreeBackfill, &0u32);
+        }
+        if from_version < 3 {
+            staff_status::set_status_counts(&env, &Scope::Main, &Map::new(&env));
+            storage::remove(&env, DataClass::Config, &DataKey::StaffCounters);
+            storage::set(&env, DataClass::Config, &UpgradeKey::StatusRecount, &0u32);
+        }
+        if from_version < 7 {
+            lists::seal_legacy(&env);
+        }
+
+        storage::set(&env, DataClass::Config, &DataKey::Version, &CONTRACT_VERSION);
+
+        audit::log_action(&env, symbol_short!("migrated"), &owner);
+
+        env.events().publish(
+            (symbol_short!("migrated"),),
+            (from_version, CONTRACT_VERSION, env.ledger().timestamp())
+        );
+    }
+
+    /// Work through up to `max` staff of the registry tree backfill and then the status
+    /// recount that migrate started (owner only). Returns how many staff are left;
+    /// call until 0. Headcounts and the registry root are complete once it is.
+    pub fn migrate_staff(env: Env, max: u32) -> u32 {
+        let owner = auth::require_owner(&env);
+        ttl::extend_instance(&env);
+
+        // Staff registered since migrate already have a leaf
+        let tree_left = backfill(&env, UpgradeKey::TreeBackfill, max, |staff_hash| {
+            if !registry_tree::contains(&env, staff_hash) {
+                let record = Self::get_staff_record(env.clone(), staff_hash.clone());
+                registry_tree::insert(&env, staff_hash, record.status == StaffStatus::Active);
+            }
+        });
+
+        let recount_budget = if tree_left == 0 { max } else { 0 };
+        let recount_left = backfill(&env, UpgradeKey::StatusRecount, recount_budget, |staff_hash| {
+            let status = Self::get_staff_record(env.clone(), staff_hash.clone()).status;
+            let mut counts = staff_status::status_counts(&env, &Scope::Main);
+            counts.set(status, counts.get(status).unwrap_or(0) + 1);
+            staff_status::set_status_counts(&env, &Scope::Main, &counts);
+        });
+        let remaining = tree_left + recount_left;
+
+        audit::log_action(&env, symbol_short!("stf_migr"), &owner);
+
+        env.events().publish(
+            (symbol_short!("stf_migr"),),
+            (remaining, env.ledger().timestamp())
+        );
+
+        remaining
+    }
+
+    /// Get the storage layout version of this deployment
+    pub fn version(env: Env) -> u32 {
+        stored_version(&env)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env, Vec};
+
+    #[test]
+    fn test_version_tracking() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        client.initialize(&Address::generate(&env));
+        assert_eq!(client.version(), CONTRACT_VERSION);
+
+        // Simulate a deployment that predates version tracking
+        env.as_contract(&contract_id, || env.storage().instance().remove(&DataKey::Version));
+        assert_eq!(client.version(), 0);
+
+        client.migrate();
+        assert_eq!(client.version(), CONTRACT_VERSION);
+    }
+
+    #[test]
+    fn test_migrate_upgrades_legacy_staff_records() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+        let active = BytesN::from_array(&env, &[1u8; 32]);
+        let revoked = BytesN::from_array(&env, &[2u8; 32]);
+        client.register_staff(&owner, &active);
+        client.register_staff(&owner, &revoked);
+
+        // Simulate a version 2 deployment: is_active records and (active, revoked) counters
+        env.as_contract(&contract_id, || {
+            let legacy = LegacyStaffRecord {
+                staff_hash: revoked.clone(),
+                registered_by: owner.clone(),
+                registered_at: 0,
+                is_active: false,
+                reactivated_at: None,
+                metadata_uri: String::from_str(&env, ""),
+            };
+            storage::set(&env, DataClass::Records, &DataKey::StaffRecord(revoked.clone()), &legacy);
+            env.storage().instance().set(&DataKey::StaffCounters, &(1u32, 1u32));
+            staff_status::set_status_counts(&env, &Scope::Main, &Map::new(&env));
+            env.storage().instance().set(&DataKey::Version, &2u32);
+        });
+        assert_eq!(client.get_staff_record(&revoked).status, StaffStatus::Terminated);
+        assert!(!client.is_staff_active(&revoked));
+
+        client.migrate();
+        assert_eq!(client.migrate_staff(&10), 0);
+        let stats = client.get_staff_stats();
+        assert_eq!((stats.active_count, stats.revoked_count), (1, 1));
+    }
+
+    #[test]
+    fn test_reads_baseline_staff_records() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        let active = BytesN::from_array(&env, &[1u8; 32]);
+        let revoked = BytesN::from_array(&env, &[2u8; 32]);
+
+        // Written exactly as the first deployment did: 4-field record plus registered flag
+        env.as_contract(&contract_id, || {
+            for (staff_hash, is_active) in [(active.clone(), true), (revoked.clone(), false)] {
+                let baseline = LegacyStaffRecordV0 {
+                    staff_hash: staff_hash.clone(),
+                    registered_by: owner.clone(),
+                    registered_at: 7,
+                    is_active,
+                };
+                env.storage().persistent().set(&DataKey::StaffRecord(staff_hash.clone()), &baseline);
+                env.storage().persistent().set(&DataKey::IsStaffRegistered(staff_hash), &true);
+            }
+        });
+
+        let record = client.get_staff_record(&active);
+        assert_eq!(record.registered_at, 7);
+        assert_eq!(record.status, StaffStatus::Active);
+        assert_eq!(record.reactivated_at, None);
+        assert_eq!(record.metadata_uri, String::from_str(&env, ""));
+        assert_eq!(client.get_staff_record(&revoked).status, StaffStatus::Terminated);
+        assert!(client.is_staff_registered(&revoked));
+    }
+
+    #[test]
+    fn test_reads_baseline_payroll_batches() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        let batch_hash = BytesN::from_array(&env, &[2u8; 32]);
+
+        // Written exactly as the first deployment did: 4-field batch plus recorded flag
+        env.as_contract(&contract_id, || {
+            let baseline = LegacyPayrollBatchV0 {
+                batch_hash: batch_hash.clone(),
+                uploaded_by: owner.clone(),
+                timestamp: 7,
+                staff_count: 3,
+            };
+            env.storage().persistent().set(&DataKey::PayrollBatch(batch_hash.clone()), &baseline);
+            env.storage().persistent().set(&DataKey::IsBatchRecorded(batch_hash.clone()), &true);
+        });
+
+        let zero = BytesN::from_array(&env, &[0u8; 32]);
+        let batch = client.get_payroll_batch(&batch_hash);
+        assert_eq!((batch.timestamp, batch.staff_count), (7, 3));
+        assert!(batch.is_valid);
+        assert_eq!(batch.merkle_root, zero);
+        assert_eq!(batch.supersedes, zero);
+        assert_eq!(batch.prev_batch_hash, zero);
+        assert!(client.is_batch_recorded(&batch_hash));
+    }
+
+    #[test]
+    fn test_migrate_baseline_deployment() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        let staff = [
+            BytesN::from_array(&env, &[1u8; 32]),
+            BytesN::from_array(&env, &[2u8; 32]),
+            BytesN::from_array(&env, &[3u8; 32]),
+        ];
+        let batches = [
+            BytesN::from_array(&env, &[11u8; 32]),
+            BytesN::from_array(&env, &[12u8; 32]),
+            BytesN::from_array(&env, &[13u8; 32]),
+        ];
+
+        // Storage as left by an unversioned deployment, mixing the layouts that shipped
+        // before versioning: baseline, rehire-tracking and merkle/correction batches
+        env.as_contract(&contract_id, || {
+            env.storage().instance().set(&DataKey::Owner, &owner);
+            env.storage().persistent().set(&DataKey::AllStaffHashes, &Vec::from_array(&env, staff.clone()));
+            env.storage().persistent().set(&DataKey::AllBatchHashes, &Vec::from_array(&env, batches.clone()));
+
+            let baseline = |staff_hash: &BytesN<32>, is_active| LegacyStaffRecordV0 {
+                staff_hash: staff_hash.clone(),
+                registered_by: owner.clone(),
+                registered_at: 1,
+                is_active,
+            };
+            let persistent = env.storage().persistent();
+            persistent.set(&DataKey::StaffRecord(staff[0].clone()), &baseline(&staff[0], true));
+            persistent.set(&DataKey::StaffRecord(staff[1].clone()), &baseline(&staff[1], false));
+            persistent.set(&DataKey::StaffRecord(staff[2].clone()), &LegacyStaffRecordNoMetadata {
+                staff_hash: staff[2].clone(),
+                registered_by: owner.clone(),
+                registered_at: 1,
+                is_active: true,
+                reactivated_at: Some(5),
+            });
+            for staff_hash in staff.iter() {
+                persistent.set(&DataKey::IsStaffRegistered(staff_hash.clone()), &true);
+            }
+
+            let v0 = LegacyPayrollBatchV0 { batch_hash: batches[0].clone(), uploaded_by: owner.clone(), timestamp: 2, staff_count: 3 };
+            let merkle = v0.clone().upgrade(&env);
+            let corrected = merkle.clone().upgrade(&env);
+            persistent.set(&DataKey::PayrollBatch(batches[0].clone()), &v0);
+            persistent.set(&DataKey::PayrollBatch(batches[1].clone()), &LegacyPayrollBatchNoCorrections {
+                batch_hash: batches[1].clone(),
+                merkle_root: BytesN::from_array(&env, &[9u8; 32]),
+                ..merkle
+            });
+            persistent.set(&DataKey::PayrollBatch(batches[2].clone()), &LegacyPayrollBatchNoInvalidation {
+                batch_hash: batches[2].clone(),
+                supersedes: batches[1].clone(),
+                ..corrected
+            });
+            for batch_hash in batches.iter() {
+                persistent.set(&DataKey::IsBatchRecorded(batch_hash.clone()), &true);
+            }
+        });
+        assert_eq!(client.version(), 0);
+
+        client.migrate();
+        assert_eq!(client.migrate_staff(&10), 0);
+        assert_eq!(client.migrate_lists(&10), 0);
+        assert_eq!(client.migrate_flags(&0, &10), 6);
+
+        let stats = client.get_staff_stats();
+        assert_eq!((stats.total, stats.active_count, stats.revoked_count), (3, 2, 1));
+        assert_eq!(client.get_staff_record(&staff[2]).reactivated_at, Some(5));
+        assert_eq!(client.get_staff_hashes(&0, &10), Vec::from_array(&env, staff.clone()));
+
+        let root = client.compute_registry_root();
+        let proof = client.get_registry_proof(&staff[2]);
+        assert!(merkle::verify_proof(&env, HashMode::Sha256, &root, &staff[2], &proof));
+
+        assert_eq!(client.get_total_batches(), 3);
+        assert_eq!(client.get_payroll_batch(&batches[1]).merkle_root, BytesN::from_array(&env, &[9u8; 32]));
+        let correction = client.get_payroll_batch(&batches[2]);
+        assert_eq!(correction.supersedes, batches[1]);
+        assert!(correction.is_valid);
+    }
+
+    #[test]
+    fn test_staff_migration_runs_in_chunks() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+        for seed in 1..=3u8 {
+            client.register_staff(&owner, &BytesN::from_array(&env, &[seed; 32]));
+        }
+
+        // Simulate a version 2 deployment that has not counted statuses yet
+        env.as_contract(&contract_id, || {
+            staff_status::set_status_counts(&env, &Scope::Main, &Map::new(&env));
+            env.storage().instance().set(&DataKey::Version, &2u32);
+        });
+        client.migrate();
+
+        // Tree first (staff with a leaf keep it), then the recount
+        let root = client.compute_registry_root();
+        assert_eq!(client.migrate_staff(&2), 4);
+        assert_eq!(client.compute_registry_root(), root);
+
+        // Changes made mid-way are counted exactly once
+        client.revoke_staff(&owner, &BytesN::from_array(&env, &[1u8; 32]));
+        client.register_staff(&owner, &BytesN::from_array(&env, &[4u8; 32]));
+        assert_eq!(client.migrate_staff(&2), 2);
+        client.revoke_staff(&owner, &BytesN::from_array(&env, &[2u8; 32]));
+        assert_eq!(client.migrate_staff(&2), 0);
+        client.revoke_staff(&owner, &BytesN::from_array(&env, &[3u8; 32]));
+
+        let stats = client.get_staff_stats();
+        assert_eq!((stats.total, stats.active_count, stats.revoked_count), (4, 1, 3));
+    }
+
+    #[test]
+    fn test_reads_staff_records_without_end_date() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+
+        let owner = Address::generate(&env);
+        client.initialize(&owner);
+        client.set_legacy_hashes_allowed(&true);
+        let staff_hash = BytesN::from_array(&env, &[1u8; 32]);
+        client.register_staff(&owner, &staff_hash);
+
+        env.as_contract(&contract_id, || {
+            let legacy = LegacyStaffRecordV5 {
+                staff_hash: staff_hash.clone(),
+                registered_by: owner.clone(),
+                registered_at: 0,
+                status: StaffStatus::OnLeave,
+                reactivated_at: None,
+                metadata_uri: String::from_str(&env, ""),
+            };
+            storage::set(&env, DataClass::Records, &DataKey::StaffRecord(staff_hash.clone()), &legacy);
+        });
+
+        let record = client.get_staff_record(&staff_hash);
+        assert_eq!(record.status, StaffStatus::OnLeave);
+        assert_eq!(record.employment_end_ts, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Already migrated")]
+    fn test_migrate_twice() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, StaffRegistry);
+        let client = StaffRegistryClient::new(&env, &contract_id);
+        client.initialize(&Address::generate(&env));
+
+        env.as_contract(&contract_id, || StaffRegistry::migrate(env.clone()));
+    }
+}
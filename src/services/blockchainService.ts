@@ -4,6 +4,7 @@ import {
   Networks,
   TransactionBuilder,
   Contract,
+  Address,
   xdr,
   SorobanRpc,
   BASE_FEE,
@@ -59,6 +60,13 @@ const createBytes32ScVal = (hexString: string): xdr.ScVal => {
   return xdr.ScVal.scvBytes(buffer);
 };
 
+/**
+ * Address ScVal of the signing account, passed as the contract `caller`
+ */
+const createCallerScVal = (): xdr.ScVal => {
+  return new Address(sourceKeypair.publicKey()).toScVal();
+};
+
 /**
  * Verify transaction in background (non-blocking)
  */
@@ -194,6 +202,8 @@ const callViewFunction = async (
 
 /**
  * Register staff on blockchain
+ * Requires the contract owner to have called set_legacy_hashes_allowed(true)
+ * (done by scripts/deploy-soroban.sh), since the hash is derived off-chain
  */
 export const registerStaffOnChain = async (staffHash: string): Promise<{
   transactionHash: string;
@@ -212,12 +222,17 @@ export const registerStaffOnChain = async (staffHash: string): Promise<{
 
     const staffHashScVal = createBytes32ScVal(staffHash);
     const contract = new Contract(CONTRACT_ID);
-    const operation = contract.call('register_staff', staffHashScVal);
+    const operation = contract.call('register_staff', createCallerScVal(), staffHashScVal);
 
     const result = await submitTransaction(operation);
     
     return result;
   } catch (error: any) {
+    if (error?.message?.includes('Domain-separated hash required')) {
+      throw new Error(
+        'Contract rejects backend-supplied staff hashes; invoke set_legacy_hashes_allowed(true) as the contract owner'
+      );
+    }
     throw error;
   }
 };
@@ -270,7 +285,7 @@ export const revokeStaffOnChain = async (staffHash: string): Promise<{
 
     const staffHashScVal = createBytes32ScVal(staffHash);
     const contract = new Contract(CONTRACT_ID);
-    const operation = contract.call('revoke_staff', staffHashScVal);
+    const operation = contract.call('revoke_staff', createCallerScVal(), staffHashScVal);
 
     const result = await submitTransaction(operation);
     
@@ -305,6 +320,7 @@ export const recordPayrollBatchOnChain = async (
     const contract = new Contract(CONTRACT_ID);
     const operation = contract.call(
       'record_payroll_batch',
+      createCallerScVal(),
       batchHashScVal,
       staffCountScVal
     );